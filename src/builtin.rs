@@ -6,6 +6,9 @@ pub mod archs;
 /// Builtin Operating Systems
 pub mod os;
 
+/// Builtin environments (C libraries)
+pub mod env;
+
 /// Builtin link format
 pub mod link;
 
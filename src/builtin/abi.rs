@@ -1,22 +1,171 @@
 use target_tuples::pieces::{Architecture, Environment, OS};
 
 use crate::{
-    builtin::archs::x86::{X32_PRIMITIVES, X86_64_F64_LONG_DOUBLE, X86_64_PRIMITIVES_SYSV},
-    properties::abi::{Abi, PrimitiveLayouts},
+    builtin::archs::{
+        aarch64::{AARCH64_BE_PRIMITIVES, AARCH64_DARWIN_PRIMITIVES, AARCH64_PRIMITIVES},
+        arm::{ARM_PRIMITIVES, ARMEB_PRIMITIVES},
+        holeybytes::HOLEYBYTES_PRIMITIVES,
+        mips::MipsAbi,
+        ppc::PpcLongDouble,
+        riscv::RiscvAbi,
+        sparc::{SPARC_PRIMITIVES, SPARCEL_PRIMITIVES, SPARCV9_PRIMITIVES},
+        wasm::{WASM32_PRIMITIVES, WASM64_PRIMITIVES},
+        x86::{
+            X32_PRIMITIVES, X86_32_PRIMITIVES, X86_32_PRIMITIVES_ANDROID, X86_32_PRIMITIVES_MINGW,
+            X86_32_PRIMITIVES_MSVC, X86_64_F64_LONG_DOUBLE, X86_64_PRIMITIVES_ANDROID,
+            X86_64_PRIMITIVES_MINGW, X86_64_PRIMITIVES_MSVC, X86_64_PRIMITIVES_SYSV,
+        },
+    },
+    properties::abi::{Abi, PrimitiveLayouts, Stack, StackDirection, StackProbes},
 };
 
 /// Hardfloat ABI
 pub static ABI_HARDFLOAT: Abi = Abi {
     float_pass_override: None,
+    float_reg_max_width: None,
     simd_pass_override: None,
 };
 
 /// Softfloat ABI
 pub static ABI_SOFTFLOAT: Abi = Abi {
     float_pass_override: Some(crate::properties::abi::PassModeOverride::Int),
+    float_reg_max_width: None,
     simd_pass_override: Some(crate::properties::abi::PassModeOverride::Int),
 };
 
+/// Single-float ABI, where only `float` is passed in floating-point registers (such as RISC-V `ilp32f` and `lp64f`)
+pub static ABI_SINGLEFLOAT: Abi = Abi {
+    float_pass_override: None,
+    float_reg_max_width: Some(32),
+    simd_pass_override: None,
+};
+
+/// Double-float ABI, where `float` and `double` are passed in floating-point registers, but wider types are not (such as RISC-V `ilp32d` and `lp64d`)
+pub static ABI_DOUBLEFLOAT: Abi = Abi {
+    float_pass_override: None,
+    float_reg_max_width: Some(64),
+    simd_pass_override: None,
+};
+
+/// The stack of the System V x86-64 ABI, with 16-byte alignment, a 128-byte red zone, and inline stack probes
+pub const STACK_SYSV_X86_64: Stack = Stack {
+    align: 16,
+    red_zone: 128,
+    direction: StackDirection::Down,
+    probes: StackProbes::Inline,
+};
+
+/// The System V x86-64 stack without stack probes
+pub static STACK_SYSV_X86_64_NO_PROBES: Stack = Stack {
+    probes: StackProbes::None,
+    ..STACK_SYSV_X86_64
+};
+
+/// The x86-64 stack for kernels, where the red zone would be clobbered by interrupts
+pub static STACK_X86_64_KERNEL: Stack = Stack {
+    red_zone: 0,
+    probes: StackProbes::None,
+    ..STACK_SYSV_X86_64
+};
+
+/// The x86-64 stack on Darwin, which probes using `__chkstk_darwin`
+pub static STACK_X86_64_DARWIN: Stack = Stack {
+    probes: StackProbes::Call(cowstr!("___chkstk_darwin")),
+    ..STACK_SYSV_X86_64
+};
+
+/// The x86-64 stack on Windows with the MSVC toolchain. The Win64 ABI has no red zone
+pub static STACK_X86_64_MSVC: Stack = Stack {
+    red_zone: 0,
+    probes: StackProbes::Call(cowstr!("__chkstk")),
+    ..STACK_SYSV_X86_64
+};
+
+/// The x86-64 stack on Windows with the MinGW toolchain
+pub static STACK_X86_64_MINGW: Stack = Stack {
+    red_zone: 0,
+    probes: StackProbes::Call(cowstr!("___chkstk_ms")),
+    ..STACK_SYSV_X86_64
+};
+
+/// The stack of the i386 System V ABI, which historically only guarantees 4-byte alignment and has no red zone
+pub const STACK_X86_32: Stack = Stack {
+    align: 4,
+    red_zone: 0,
+    direction: StackDirection::Down,
+    probes: StackProbes::Inline,
+};
+
+/// The i386 stack without stack probes
+pub static STACK_X86_32_NO_PROBES: Stack = Stack {
+    probes: StackProbes::None,
+    ..STACK_X86_32
+};
+
+/// The i386 stack on Android, which requires 16-byte alignment
+pub static STACK_X86_32_ANDROID: Stack = Stack {
+    align: 16,
+    ..STACK_X86_32
+};
+
+/// The i386 stack on Windows with the MSVC toolchain
+pub static STACK_X86_32_MSVC: Stack = Stack {
+    probes: StackProbes::Call(cowstr!("__chkstk")),
+    ..STACK_X86_32
+};
+
+/// The i386 stack on Windows with the MinGW toolchain
+pub static STACK_X86_32_MINGW: Stack = Stack {
+    probes: StackProbes::Call(cowstr!("___chkstk_ms")),
+    ..STACK_X86_32
+};
+
+/// A stack with 16-byte alignment, no red zone, and inline stack probes
+pub const STACK_ALIGN16: Stack = Stack {
+    align: 16,
+    red_zone: 0,
+    direction: StackDirection::Down,
+    probes: StackProbes::Inline,
+};
+
+/// A stack with 16-byte alignment, no red zone, and no stack probes
+pub static STACK_ALIGN16_NO_PROBES: Stack = Stack {
+    probes: StackProbes::None,
+    ..STACK_ALIGN16
+};
+
+/// A stack with 8-byte alignment, no red zone, and inline stack probes
+pub static STACK_ALIGN8: Stack = Stack {
+    align: 8,
+    ..STACK_ALIGN16
+};
+
+/// A stack with 8-byte alignment, no red zone, and no stack probes
+pub static STACK_ALIGN8_NO_PROBES: Stack = Stack {
+    align: 8,
+    probes: StackProbes::None,
+    ..STACK_ALIGN16
+};
+
+/// The AArch64 stack on Darwin, which has a 128-byte red zone and probes using `__chkstk_darwin`
+pub static STACK_AARCH64_DARWIN: Stack = Stack {
+    red_zone: 128,
+    probes: StackProbes::Call(cowstr!("___chkstk_darwin")),
+    ..STACK_ALIGN16
+};
+
+/// The stack of the 64-bit PowerPC ELF ABIs, which have a 288-byte protected zone below the stack pointer
+pub const STACK_PPC64: Stack = Stack {
+    red_zone: 288,
+    ..STACK_ALIGN16
+};
+
+/// The 64-bit PowerPC stack without stack probes
+pub static STACK_PPC64_NO_PROBES: Stack = Stack {
+    probes: StackProbes::None,
+    ..STACK_PPC64
+};
+
 /// Obtains the [`PrimitiveLayouts`] properties for a given arch, os, env triple
 pub const fn primitives_from_target(
     arch: Architecture,
@@ -27,12 +176,74 @@ pub const fn primitives_from_target(
         (Architecture::X86_64 { .. }, OS::Linux, Some(Environment::GNUX32)) => {
             Some(&X32_PRIMITIVES)
         }
+        (Architecture::X86_64 { .. }, OS::Linux, Some(Environment::Android)) => {
+            Some(&X86_64_PRIMITIVES_ANDROID)
+        }
+        // musl uses the same `long double` (x87) and `intmax_t` (64-bit) as glibc
         (
             Architecture::X86_64 { .. },
-            OS::Linux | OS::FreeBSD | OS::OpenBSD | OS::NetBSD | OS::Fuchsia,
+            OS::Linux
+            | OS::FreeBSD
+            | OS::OpenBSD
+            | OS::NetBSD
+            | OS::Fuchsia
+            | OS::Darwin
+            | OS::MacOSX,
             _,
         ) => Some(&X86_64_PRIMITIVES_SYSV),
         (Architecture::X86_64 { .. }, OS::Lilium, _) => Some(&X86_64_F64_LONG_DOUBLE),
+        (Architecture::X86_64 { .. }, OS::Win32, Some(Environment::MSVC)) => {
+            Some(&X86_64_PRIMITIVES_MSVC)
+        }
+        (Architecture::X86_64 { .. }, OS::Win32, Some(Environment::GNU)) => {
+            Some(&X86_64_PRIMITIVES_MINGW)
+        }
+        (Architecture::X86_32(_), OS::Win32, Some(Environment::MSVC)) => {
+            Some(&X86_32_PRIMITIVES_MSVC)
+        }
+        (Architecture::X86_32(_), OS::Win32, Some(Environment::GNU)) => {
+            Some(&X86_32_PRIMITIVES_MINGW)
+        }
+        (Architecture::X86_32(_), OS::Linux, Some(Environment::Android)) => {
+            Some(&X86_32_PRIMITIVES_ANDROID)
+        }
+        (Architecture::X86_32(_), OS::Linux, _) => Some(&X86_32_PRIMITIVES),
+        (Architecture::Aarch64, OS::Darwin | OS::MacOSX | OS::IOS, _) => {
+            Some(&AARCH64_DARWIN_PRIMITIVES)
+        }
+        (Architecture::Aarch64, _, _) => Some(&AARCH64_PRIMITIVES),
+        (Architecture::Aarch64Be, _, _) => Some(&AARCH64_BE_PRIMITIVES),
+        (Architecture::Arm, _, _) => Some(&ARM_PRIMITIVES),
+        (Architecture::ArmBe, _, _) => Some(&ARMEB_PRIMITIVES),
+        (Architecture::HoleyBytes, _, _) => Some(&HOLEYBYTES_PRIMITIVES),
+        (Architecture::Sparc, _, _) => Some(&SPARC_PRIMITIVES),
+        (Architecture::SparcEL, _, _) => Some(&SPARCEL_PRIMITIVES),
+        (Architecture::SparcV9, _, _) => Some(&SPARCV9_PRIMITIVES),
+        (
+            Architecture::Mips
+            | Architecture::MipsLE
+            | Architecture::Mips64
+            | Architecture::Mips64LE,
+            _,
+            _,
+        ) => match MipsAbi::default_for(arch, env) {
+            Some(abi) => abi.primitives(arch),
+            None => None,
+        },
+        (Architecture::Wasm32, _, _) => Some(&WASM32_PRIMITIVES),
+        (Architecture::Wasm64, _, _) => Some(&WASM64_PRIMITIVES),
+        (Architecture::RiscV32 | Architecture::RiscV64, _, _) => {
+            match RiscvAbi::default_for(arch, os) {
+                Some(abi) => Some(abi.primitives()),
+                None => None,
+            }
+        }
+        (Architecture::PowerPC32 | Architecture::PowerPC64 | Architecture::PowerPC64le, _, _) => {
+            match PpcLongDouble::default_for(arch, os, env) {
+                Some(ldouble) => ldouble.primitives(arch),
+                None => None,
+            }
+        }
         _ => None,
     }
 }
@@ -44,36 +255,227 @@ pub const fn abi_from_target(
     env: Option<Environment>,
 ) -> Option<&'static Abi> {
     match (arch, os, env) {
-        (Architecture::X86_64 { .. } | Architecture::Clever | Architecture::HoleyBytes, _, _) => {
-            Some(&ABI_HARDFLOAT)
-        }
+        (
+            Architecture::X86_64 { .. }
+            | Architecture::Clever
+            | Architecture::HoleyBytes
+            | Architecture::Wasm32
+            | Architecture::Wasm64
+            | Architecture::Mips
+            | Architecture::MipsLE
+            | Architecture::Mips64
+            | Architecture::Mips64LE
+            | Architecture::Sparc
+            | Architecture::SparcEL
+            | Architecture::SparcV9,
+            _,
+            _,
+        ) => Some(&ABI_HARDFLOAT),
+        (Architecture::X86_32(_), OS::Linux | OS::Win32, _) => Some(&ABI_HARDFLOAT),
         (
             Architecture::Aarch64
             | Architecture::Aarch64Be
             | Architecture::Aarch64_32
             | Architecture::Arm
             | Architecture::ArmBe,
-            OS::Linux | OS::Lilium | OS::Win32 | OS::MacOSX,
             _,
-        )
-        | (
+            Some(Environment::GNUEABIHF | Environment::EABIHF | Environment::MuslEABIHF),
+        ) => Some(&ABI_HARDFLOAT),
+        (
             Architecture::Aarch64
             | Architecture::Aarch64Be
             | Architecture::Aarch64_32
             | Architecture::Arm
             | Architecture::ArmBe,
             _,
-            Some(Environment::GNUEABIHF | Environment::EABIHF),
-        ) => Some(&ABI_HARDFLOAT),
+            Some(Environment::GNUEABI | Environment::EABI | Environment::MuslEABI),
+        ) => Some(&ABI_SOFTFLOAT),
         (
             Architecture::Aarch64
             | Architecture::Aarch64Be
             | Architecture::Aarch64_32
             | Architecture::Arm
             | Architecture::ArmBe,
+            OS::Linux | OS::Lilium | OS::Win32 | OS::Darwin | OS::MacOSX | OS::IOS,
             _,
-            Some(Environment::GNUEABI | Environment::EABI),
-        ) => Some(&ABI_SOFTFLOAT),
+        ) => Some(&ABI_HARDFLOAT),
+        (Architecture::RiscV32 | Architecture::RiscV64, _, _) => {
+            match RiscvAbi::default_for(arch, os) {
+                Some(abi) => Some(abi.abi()),
+                None => None,
+            }
+        }
+        (Architecture::PowerPC32 | Architecture::PowerPC64 | Architecture::PowerPC64le, _, _) => {
+            Some(&ABI_HARDFLOAT)
+        }
         _ => None,
     }
 }
+
+/// Obtains the [`Stack`] properties for a given arch, os, env triple.
+///
+/// Inline stack probes are used on Linux and Lilium userspace. Kernel environments have no red zone
+pub const fn stack_from_target(
+    arch: Architecture,
+    os: OS,
+    env: Option<Environment>,
+) -> Option<&'static Stack> {
+    let kernel = matches!(env, Some(Environment::Kernel));
+    let probes = matches!(os, OS::Linux | OS::Lilium) && !kernel;
+    match (arch, os, env) {
+        (Architecture::X86_64 { .. }, _, _) if kernel => Some(&STACK_X86_64_KERNEL),
+        (Architecture::X86_64 { .. }, OS::Win32, Some(Environment::MSVC)) => {
+            Some(&STACK_X86_64_MSVC)
+        }
+        (Architecture::X86_64 { .. }, OS::Win32, _) => Some(&STACK_X86_64_MINGW),
+        (Architecture::X86_64 { .. }, OS::Darwin | OS::MacOSX, _) => Some(&STACK_X86_64_DARWIN),
+        (Architecture::X86_64 { .. }, _, _) if probes => Some(&STACK_SYSV_X86_64),
+        (Architecture::X86_64 { .. }, _, _) => Some(&STACK_SYSV_X86_64_NO_PROBES),
+        (Architecture::X86_32(_), OS::Win32, Some(Environment::MSVC)) => Some(&STACK_X86_32_MSVC),
+        (Architecture::X86_32(_), OS::Win32, _) => Some(&STACK_X86_32_MINGW),
+        (Architecture::X86_32(_), OS::Linux, Some(Environment::Android)) => {
+            Some(&STACK_X86_32_ANDROID)
+        }
+        (Architecture::X86_32(_), _, _) if probes => Some(&STACK_X86_32),
+        (Architecture::X86_32(_), _, _) => Some(&STACK_X86_32_NO_PROBES),
+        (Architecture::Aarch64 | Architecture::Aarch64Be, OS::Darwin | OS::MacOSX | OS::IOS, _) => {
+            Some(&STACK_AARCH64_DARWIN)
+        }
+        (Architecture::PowerPC64 | Architecture::PowerPC64le, _, _) if probes => Some(&STACK_PPC64),
+        (Architecture::PowerPC64 | Architecture::PowerPC64le, _, _) => Some(&STACK_PPC64_NO_PROBES),
+        (
+            Architecture::Aarch64
+            | Architecture::Aarch64Be
+            | Architecture::RiscV32
+            | Architecture::RiscV64
+            | Architecture::PowerPC32
+            | Architecture::Mips64
+            | Architecture::Mips64LE
+            | Architecture::SparcV9,
+            _,
+            _,
+        ) if probes => Some(&STACK_ALIGN16),
+        (
+            Architecture::Aarch64
+            | Architecture::Aarch64Be
+            | Architecture::RiscV32
+            | Architecture::RiscV64
+            | Architecture::PowerPC32
+            | Architecture::Mips64
+            | Architecture::Mips64LE
+            | Architecture::SparcV9
+            | Architecture::Wasm32
+            | Architecture::Wasm64
+            | Architecture::Clever,
+            _,
+            _,
+        ) => Some(&STACK_ALIGN16_NO_PROBES),
+        (
+            Architecture::Arm
+            | Architecture::ArmBe
+            | Architecture::Mips
+            | Architecture::MipsLE
+            | Architecture::Sparc
+            | Architecture::SparcEL,
+            _,
+            _,
+        ) if probes => Some(&STACK_ALIGN8),
+        (
+            Architecture::Arm
+            | Architecture::ArmBe
+            | Architecture::Mips
+            | Architecture::MipsLE
+            | Architecture::Sparc
+            | Architecture::SparcEL
+            | Architecture::HoleyBytes,
+            _,
+            _,
+        ) => Some(&STACK_ALIGN8_NO_PROBES),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use target_tuples::TargetRef;
+
+    use super::*;
+
+    fn primitives(name: &str) -> &'static PrimitiveLayouts {
+        let targ = TargetRef::parse(name);
+        primitives_from_target(targ.arch, targ.sys.os().unwrap(), targ.sys.env()).unwrap()
+    }
+
+    #[test]
+    fn i686_windows_primitives() {
+        let mingw = primitives("i686-pc-windows-gnu");
+        assert!(core::ptr::eq(mingw, &X86_32_PRIMITIVES_MINGW));
+        assert_eq!(mingw.max_int_align, 8);
+        assert_eq!(mingw.ldouble_align, 4);
+        assert_eq!(mingw.ldouble_format, X86_32_PRIMITIVES.ldouble_format);
+
+        let msvc = primitives("i686-pc-windows-msvc");
+        assert_eq!(msvc.max_int_align, 8);
+        assert_ne!(msvc.ldouble_format, mingw.ldouble_format);
+
+        assert_eq!(primitives("i686-pc-linux-gnu").max_int_align, 4);
+    }
+
+    fn stack(name: &str) -> &'static Stack {
+        let targ = TargetRef::parse(name);
+        stack_from_target(targ.arch, targ.sys.os().unwrap(), targ.sys.env()).unwrap()
+    }
+
+    #[test]
+    fn sysv_x86_64_stack() {
+        assert_eq!(
+            *stack("x86_64-pc-linux-gnu"),
+            Stack {
+                align: 16,
+                red_zone: 128,
+                direction: StackDirection::Down,
+                probes: StackProbes::Inline,
+            }
+        );
+        // Stack clash protection is only enabled by default on Linux and Lilium
+        let freebsd = stack("x86_64-unknown-freebsd");
+        assert_eq!((freebsd.align, freebsd.red_zone), (16, 128));
+        assert_eq!(freebsd.probes, StackProbes::None);
+    }
+
+    #[test]
+    fn kernel_stack() {
+        let kernel = stack("x86_64-pc-lilium-kernel");
+        assert_eq!(kernel.red_zone, 0);
+        assert_eq!(kernel.probes, StackProbes::None);
+        assert_eq!(stack("x86_64-pc-lilium-std").red_zone, 128);
+    }
+
+    #[test]
+    fn windows_stack() {
+        let msvc = stack("x86_64-pc-windows-msvc");
+        assert_eq!(msvc.red_zone, 0);
+        assert_eq!(msvc.probes, StackProbes::Call(cowstr!("__chkstk")));
+        let mingw = stack("x86_64-pc-windows-gnu");
+        assert_eq!(mingw.red_zone, 0);
+        assert_eq!(mingw.probes, StackProbes::Call(cowstr!("___chkstk_ms")));
+
+        let msvc = stack("i686-pc-windows-msvc");
+        assert_eq!((msvc.align, msvc.red_zone), (4, 0));
+        assert_eq!(msvc.probes, StackProbes::Call(cowstr!("__chkstk")));
+    }
+
+    #[test]
+    fn i386_stack() {
+        assert_eq!(
+            *stack("i686-pc-linux-gnu"),
+            Stack {
+                align: 4,
+                red_zone: 0,
+                direction: StackDirection::Down,
+                probes: StackProbes::Inline,
+            }
+        );
+        assert_eq!(stack("i686-linux-android").align, 16);
+    }
+}
@@ -1,19 +1,46 @@
-use target_tuples::pieces::Architecture;
+use target_tuples::pieces::{Architecture, Environment, OS};
 
-use crate::{
-    helpers::CowPtr,
-    properties::arch::{Arch, Asm, Machine},
-};
+use crate::properties::arch::Arch;
 
 /// x86/x86-64
 pub mod x86;
 
+/// AArch64
+pub mod aarch64;
+
+/// 32-bit ARM
+pub mod arm;
+
+/// AVR
+pub mod avr;
+
 /// Clever-ISA
 pub mod clever;
 
+/// Holey Bytes
+pub mod holeybytes;
+
+/// Motorola 68000 series
+pub mod m68k;
+
 /// 6502 and derivatives
 pub mod m65;
 
+/// MIPS
+pub mod mips;
+
+/// PowerPC
+pub mod ppc;
+
+/// RISC-V
+pub mod riscv;
+
+/// SPARC
+pub mod sparc;
+
+/// WebAssembly
+pub mod wasm;
+
 /// Determines the architecture info from the architecture target name
 pub const fn from_target(arch: Architecture) -> Option<&'static Arch> {
     match arch {
@@ -31,7 +58,41 @@ pub const fn from_target(arch: Architecture) -> Option<&'static Arch> {
         Architecture::Wc65c816 => Some(&m65::W65),
         Architecture::M6502 => Some(&m65::M6502),
         Architecture::M65C02 => Some(&m65::M65C02),
+        Architecture::Aarch64 => Some(&aarch64::AARCH64),
+        Architecture::Aarch64Be => Some(&aarch64::AARCH64_BE),
+        Architecture::Arm => Some(&arm::ARM),
+        Architecture::ArmBe => Some(&arm::ARMEB),
+        Architecture::RiscV32 => Some(&riscv::RISCV32),
+        Architecture::RiscV64 => Some(&riscv::RISCV64),
+        Architecture::PowerPC32 => Some(&ppc::POWERPC),
+        Architecture::PowerPC64 => Some(&ppc::POWERPC64),
+        Architecture::PowerPC64le => Some(&ppc::POWERPC64LE),
+        Architecture::Wasm32 => Some(&wasm::WASM32),
+        Architecture::Wasm64 => Some(&wasm::WASM64),
+        Architecture::Mips => Some(&mips::MIPS),
+        Architecture::MipsLE => Some(&mips::MIPSEL),
+        Architecture::Mips64 => Some(&mips::MIPS64),
+        Architecture::Mips64LE => Some(&mips::MIPS64EL),
+        Architecture::Sparc => Some(&sparc::SPARC),
+        Architecture::SparcEL => Some(&sparc::SPARCEL),
+        Architecture::SparcV9 => Some(&sparc::SPARCV9),
         Architecture::Clever => Some(&clever::CLEVER),
+        Architecture::HoleyBytes => Some(&holeybytes::HOLEYBYTES),
         _ => None,
     }
 }
+
+/// Determines the architecture info for a target, which may depend on the OS and environment.
+///
+/// This is the same as [`from_target`], except that the default machine is chosen to match the ABI for 32-bit ARM (see [`arm::arch_for_env`]) and RISC-V (see [`riscv::arch_for_os`])
+pub const fn from_target_env(
+    arch: Architecture,
+    os: OS,
+    env: Option<Environment>,
+) -> Option<&'static Arch> {
+    match arch {
+        Architecture::Arm | Architecture::ArmBe => Some(arm::arch_for_env(arch, os, env)),
+        Architecture::RiscV32 | Architecture::RiscV64 => riscv::arch_for_os(arch, os),
+        _ => from_target(arch),
+    }
+}
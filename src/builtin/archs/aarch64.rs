@@ -0,0 +1,167 @@
+use crate::{
+    arch_features, native_atomics,
+    properties::{
+        abi::{BE_LP64, IEEE754_DOUBLE, IEEE764_QUAD, LE_LP64, PrimitiveLayouts},
+        arch::{Arch, Asm, Machine},
+    },
+};
+
+arch_features! {
+    /// The list of AArch64 features
+    pub static AARCH64_FEATURES = [
+        "v8a" ("fp-armv8", "neon"),
+        "v8.1a" ("v8a", "fp-armv8", "neon", "crc", "lse", "rdm"),
+        "v8.2a" ("v8a", "v8.1a", "fp-armv8", "neon", "crc", "lse", "rdm"),
+        "v8.3a" ("v8a", "v8.1a", "v8.2a", "fp-armv8", "neon", "crc", "lse", "rdm", "rcpc", "paca", "pacg", "jsconv", "fcma"),
+        "v8.4a" ("v8a", "v8.1a", "v8.2a", "v8.3a", "fp-armv8", "neon", "crc", "lse", "rdm", "rcpc", "paca", "pacg", "jsconv", "fcma", "dotprod", "dit", "flagm"),
+        "v8.5a" ("v8a", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "fp-armv8", "neon", "crc", "lse", "rdm", "rcpc", "paca", "pacg", "jsconv", "fcma", "dotprod", "dit", "flagm", "bti", "ssbs", "sb", "fptoint"),
+        "v9a" ("v8a", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "fp-armv8", "neon", "crc", "lse", "rdm", "rcpc", "paca", "pacg", "jsconv", "fcma", "dotprod", "dit", "flagm", "bti", "ssbs", "sb", "fptoint", "sve", "sve2", "fp16"),
+        "fp-armv8",
+        "neon" ("fp-armv8"),
+        "fp16" ("fp-armv8", "neon"),
+        "crc",
+        "lse",
+        "rdm" ("fp-armv8", "neon"),
+        "rcpc",
+        "paca",
+        "pacg",
+        "jsconv" ("fp-armv8", "neon"),
+        "fcma" ("fp-armv8", "neon"),
+        "dotprod" ("fp-armv8", "neon"),
+        "dit",
+        "flagm",
+        "bti",
+        "ssbs",
+        "sb",
+        "fptoint" ("fp-armv8"),
+        "mte",
+        "aes" ("fp-armv8", "neon"),
+        "sha2" ("fp-armv8", "neon"),
+        "sha3" ("fp-armv8", "neon", "sha2"),
+        "sve" ("fp-armv8", "neon", "fp16"),
+        "sve2" ("fp-armv8", "neon", "fp16", "sve"),
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of AArch64.
+    ///
+    /// Exclusive loads and stores (`ldxr`/`stxr` and `ldxp`/`stxp`) are always available.
+    pub static AARCH64_ATOMICS = [
+        8, 16, 32, 64, 128,
+    ];
+}
+
+/// The list of known AArch64 machines
+pub static AARCH64_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("generic"),
+        features: slice![cowstr!("v8a")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv8-a"),
+        features: slice![cowstr!("v8a")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv8.1-a"),
+        features: slice![cowstr!("v8.1a")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv8.2-a"),
+        features: slice![cowstr!("v8.2a")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv8.3-a"),
+        features: slice![cowstr!("v8.3a")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv8.4-a"),
+        features: slice![cowstr!("v8.4a")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv8.5-a"),
+        features: slice![cowstr!("v8.5a")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv9-a"),
+        features: slice![cowstr!("v9a")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("apple-m1"),
+        features: slice![
+            cowstr!("v8.5a"),
+            cowstr!("fp16"),
+            cowstr!("aes"),
+            cowstr!("sha2"),
+            cowstr!("sha3")
+        ],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// AArch64 call tags
+pub static AARCH64_TAGS: &[crate::helpers::CowStr] =
+    &[cowstr!("aapcs64"), cowstr!("darwinpcs"), cowstr!("win64")];
+
+/// The ASM Specification for AArch64
+pub static AARCH64_ASM: Asm = Asm {};
+
+/// AArch64, little-endian
+pub static AARCH64: Arch = Arch {
+    name: cowstr!("aarch64"),
+    alias_names: slice![cowstr!("arm64")],
+    machines: cow!(*AARCH64_MACHINES),
+    raw_width: 64,
+    features: cow!(*AARCH64_FEATURES),
+    native_atomics: cow!(*AARCH64_ATOMICS),
+    default_machine: cow!(AARCH64_MACHINES[0]),
+    call_tags: cow!(*AARCH64_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(AARCH64_ASM)),
+};
+
+/// AArch64, big-endian
+pub static AARCH64_BE: Arch = Arch {
+    name: cowstr!("aarch64_be"),
+    alias_names: slice![],
+    machines: cow!(*AARCH64_MACHINES),
+    raw_width: 64,
+    features: cow!(*AARCH64_FEATURES),
+    native_atomics: cow!(*AARCH64_ATOMICS),
+    default_machine: cow!(AARCH64_MACHINES[0]),
+    call_tags: cow!(*AARCH64_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(AARCH64_ASM)),
+};
+
+/// Default primitives for little-endian AArch64 (AAPCS64).
+/// `long double` is IEEE binary128
+pub static AARCH64_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LP64,
+    max_int_align: 16,
+    max_bit_int_align: 16,
+    max_simd_align: 16,
+    ldouble_align: 16,
+    ldouble_format: IEEE764_QUAD,
+};
+
+/// Default primitives for big-endian AArch64 (AAPCS64).
+pub static AARCH64_BE_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_LP64,
+    ..AARCH64_PRIMITIVES
+};
+
+/// Primitives for Apple AArch64 targets, where `long double` is the same as `double`
+pub static AARCH64_DARWIN_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    ldouble_align: 8,
+    ldouble_format: IEEE754_DOUBLE,
+    ..AARCH64_PRIMITIVES
+};
@@ -0,0 +1,338 @@
+use target_tuples::pieces::{Architecture, Environment, OS};
+
+use crate::{
+    arch_features, native_atomics,
+    properties::{
+        abi::{BE_ILP32, IEEE754_DOUBLE, LE_ILP32, PrimitiveLayouts},
+        arch::{Arch, Asm, Machine},
+    },
+};
+
+arch_features! {
+    /// The list of 32-bit ARM features
+    pub static ARM_FEATURES = [
+        "v4t" ("thumb"),
+        "v5te" ("v4t", "thumb"),
+        "v6" ("v4t", "v5te", "thumb"),
+        "v6k" ("v4t", "v5te", "v6", "thumb"),
+        "v6m" ("thumb", "mclass"),
+        "v6t2" ("v4t", "v5te", "v6", "v6k", "thumb", "thumb2"),
+        "v7" ("v4t", "v5te", "v6", "v6k", "v6t2", "thumb", "thumb2"),
+        "v7m" ("v6m", "thumb", "thumb2", "mclass", "hwdiv"),
+        "v7em" ("v6m", "v7m", "thumb", "thumb2", "mclass", "hwdiv", "dsp"),
+        "v8" ("v4t", "v5te", "v6", "v6k", "v6t2", "v7", "thumb", "thumb2", "hwdiv", "hwdiv-arm", "dsp"),
+        "v8m" ("v6m", "thumb", "mclass", "hwdiv"),
+        "v8m.main" ("v6m", "v7m", "v8m", "thumb", "thumb2", "mclass", "hwdiv"),
+        "v8.1m.main" ("v6m", "v7m", "v8m", "v8m.main", "thumb", "thumb2", "mclass", "hwdiv"),
+        "aclass",
+        "rclass",
+        "mclass" ("thumb"),
+        "thumb",
+        "thumb2" ("thumb"),
+        "thumb-mode" ("thumb"),
+        "hwdiv",
+        "hwdiv-arm" ("hwdiv"),
+        "dsp",
+        "vfp2" ("fp64"),
+        "vfp2sp",
+        "vfp3" ("vfp2", "fp64"),
+        "vfp3d16" ("vfp2", "fp64"),
+        "vfp4" ("vfp2", "vfp3", "fp16", "fp64"),
+        "vfp4d16" ("vfp2", "vfp3d16", "fp16", "fp64"),
+        "fp64",
+        "fp16",
+        "d32",
+        "neon" ("vfp2", "vfp3", "d32", "fp64"),
+        "mve" ("dsp"),
+        "mve.fp" ("dsp", "mve", "fp16"),
+        "crypto" ("vfp2", "vfp3", "d32", "fp64", "neon", "aes", "sha2"),
+        "aes" ("vfp2", "vfp3", "d32", "fp64", "neon"),
+        "sha2" ("vfp2", "vfp3", "d32", "fp64", "neon"),
+        "crc",
+        "trustzone",
+        "8msecext",
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of 32-bit ARM.
+    ///
+    /// Exclusive loads and stores were introduced in ARMv6 (word only) and ARMv6K (all widths), and are not available on ARMv6-M.
+    pub static ARM_ATOMICS = [
+        8 ("v6k", "v7m", "v8m"),
+        16 ("v6k", "v7m", "v8m"),
+        32 ("v6", "v7m", "v8m"),
+        64 ("v6k"),
+    ];
+}
+
+/// The list of known 32-bit ARM machines.
+///
+/// Machines with a `thumb` prefix are the same as the corresponding `arm` machines, but generate Thumb code by default.
+pub static ARM_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("armv4t"),
+        features: slice![cowstr!("v4t")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv5te"),
+        features: slice![cowstr!("v5te")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv6"),
+        features: slice![cowstr!("v6"), cowstr!("dsp"), cowstr!("vfp2")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv6k"),
+        features: slice![cowstr!("v6k"), cowstr!("dsp"), cowstr!("vfp2")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv6t2"),
+        features: slice![cowstr!("v6t2"), cowstr!("dsp"), cowstr!("vfp2")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv7-a"),
+        features: slice![
+            cowstr!("v7"),
+            cowstr!("aclass"),
+            cowstr!("dsp"),
+            cowstr!("vfp3"),
+            cowstr!("neon")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv7-a+vfpv3-d16"),
+        features: slice![
+            cowstr!("v7"),
+            cowstr!("aclass"),
+            cowstr!("dsp"),
+            cowstr!("vfp3d16")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv7-r"),
+        features: slice![
+            cowstr!("v7"),
+            cowstr!("rclass"),
+            cowstr!("dsp"),
+            cowstr!("hwdiv"),
+            cowstr!("vfp3d16")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv8-a"),
+        features: slice![
+            cowstr!("v8"),
+            cowstr!("aclass"),
+            cowstr!("vfp4"),
+            cowstr!("neon"),
+            cowstr!("crc"),
+            cowstr!("trustzone")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("armv8-r"),
+        features: slice![
+            cowstr!("v8"),
+            cowstr!("rclass"),
+            cowstr!("vfp4d16"),
+            cowstr!("crc")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("thumbv4t"),
+        features: slice![cowstr!("v4t"), cowstr!("thumb-mode")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("thumbv5te"),
+        features: slice![cowstr!("v5te"), cowstr!("thumb-mode")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("thumbv6m"),
+        features: slice![cowstr!("v6m"), cowstr!("thumb-mode")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("thumbv7-a"),
+        features: slice![
+            cowstr!("v7"),
+            cowstr!("aclass"),
+            cowstr!("dsp"),
+            cowstr!("vfp3"),
+            cowstr!("neon"),
+            cowstr!("thumb-mode")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("thumbv7m"),
+        features: slice![cowstr!("v7m"), cowstr!("thumb-mode")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("thumbv7em"),
+        features: slice![cowstr!("v7em"), cowstr!("thumb-mode")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("thumbv8m.base"),
+        features: slice![cowstr!("v8m"), cowstr!("8msecext"), cowstr!("thumb-mode")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("thumbv8m.main"),
+        features: slice![
+            cowstr!("v8m.main"),
+            cowstr!("8msecext"),
+            cowstr!("thumb-mode")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("thumbv8.1m.main"),
+        features: slice![
+            cowstr!("v8.1m.main"),
+            cowstr!("8msecext"),
+            cowstr!("thumb-mode")
+        ],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// 32-bit ARM call tags
+pub static ARM_TAGS: &[crate::helpers::CowStr] =
+    &[cowstr!("aapcs"), cowstr!("aapcs-vfp"), cowstr!("apcs")];
+
+/// The ASM Specification for 32-bit ARM
+pub static ARM_ASM: Asm = Asm {};
+
+macro_rules! arm_archs {
+    {
+        $($(#[$meta:meta])* $vis:vis static $name:ident ($name_canon:literal) {
+            alias_names: [$($name_alias:literal),* $(,)?],
+            default_machine: $default_machine:literal $(,)?
+        })*
+    } => {
+        $(
+            $(#[$meta])*
+            $vis static $name: Arch = Arch {
+                name: cowstr!($name_canon),
+                alias_names: slice![$(cowstr!($name_alias)),*],
+                machines: cow!(*ARM_MACHINES),
+                raw_width: 32,
+                features: cow!(*ARM_FEATURES),
+                native_atomics: cow!(*ARM_ATOMICS),
+                default_machine: cow!(ARM_MACHINES[$default_machine]),
+                call_tags: cow!(*ARM_TAGS),
+                arch_extended_properties: slice![],
+                asm_spec: Some(cow!(ARM_ASM)),
+            };
+        )*
+    };
+}
+
+arm_archs! {
+    /// 32-bit ARM, little-endian
+    pub static ARM ("arm") {
+        alias_names: ["armel", "thumb"],
+        default_machine: 0,
+    }
+
+    /// 32-bit ARM, big-endian
+    pub static ARMEB ("armeb") {
+        alias_names: ["arm", "thumbeb"],
+        default_machine: 0,
+    }
+
+    /// 32-bit ARM, little-endian, for soft-float Linux (`gnueabi`). The default machine is `armv5te`
+    pub static ARM_V5TE ("arm") {
+        alias_names: ["armel", "thumb"],
+        default_machine: 1,
+    }
+
+    /// 32-bit ARM, big-endian, for soft-float Linux (`gnueabi`). The default machine is `armv5te`
+    pub static ARMEB_V5TE ("armeb") {
+        alias_names: ["arm", "thumbeb"],
+        default_machine: 1,
+    }
+
+    /// 32-bit ARM, little-endian, for the hard-float ABI. The default machine is `armv7-a+vfpv3-d16`
+    pub static ARM_HF ("arm") {
+        alias_names: ["armel", "thumb"],
+        default_machine: 6,
+    }
+
+    /// 32-bit ARM, big-endian, for the hard-float ABI. The default machine is `armv7-a+vfpv3-d16`
+    pub static ARMEB_HF ("armeb") {
+        alias_names: ["arm", "thumbeb"],
+        default_machine: 6,
+    }
+}
+
+/// Selects the 32-bit ARM architecture for a target, so that the default machine matches the float ABI.
+///
+/// This follows [`abi_from_target`][crate::builtin::abi::abi_from_target]: hard-float targets default to `armv7-a+vfpv3-d16`,
+/// soft-float Linux targets (`gnueabi`) to `armv5te`, and all others to `armv4t`.
+pub const fn arch_for_env(arch: Architecture, os: OS, env: Option<Environment>) -> &'static Arch {
+    let big_endian = matches!(arch, Architecture::ArmBe);
+    match (os, env) {
+        (_, Some(Environment::GNUEABI | Environment::MuslEABI)) => {
+            if big_endian {
+                &ARMEB_V5TE
+            } else {
+                &ARM_V5TE
+            }
+        }
+        (_, Some(Environment::EABI)) => {
+            if big_endian {
+                &ARMEB
+            } else {
+                &ARM
+            }
+        }
+        (_, Some(Environment::GNUEABIHF | Environment::EABIHF | Environment::MuslEABIHF))
+        | (OS::Linux | OS::Lilium | OS::Win32 | OS::Darwin | OS::MacOSX | OS::IOS, _) => {
+            if big_endian {
+                &ARMEB_HF
+            } else {
+                &ARM_HF
+            }
+        }
+        _ => {
+            if big_endian {
+                &ARMEB
+            } else {
+                &ARM
+            }
+        }
+    }
+}
+
+/// Default primitives for little-endian 32-bit ARM (AAPCS).
+/// `long long` and `double` are 8-byte aligned
+pub static ARM_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_ILP32,
+    max_int_align: 8,
+    max_bit_int_align: 8,
+    max_simd_align: 8,
+    ldouble_align: 8,
+    ldouble_format: IEEE754_DOUBLE,
+};
+
+/// Default primitives for big-endian 32-bit ARM (AAPCS).
+pub static ARMEB_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_ILP32,
+    ..ARM_PRIMITIVES
+};
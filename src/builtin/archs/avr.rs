@@ -0,0 +1,171 @@
+use crate::{
+    arch_features,
+    properties::{
+        abi::{IEEE754_DOUBLE, LE_IP16, PrimitiveLayouts},
+        arch::{Arch, Asm, Machine},
+    },
+};
+
+arch_features! {
+    /// The list of AVR features
+    pub static AVR_FEATURES = [
+        "sram",
+        "addsubiw",
+        "jmpcall",
+        "eijmpcall" ("jmpcall"),
+        "movw",
+        "lpm",
+        "lpmx" ("lpm"),
+        "elpm" ("lpm"),
+        "elpmx" ("lpm", "lpmx", "elpm"),
+        "spm",
+        "spmx" ("spm"),
+        "des",
+        "mul",
+        "break",
+        "rmw",
+        "tinyencoding",
+    ];
+}
+
+/// The list of known AVR machines. These are the AVR families, as used by `-mmcu`
+pub static AVR_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("avr2"),
+        features: slice![cowstr!("sram"), cowstr!("addsubiw"), cowstr!("lpm")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("avr1"),
+        features: slice![cowstr!("lpm")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("avr25"),
+        features: slice![
+            cowstr!("sram"),
+            cowstr!("addsubiw"),
+            cowstr!("lpmx"),
+            cowstr!("movw"),
+            cowstr!("spm"),
+            cowstr!("break")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("avr3"),
+        features: slice![
+            cowstr!("sram"),
+            cowstr!("addsubiw"),
+            cowstr!("jmpcall"),
+            cowstr!("lpm")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("avr4"),
+        features: slice![
+            cowstr!("sram"),
+            cowstr!("addsubiw"),
+            cowstr!("lpmx"),
+            cowstr!("movw"),
+            cowstr!("mul"),
+            cowstr!("spm"),
+            cowstr!("break")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("avr5"),
+        features: slice![
+            cowstr!("sram"),
+            cowstr!("addsubiw"),
+            cowstr!("jmpcall"),
+            cowstr!("lpmx"),
+            cowstr!("movw"),
+            cowstr!("mul"),
+            cowstr!("spm"),
+            cowstr!("break")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("avr6"),
+        features: slice![
+            cowstr!("sram"),
+            cowstr!("addsubiw"),
+            cowstr!("eijmpcall"),
+            cowstr!("elpmx"),
+            cowstr!("movw"),
+            cowstr!("mul"),
+            cowstr!("spm"),
+            cowstr!("break")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("avrxmega3"),
+        features: slice![
+            cowstr!("sram"),
+            cowstr!("addsubiw"),
+            cowstr!("jmpcall"),
+            cowstr!("lpmx"),
+            cowstr!("movw"),
+            cowstr!("mul"),
+            cowstr!("spmx"),
+            cowstr!("des"),
+            cowstr!("break")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("avrxmega6"),
+        features: slice![
+            cowstr!("sram"),
+            cowstr!("addsubiw"),
+            cowstr!("eijmpcall"),
+            cowstr!("elpmx"),
+            cowstr!("movw"),
+            cowstr!("mul"),
+            cowstr!("spmx"),
+            cowstr!("des"),
+            cowstr!("rmw"),
+            cowstr!("break")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("avrtiny"),
+        features: slice![cowstr!("sram"), cowstr!("break"), cowstr!("tinyencoding")],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// The ASM Specification for AVR
+pub static AVR_ASM: Asm = Asm {};
+
+/// Atmel/Microchip AVR
+pub static AVR: Arch = Arch {
+    name: cowstr!("avr"),
+    alias_names: slice![],
+    machines: cow!(*AVR_MACHINES),
+    raw_width: 8,
+    features: cow!(*AVR_FEATURES),
+    native_atomics: slice![],
+    default_machine: cow!(AVR_MACHINES[0]),
+    call_tags: slice![cowstr!("C")],
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(AVR_ASM)),
+};
+
+/// Default primitives for AVR. `int` and pointers are 16-bit, and no type is aligned to more than a byte.
+///
+/// `long double` uses binary64, as selected by `-mlong-double=64`.
+pub static AVR_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_IP16,
+    max_int_align: 1,
+    max_bit_int_align: 1,
+    max_simd_align: 1,
+    ldouble_align: 1,
+    ldouble_format: IEEE754_DOUBLE,
+};
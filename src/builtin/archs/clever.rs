@@ -1,4 +1,5 @@
 use crate::arch_features;
+use crate::native_atomics;
 use crate::properties::ExtPropertyValue;
 use crate::properties::arch::{Arch, Asm, Machine};
 
@@ -17,6 +18,18 @@ arch_features! {
     ];
 }
 
+native_atomics! {
+    /// The native atomic widths of Clever-ISA.
+    ///
+    /// Atomic exchange and compare-exchange are provided by the `atomic-xchg` extension.
+    pub static CLEVER_ATOMICS = [
+        8 ("atomic-xchg"),
+        16 ("atomic-xchg"),
+        32 ("atomic-xchg"),
+        64 ("atomic-xchg"),
+    ];
+}
+
 /// This list of known Clever-ISA machines
 pub static CLEVER_MACHINES: &[Machine] = &[
     Machine {
@@ -45,6 +58,7 @@ pub static CLEVER: Arch = Arch {
     alias_names: slice![],
     raw_width: 64,
     features: cow!(*CLEVER_FEATURES),
+    native_atomics: cow!(*CLEVER_ATOMICS),
     default_machine: cow!(CLEVER_MACHINES[0]),
     call_tags: slice![cowstr!("C")],
     arch_extended_properties: slice![(
@@ -0,0 +1,44 @@
+use crate::arch_features;
+use crate::properties::abi::{IEEE754_DOUBLE, LE_LP64, PrimitiveLayouts};
+use crate::properties::arch::{Arch, Asm, Machine};
+
+arch_features! {
+    /// The List of features for Holey Bytes
+    pub static HOLEYBYTES_FEATURES = [
+        "float",
+    ];
+}
+
+/// This list of known Holey Bytes machines
+pub static HOLEYBYTES_MACHINES: &[Machine] = &[Machine {
+    name: cowstr!("hbvm"),
+    features: slice![cowstr!("float")],
+    mach_extended_properties: slice![],
+}];
+
+/// The ASM Specification for Holey Bytes
+pub static HOLEYBYTES_ASM: Asm = Asm {};
+
+/// Holey Bytes
+pub static HOLEYBYTES: Arch = Arch {
+    name: cowstr!("holeybytes"),
+    alias_names: slice![cowstr!("hbvm"), cowstr!("hb")],
+    raw_width: 64,
+    features: cow!(*HOLEYBYTES_FEATURES),
+    native_atomics: slice![],
+    default_machine: cow!(HOLEYBYTES_MACHINES[0]),
+    call_tags: slice![cowstr!("C")],
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(HOLEYBYTES_ASM)),
+    machines: cow!(*HOLEYBYTES_MACHINES),
+};
+
+/// Default Primitives for Holey Bytes
+pub static HOLEYBYTES_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LP64,
+    max_int_align: 8,
+    max_bit_int_align: 8,
+    max_simd_align: 8,
+    ldouble_align: 8,
+    ldouble_format: IEEE754_DOUBLE,
+};
@@ -49,6 +49,7 @@ pub static M6502: Arch = Arch {
     machines: cow!(*M6502_MACHINES),
     raw_width: 8,
     features: cow!(*M6502_FEATURES),
+    native_atomics: slice![],
     default_machine: cow!(M6502_MACHINES[0]),
     call_tags: slice![cowstr!("C")],
     arch_extended_properties: slice![],
@@ -62,6 +63,7 @@ pub static M65C02: Arch = Arch {
     machines: cow!(*M6502_MACHINES),
     raw_width: 8,
     features: cow!(*M6502_FEATURES),
+    native_atomics: slice![],
     default_machine: cow!(M6502_MACHINES[2]),
     call_tags: slice![cowstr!("C")],
     arch_extended_properties: slice![],
@@ -75,6 +77,7 @@ pub static W65: Arch = Arch {
     machines: cow!(*W65_MACHINES),
     raw_width: 16,
     features: cow!([]),
+    native_atomics: slice![],
     default_machine: cow!(W65_MACHINES[0]),
     call_tags: slice![cowstr!("C")],
     arch_extended_properties: slice![],
@@ -0,0 +1,130 @@
+use crate::{
+    arch_features, native_atomics,
+    properties::{
+        abi::{BE_ILP32, BE_LP32, FloatFormat, IntLayouts, PrimitiveLayouts},
+        arch::{Arch, Asm, Machine},
+    },
+};
+
+arch_features! {
+    /// The list of m68k features
+    pub static M68K_FEATURES = [
+        "68000",
+        "68010" ("68000"),
+        "68020" ("68000", "68010"),
+        "68030" ("68000", "68010", "68020"),
+        "68040" ("68000", "68010", "68020", "68030", "68881", "68882"),
+        "68060" ("68000", "68010", "68020", "68030", "68040", "68881", "68882"),
+        "cpu32" ("68000", "68010"),
+        "coldfire",
+        "68881",
+        "68882" ("68881"),
+        "isa-a" ("coldfire"),
+        "isa-b" ("coldfire", "isa-a"),
+        "isa-c" ("coldfire", "isa-a", "isa-b"),
+        "cfv4e" ("coldfire", "isa-a", "isa-b"),
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of m68k.
+    ///
+    /// Compare-and-swap (`cas`) was introduced in the 68020.
+    pub static M68K_ATOMICS = [
+        8 ("68020"),
+        16 ("68020"),
+        32 ("68020"),
+    ];
+}
+
+/// The list of known m68k machines
+pub static M68K_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("68020"),
+        features: slice![cowstr!("68020"), cowstr!("68881")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("68000"),
+        features: slice![cowstr!("68000")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("68010"),
+        features: slice![cowstr!("68010")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("68030"),
+        features: slice![cowstr!("68030"), cowstr!("68882")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("68040"),
+        features: slice![cowstr!("68040")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("68060"),
+        features: slice![cowstr!("68060")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("cpu32"),
+        features: slice![cowstr!("cpu32")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("5206e"),
+        features: slice![cowstr!("isa-a")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("5475"),
+        features: slice![cowstr!("cfv4e")],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// The ASM Specification for m68k
+pub static M68K_ASM: Asm = Asm {};
+
+/// Motorola 68000 series
+pub static M68K: Arch = Arch {
+    name: cowstr!("m68k"),
+    alias_names: slice![cowstr!("m68000"), cowstr!("coldfire")],
+    machines: cow!(*M68K_MACHINES),
+    raw_width: 32,
+    features: cow!(*M68K_FEATURES),
+    native_atomics: cow!(*M68K_ATOMICS),
+    default_machine: cow!(M68K_MACHINES[0]),
+    call_tags: slice![cowstr!("C"), cowstr!("rtd")],
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(M68K_ASM)),
+};
+
+/// The m68881 extended precision format. This is an 80-bit value with an explicit integer bit, stored in 12 bytes
+pub const M68881_EXTENDED: FloatFormat = FloatFormat::Ieee754Like {
+    exp_bits: nzlit!(15),
+    repr_int_bit: true,
+    mant_bits: nzlit!(63),
+};
+
+/// Default primitives for m68k (System V). All types larger than a byte are only 2-byte aligned
+pub static M68K_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_ILP32,
+    max_int_align: 2,
+    max_bit_int_align: 2,
+    max_simd_align: 2,
+    ldouble_align: 2,
+    ldouble_format: M68881_EXTENDED,
+};
+
+/// Primitives for m68k with `-mshort` (16-bit `int`)
+pub static M68K_SHORT_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: IntLayouts {
+        size_width: 32,
+        ..BE_LP32
+    },
+    ..M68K_PRIMITIVES
+};
@@ -0,0 +1,358 @@
+use target_tuples::pieces::{Architecture, Environment};
+
+use crate::{
+    arch_features, native_atomics,
+    properties::{
+        abi::{
+            BE_ILP32, BE_LP64, IEEE754_DOUBLE, IEEE764_QUAD, LE_ILP32, LE_LP64, PrimitiveLayouts,
+        },
+        arch::{Arch, Asm, Machine},
+    },
+};
+
+arch_features! {
+    /// The list of MIPS features
+    pub static MIPS_FEATURES = [
+        "mips1",
+        "mips2" ("mips1"),
+        "mips3" ("mips1", "mips2", "64bit"),
+        "mips4" ("mips1", "mips2", "mips3", "64bit"),
+        "mips5" ("mips1", "mips2", "mips3", "mips4", "64bit"),
+        "mips32" ("mips1", "mips2"),
+        "mips32r2" ("mips1", "mips2", "mips32"),
+        "mips32r5" ("mips1", "mips2", "mips32", "mips32r2"),
+        "mips32r6" ("mips1", "mips2", "mips32", "mips32r2", "mips32r5", "nan2008", "abs2008", "fp64"),
+        "mips64" ("mips1", "mips2", "mips3", "mips4", "mips5", "mips32", "64bit"),
+        "mips64r2" ("mips1", "mips2", "mips3", "mips4", "mips5", "mips32", "mips32r2", "mips64", "64bit"),
+        "mips64r5" ("mips1", "mips2", "mips3", "mips4", "mips5", "mips32", "mips32r2", "mips32r5", "mips64", "mips64r2", "64bit"),
+        "mips64r6" ("mips1", "mips2", "mips3", "mips4", "mips5", "mips32", "mips32r2", "mips32r5", "mips32r6", "mips64", "mips64r2", "mips64r5", "64bit", "nan2008", "abs2008", "fp64"),
+        "64bit",
+        "soft-float",
+        "single-float",
+        "fpxx",
+        "fp64",
+        "nan2008",
+        "abs2008",
+        "msa" ("fp64"),
+        "dsp",
+        "dspr2" ("dsp"),
+        "dspr3" ("dsp", "dspr2"),
+        "mips16",
+        "micromips",
+        "eva",
+        "virt",
+        "crc",
+        "ginv",
+        "mt",
+        "cnmips" ("mips64r2"),
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of 32-bit MIPS.
+    ///
+    /// Load-linked/store-conditional (`ll`/`sc`) was introduced in MIPS II.
+    pub static MIPS_ATOMICS = [
+        8 ("mips2"),
+        16 ("mips2"),
+        32 ("mips2"),
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of 64-bit MIPS.
+    ///
+    /// 64-bit load-linked/store-conditional (`lld`/`scd`) was introduced in MIPS III.
+    pub static MIPS64_ATOMICS = [
+        8 ("mips2"),
+        16 ("mips2"),
+        32 ("mips2"),
+        64 ("mips3"),
+    ];
+}
+
+/// The list of known 32-bit MIPS machines
+pub static MIPS32_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("mips32r2"),
+        features: slice![cowstr!("mips32r2"), cowstr!("fpxx")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips1"),
+        features: slice![cowstr!("mips1")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips2"),
+        features: slice![cowstr!("mips2")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips32"),
+        features: slice![cowstr!("mips32")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips32r5"),
+        features: slice![cowstr!("mips32r5"), cowstr!("fpxx")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips32r6"),
+        features: slice![cowstr!("mips32r6")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("24kc"),
+        features: slice![
+            cowstr!("mips32r2"),
+            cowstr!("mips16"),
+            cowstr!("soft-float")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("74kf"),
+        features: slice![
+            cowstr!("mips32r2"),
+            cowstr!("mips16"),
+            cowstr!("dsp"),
+            cowstr!("dspr2")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("p5600"),
+        features: slice![
+            cowstr!("mips32r5"),
+            cowstr!("msa"),
+            cowstr!("eva"),
+            cowstr!("virt")
+        ],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// The list of known 64-bit MIPS machines
+pub static MIPS64_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("mips64r2"),
+        features: slice![cowstr!("mips64r2")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips3"),
+        features: slice![cowstr!("mips3")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips4"),
+        features: slice![cowstr!("mips4")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips64"),
+        features: slice![cowstr!("mips64")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips64r5"),
+        features: slice![cowstr!("mips64r5")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mips64r6"),
+        features: slice![cowstr!("mips64r6")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("octeon"),
+        features: slice![cowstr!("mips64r2"), cowstr!("cnmips")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("i6400"),
+        features: slice![
+            cowstr!("mips64r6"),
+            cowstr!("msa"),
+            cowstr!("crc"),
+            cowstr!("ginv")
+        ],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// MIPS call tags
+pub static MIPS_TAGS: &[crate::helpers::CowStr] = &[
+    cowstr!("o32"),
+    cowstr!("n32"),
+    cowstr!("n64"),
+    cowstr!("eabi"),
+];
+
+/// The ASM Specification for MIPS
+pub static MIPS_ASM: Asm = Asm {};
+
+/// 32-bit MIPS, big-endian
+pub static MIPS: Arch = Arch {
+    name: cowstr!("mips"),
+    alias_names: slice![cowstr!("mipseb")],
+    machines: cow!(*MIPS32_MACHINES),
+    raw_width: 32,
+    features: cow!(*MIPS_FEATURES),
+    native_atomics: cow!(*MIPS_ATOMICS),
+    default_machine: cow!(MIPS32_MACHINES[0]),
+    call_tags: cow!(*MIPS_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(MIPS_ASM)),
+};
+
+/// 32-bit MIPS, little-endian
+pub static MIPSEL: Arch = Arch {
+    name: cowstr!("mipsel"),
+    alias_names: slice![cowstr!("mips")],
+    machines: cow!(*MIPS32_MACHINES),
+    raw_width: 32,
+    features: cow!(*MIPS_FEATURES),
+    native_atomics: cow!(*MIPS_ATOMICS),
+    default_machine: cow!(MIPS32_MACHINES[0]),
+    call_tags: cow!(*MIPS_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(MIPS_ASM)),
+};
+
+/// 64-bit MIPS, big-endian
+pub static MIPS64: Arch = Arch {
+    name: cowstr!("mips64"),
+    alias_names: slice![cowstr!("mips"), cowstr!("mips64eb")],
+    machines: cow!(*MIPS64_MACHINES),
+    raw_width: 64,
+    features: cow!(*MIPS_FEATURES),
+    native_atomics: cow!(*MIPS64_ATOMICS),
+    default_machine: cow!(MIPS64_MACHINES[0]),
+    call_tags: cow!(*MIPS_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(MIPS_ASM)),
+};
+
+/// 64-bit MIPS, little-endian
+pub static MIPS64EL: Arch = Arch {
+    name: cowstr!("mips64el"),
+    alias_names: slice![cowstr!("mips"), cowstr!("mipsel")],
+    machines: cow!(*MIPS64_MACHINES),
+    raw_width: 64,
+    features: cow!(*MIPS_FEATURES),
+    native_atomics: cow!(*MIPS64_ATOMICS),
+    default_machine: cow!(MIPS64_MACHINES[0]),
+    call_tags: cow!(*MIPS_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(MIPS_ASM)),
+};
+
+/// Primitives for big-endian MIPS o32. `long double` is binary64
+pub static MIPS_O32_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_ILP32,
+    max_int_align: 8,
+    max_bit_int_align: 8,
+    max_simd_align: 16,
+    ldouble_align: 8,
+    ldouble_format: IEEE754_DOUBLE,
+};
+
+/// Primitives for little-endian MIPS o32. `long double` is binary64
+pub static MIPSEL_O32_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_ILP32,
+    ..MIPS_O32_PRIMITIVES
+};
+
+/// Primitives for big-endian MIPS n32. `long double` is binary128
+pub static MIPS_N32_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_ILP32,
+    max_int_align: 8,
+    max_bit_int_align: 8,
+    max_simd_align: 16,
+    ldouble_align: 16,
+    ldouble_format: IEEE764_QUAD,
+};
+
+/// Primitives for little-endian MIPS n32. `long double` is binary128
+pub static MIPSEL_N32_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_ILP32,
+    ..MIPS_N32_PRIMITIVES
+};
+
+/// Primitives for big-endian MIPS n64. `long double` is binary128
+pub static MIPS_N64_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_LP64,
+    max_int_align: 16,
+    max_bit_int_align: 8,
+    max_simd_align: 16,
+    ldouble_align: 16,
+    ldouble_format: IEEE764_QUAD,
+};
+
+/// Primitives for little-endian MIPS n64. `long double` is binary128
+pub static MIPSEL_N64_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LP64,
+    ..MIPS_N64_PRIMITIVES
+};
+
+/// The MIPS ABI variants
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum MipsAbi {
+    /// The 32-bit o32 ABI
+    O32,
+    /// The ILP32 ABI for 64-bit processors
+    N32,
+    /// The LP64 ABI for 64-bit processors
+    N64,
+}
+
+impl MipsAbi {
+    /// Parses the value of a `-mabi` flag
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "32" | "o32" => Some(Self::O32),
+            "n32" => Some(Self::N32),
+            "64" | "n64" => Some(Self::N64),
+            _ => None,
+        }
+    }
+
+    /// The name of the ABI, which is also the call tag
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::O32 => "o32",
+            Self::N32 => "n32",
+            Self::N64 => "n64",
+        }
+    }
+
+    /// The default ABI for a given MIPS architecture and environment
+    pub const fn default_for(arch: Architecture, env: Option<Environment>) -> Option<Self> {
+        match (arch, env) {
+            (Architecture::Mips | Architecture::MipsLE, _) => Some(Self::O32),
+            (Architecture::Mips64 | Architecture::Mips64LE, Some(Environment::GNUABIN32)) => {
+                Some(Self::N32)
+            }
+            (Architecture::Mips64 | Architecture::Mips64LE, _) => Some(Self::N64),
+            _ => None,
+        }
+    }
+
+    /// Obtains the [`PrimitiveLayouts`] for the ABI on the given MIPS architecture
+    pub const fn primitives(self, arch: Architecture) -> Option<&'static PrimitiveLayouts> {
+        match (arch, self) {
+            (Architecture::Mips | Architecture::Mips64, Self::O32) => Some(&MIPS_O32_PRIMITIVES),
+            (Architecture::MipsLE | Architecture::Mips64LE, Self::O32) => {
+                Some(&MIPSEL_O32_PRIMITIVES)
+            }
+            (Architecture::Mips64, Self::N32) => Some(&MIPS_N32_PRIMITIVES),
+            (Architecture::Mips64LE, Self::N32) => Some(&MIPSEL_N32_PRIMITIVES),
+            (Architecture::Mips64, Self::N64) => Some(&MIPS_N64_PRIMITIVES),
+            (Architecture::Mips64LE, Self::N64) => Some(&MIPSEL_N64_PRIMITIVES),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,498 @@
+use target_tuples::pieces::{Architecture, Environment, OS};
+
+use crate::{
+    arch_features, native_atomics,
+    properties::{
+        abi::{
+            BE_ILP32, BE_LP64, FloatFormat, IEEE754_DOUBLE, IEEE764_QUAD, LE_LP64, PrimitiveLayouts,
+        },
+        arch::{Arch, Asm, Machine},
+    },
+};
+
+arch_features! {
+    /// The list of PowerPC features
+    pub static PPC_FEATURES = [
+        "64bit",
+        "hard-float",
+        "spe",
+        "fsqrt" ("hard-float"),
+        "fres" ("hard-float"),
+        "fprnd" ("hard-float"),
+        "mfocrf",
+        "popcntd",
+        "cmpb",
+        "isel",
+        "altivec" ("hard-float"),
+        "vsx" ("hard-float", "altivec"),
+        "power8-altivec" ("hard-float", "altivec"),
+        "power8-vector" ("hard-float", "altivec", "vsx", "power8-altivec"),
+        "power9-altivec" ("hard-float", "altivec", "power8-altivec"),
+        "power9-vector" ("hard-float", "altivec", "vsx", "power8-altivec", "power8-vector", "power9-altivec"),
+        "power10-vector" ("hard-float", "altivec", "vsx", "power8-altivec", "power8-vector", "power9-altivec", "power9-vector"),
+        "crypto" ("hard-float", "altivec", "power8-altivec"),
+        "direct-move" ("hard-float", "altivec", "vsx"),
+        "htm",
+        "float128" ("hard-float", "altivec", "vsx"),
+        "mma" ("hard-float", "altivec", "vsx", "power8-altivec", "power8-vector", "power9-altivec", "power9-vector", "paired-vector-memops"),
+        "paired-vector-memops",
+        "prefix-instrs",
+        "pcrelative-memops" ("prefix-instrs"),
+        "isa-v206-instructions",
+        "isa-v207-instructions" ("isa-v206-instructions"),
+        "isa-v30-instructions" ("isa-v206-instructions", "isa-v207-instructions"),
+        "isa-v31-instructions" ("isa-v206-instructions", "isa-v207-instructions", "isa-v30-instructions"),
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of 32-bit PowerPC.
+    ///
+    /// Narrower operations are performed on the containing word with `lwarx`/`stwcx.`.
+    pub static PPC_ATOMICS = [
+        8, 16, 32,
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of 64-bit PowerPC.
+    ///
+    /// Quadword operations (`lqarx`/`stqcx.`) were introduced in Power ISA 2.07.
+    pub static PPC64_ATOMICS = [
+        8, 16, 32, 64,
+        128 ("isa-v207-instructions"),
+    ];
+}
+
+/// The list of known 32-bit PowerPC machines
+pub static PPC32_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("ppc"),
+        features: slice![cowstr!("hard-float")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("603e"),
+        features: slice![cowstr!("hard-float"), cowstr!("fres")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("750"),
+        features: slice![cowstr!("hard-float"), cowstr!("fres")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("7400"),
+        features: slice![cowstr!("hard-float"), cowstr!("fres"), cowstr!("altivec")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("e500"),
+        features: slice![cowstr!("spe"), cowstr!("isel")],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// The list of known 64-bit PowerPC machines
+pub static PPC64_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("ppc64"),
+        features: slice![cowstr!("64bit"), cowstr!("hard-float"), cowstr!("fsqrt")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("970"),
+        features: slice![
+            cowstr!("64bit"),
+            cowstr!("hard-float"),
+            cowstr!("fsqrt"),
+            cowstr!("fres"),
+            cowstr!("mfocrf"),
+            cowstr!("altivec")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("pwr7"),
+        features: slice![
+            cowstr!("64bit"),
+            cowstr!("hard-float"),
+            cowstr!("fsqrt"),
+            cowstr!("fres"),
+            cowstr!("fprnd"),
+            cowstr!("mfocrf"),
+            cowstr!("popcntd"),
+            cowstr!("cmpb"),
+            cowstr!("isel"),
+            cowstr!("vsx"),
+            cowstr!("isa-v206-instructions")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("pwr8"),
+        features: slice![
+            cowstr!("64bit"),
+            cowstr!("hard-float"),
+            cowstr!("fsqrt"),
+            cowstr!("fres"),
+            cowstr!("fprnd"),
+            cowstr!("mfocrf"),
+            cowstr!("popcntd"),
+            cowstr!("cmpb"),
+            cowstr!("isel"),
+            cowstr!("power8-vector"),
+            cowstr!("crypto"),
+            cowstr!("direct-move"),
+            cowstr!("htm"),
+            cowstr!("isa-v207-instructions")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("pwr9"),
+        features: slice![
+            cowstr!("64bit"),
+            cowstr!("hard-float"),
+            cowstr!("fsqrt"),
+            cowstr!("fres"),
+            cowstr!("fprnd"),
+            cowstr!("mfocrf"),
+            cowstr!("popcntd"),
+            cowstr!("cmpb"),
+            cowstr!("isel"),
+            cowstr!("power9-vector"),
+            cowstr!("crypto"),
+            cowstr!("direct-move"),
+            cowstr!("htm"),
+            cowstr!("float128"),
+            cowstr!("isa-v30-instructions")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("pwr10"),
+        features: slice![
+            cowstr!("64bit"),
+            cowstr!("hard-float"),
+            cowstr!("fsqrt"),
+            cowstr!("fres"),
+            cowstr!("fprnd"),
+            cowstr!("mfocrf"),
+            cowstr!("popcntd"),
+            cowstr!("cmpb"),
+            cowstr!("isel"),
+            cowstr!("power10-vector"),
+            cowstr!("crypto"),
+            cowstr!("direct-move"),
+            cowstr!("float128"),
+            cowstr!("mma"),
+            cowstr!("pcrelative-memops"),
+            cowstr!("isa-v31-instructions")
+        ],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// PowerPC call tags
+pub mod tags {
+    use crate::helpers::CowStr;
+
+    /// 32-bit PowerPC call tags
+    pub static PPC32: &[CowStr] = &[cowstr!("sysv"), cowstr!("eabi")];
+
+    /// 64-bit PowerPC call tags
+    pub static PPC64: &[CowStr] = &[cowstr!("elfv1"), cowstr!("elfv2"), cowstr!("aix")];
+}
+
+/// The ASM Specification for PowerPC
+pub static PPC_ASM: Asm = Asm {};
+
+/// 32-bit PowerPC (big-endian)
+pub static POWERPC: Arch = Arch {
+    name: cowstr!("powerpc"),
+    alias_names: slice![cowstr!("ppc"), cowstr!("ppc32")],
+    machines: cow!(*PPC32_MACHINES),
+    raw_width: 32,
+    features: cow!(*PPC_FEATURES),
+    native_atomics: cow!(*PPC_ATOMICS),
+    default_machine: cow!(PPC32_MACHINES[0]),
+    call_tags: cow!(*tags::PPC32),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(PPC_ASM)),
+};
+
+/// 64-bit PowerPC (big-endian)
+pub static POWERPC64: Arch = Arch {
+    name: cowstr!("powerpc64"),
+    alias_names: slice![cowstr!("ppc64")],
+    machines: cow!(*PPC64_MACHINES),
+    raw_width: 64,
+    features: cow!(*PPC_FEATURES),
+    native_atomics: cow!(*PPC64_ATOMICS),
+    default_machine: cow!(PPC64_MACHINES[0]),
+    call_tags: cow!(*tags::PPC64),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(PPC_ASM)),
+};
+
+/// 64-bit PowerPC (little-endian). The baseline is POWER8
+pub static POWERPC64LE: Arch = Arch {
+    name: cowstr!("powerpc64le"),
+    alias_names: slice![cowstr!("ppc64le")],
+    machines: cow!(*PPC64_MACHINES),
+    raw_width: 64,
+    features: cow!(*PPC_FEATURES),
+    native_atomics: cow!(*PPC64_ATOMICS),
+    default_machine: cow!(PPC64_MACHINES[3]),
+    call_tags: cow!(*tags::PPC64),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(PPC_ASM)),
+};
+
+/// Primitives for 32-bit PowerPC using IBM double-double `long double`
+pub static PPC32_PRIMITIVES_IBM128: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_ILP32,
+    max_int_align: 8,
+    max_bit_int_align: 8,
+    max_simd_align: 16,
+    ldouble_align: 16,
+    ldouble_format: FloatFormat::Ibm128,
+};
+
+/// Primitives for 32-bit PowerPC using ieee754-binary128 `long double`
+pub static PPC32_PRIMITIVES_IEEE128: PrimitiveLayouts = PrimitiveLayouts {
+    ldouble_format: IEEE764_QUAD,
+    ..PPC32_PRIMITIVES_IBM128
+};
+
+/// Primitives for 32-bit PowerPC using binary64 `long double`
+pub static PPC32_PRIMITIVES_F64: PrimitiveLayouts = PrimitiveLayouts {
+    ldouble_align: 8,
+    ldouble_format: IEEE754_DOUBLE,
+    ..PPC32_PRIMITIVES_IBM128
+};
+
+/// Primitives for big-endian 64-bit PowerPC using IBM double-double `long double`
+pub static PPC64_PRIMITIVES_IBM128: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_LP64,
+    max_int_align: 16,
+    max_bit_int_align: 8,
+    max_simd_align: 16,
+    ldouble_align: 16,
+    ldouble_format: FloatFormat::Ibm128,
+};
+
+/// Primitives for big-endian 64-bit PowerPC using ieee754-binary128 `long double`
+pub static PPC64_PRIMITIVES_IEEE128: PrimitiveLayouts = PrimitiveLayouts {
+    ldouble_format: IEEE764_QUAD,
+    ..PPC64_PRIMITIVES_IBM128
+};
+
+/// Primitives for big-endian 64-bit PowerPC using binary64 `long double`
+pub static PPC64_PRIMITIVES_F64: PrimitiveLayouts = PrimitiveLayouts {
+    ldouble_align: 8,
+    ldouble_format: IEEE754_DOUBLE,
+    ..PPC64_PRIMITIVES_IBM128
+};
+
+/// Primitives for little-endian 64-bit PowerPC using IBM double-double `long double`
+pub static PPC64LE_PRIMITIVES_IBM128: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LP64,
+    ..PPC64_PRIMITIVES_IBM128
+};
+
+/// Primitives for little-endian 64-bit PowerPC using ieee754-binary128 `long double`
+pub static PPC64LE_PRIMITIVES_IEEE128: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LP64,
+    ..PPC64_PRIMITIVES_IEEE128
+};
+
+/// Primitives for little-endian 64-bit PowerPC using binary64 `long double`
+pub static PPC64LE_PRIMITIVES_F64: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LP64,
+    ..PPC64_PRIMITIVES_F64
+};
+
+/// The format of `long double` on PowerPC, which can be selected by compiler flags.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum PpcLongDouble {
+    /// IBM double-double ([`FloatFormat::Ibm128`]). Selected by `-mabi=ibmlongdouble`
+    Ibm128,
+    /// ieee754-binary128 ([`IEEE764_QUAD`]). Selected by `-mabi=ieeelongdouble`
+    Ieee128,
+    /// ieee754-binary64. Selected by `-mlong-double-64`
+    Double,
+}
+
+impl PpcLongDouble {
+    /// Parses a `long double` selection flag (`-mabi=ibmlongdouble`, `-mabi=ieeelongdouble`, or `-mlong-double-64`).
+    /// The leading `-` and the `mabi=` prefix are optional
+    pub fn from_flag(flag: &str) -> Option<Self> {
+        let flag = flag.trim_start_matches('-');
+        match flag.strip_prefix("mabi=").unwrap_or(flag) {
+            "ibmlongdouble" => Some(Self::Ibm128),
+            "ieeelongdouble" => Some(Self::Ieee128),
+            "mlong-double-64" | "long-double-64" => Some(Self::Double),
+            "mlong-double-128" | "long-double-128" => Some(Self::Ibm128),
+            _ => None,
+        }
+    }
+
+    /// The default `long double` format for the given PowerPC target
+    pub const fn default_for(arch: Architecture, os: OS, env: Option<Environment>) -> Option<Self> {
+        match (arch, os, env) {
+            (
+                Architecture::PowerPC32 | Architecture::PowerPC64 | Architecture::PowerPC64le,
+                _,
+                Some(Environment::Musl),
+            ) => Some(Self::Double),
+            (
+                Architecture::PowerPC32 | Architecture::PowerPC64 | Architecture::PowerPC64le,
+                OS::Linux | OS::AIX,
+                _,
+            ) => Some(Self::Ibm128),
+            (
+                Architecture::PowerPC32 | Architecture::PowerPC64 | Architecture::PowerPC64le,
+                _,
+                _,
+            ) => Some(Self::Double),
+            _ => None,
+        }
+    }
+
+    /// Obtains the [`PrimitiveLayouts`] for the given PowerPC architecture using this `long double` format
+    pub const fn primitives(self, arch: Architecture) -> Option<&'static PrimitiveLayouts> {
+        match (arch, self) {
+            (Architecture::PowerPC32, Self::Ibm128) => Some(&PPC32_PRIMITIVES_IBM128),
+            (Architecture::PowerPC32, Self::Ieee128) => Some(&PPC32_PRIMITIVES_IEEE128),
+            (Architecture::PowerPC32, Self::Double) => Some(&PPC32_PRIMITIVES_F64),
+            (Architecture::PowerPC64, Self::Ibm128) => Some(&PPC64_PRIMITIVES_IBM128),
+            (Architecture::PowerPC64, Self::Ieee128) => Some(&PPC64_PRIMITIVES_IEEE128),
+            (Architecture::PowerPC64, Self::Double) => Some(&PPC64_PRIMITIVES_F64),
+            (Architecture::PowerPC64le, Self::Ibm128) => Some(&PPC64LE_PRIMITIVES_IBM128),
+            (Architecture::PowerPC64le, Self::Ieee128) => Some(&PPC64LE_PRIMITIVES_IEEE128),
+            (Architecture::PowerPC64le, Self::Double) => Some(&PPC64LE_PRIMITIVES_F64),
+            _ => None,
+        }
+    }
+}
+
+/// Determines the default ELF ABI tag (`sysv`, `elfv1` or `elfv2`) for the given PowerPC target.
+///
+/// Little-endian 64-bit targets, and big-endian musl targets, use ELFv2. Other big-endian 64-bit targets use ELFv1.
+pub const fn default_elf_abi(arch: Architecture, env: Option<Environment>) -> Option<&'static str> {
+    match (arch, env) {
+        (Architecture::PowerPC32, _) => Some("sysv"),
+        (Architecture::PowerPC64le, _) | (Architecture::PowerPC64, Some(Environment::Musl)) => {
+            Some("elfv2")
+        }
+        (Architecture::PowerPC64, _) => Some("elfv1"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_double_flags() {
+        assert_eq!(
+            PpcLongDouble::from_flag("-mabi=ibmlongdouble"),
+            Some(PpcLongDouble::Ibm128)
+        );
+        assert_eq!(
+            PpcLongDouble::from_flag("mabi=ieeelongdouble"),
+            Some(PpcLongDouble::Ieee128)
+        );
+        assert_eq!(
+            PpcLongDouble::from_flag("ieeelongdouble"),
+            Some(PpcLongDouble::Ieee128)
+        );
+        assert_eq!(
+            PpcLongDouble::from_flag("-mlong-double-64"),
+            Some(PpcLongDouble::Double)
+        );
+        assert_eq!(
+            PpcLongDouble::from_flag("-mlong-double-128"),
+            Some(PpcLongDouble::Ibm128)
+        );
+        assert_eq!(PpcLongDouble::from_flag("-mabi=elfv2"), None);
+    }
+
+    #[test]
+    fn default_long_double() {
+        use Architecture::{PowerPC32, PowerPC64, PowerPC64le};
+        assert_eq!(
+            PpcLongDouble::default_for(PowerPC64le, OS::Linux, Some(Environment::GNU)),
+            Some(PpcLongDouble::Ibm128)
+        );
+        assert_eq!(
+            PpcLongDouble::default_for(PowerPC64, OS::Linux, Some(Environment::Musl)),
+            Some(PpcLongDouble::Double)
+        );
+        assert_eq!(
+            PpcLongDouble::default_for(PowerPC32, OS::None, None),
+            Some(PpcLongDouble::Double)
+        );
+        assert_eq!(
+            PpcLongDouble::default_for(Architecture::Aarch64, OS::Linux, None),
+            None
+        );
+    }
+
+    #[test]
+    fn primitives() {
+        use Architecture::{PowerPC32, PowerPC64, PowerPC64le};
+        let ppc64le = PpcLongDouble::Ibm128.primitives(PowerPC64le).unwrap();
+        assert_eq!(ppc64le.int_layout, LE_LP64);
+        assert_eq!(ppc64le.ldouble_format, FloatFormat::Ibm128);
+        assert_eq!(ppc64le.ldouble_align, 16);
+
+        let ppc64 = PpcLongDouble::Ieee128.primitives(PowerPC64).unwrap();
+        assert_eq!(ppc64.int_layout, BE_LP64);
+        assert_eq!(ppc64.ldouble_format, IEEE764_QUAD);
+
+        let ppc32 = PpcLongDouble::Double.primitives(PowerPC32).unwrap();
+        assert_eq!(ppc32.int_layout, BE_ILP32);
+        assert_eq!(ppc32.ldouble_format, IEEE754_DOUBLE);
+        assert_eq!(ppc32.ldouble_align, 8);
+
+        assert_eq!(
+            PpcLongDouble::Double.primitives(Architecture::Aarch64),
+            None
+        );
+    }
+
+    #[test]
+    fn elf_abi() {
+        use Architecture::{PowerPC32, PowerPC64, PowerPC64le};
+        assert_eq!(
+            default_elf_abi(PowerPC32, Some(Environment::GNU)),
+            Some("sysv")
+        );
+        assert_eq!(
+            default_elf_abi(PowerPC64, Some(Environment::GNU)),
+            Some("elfv1")
+        );
+        assert_eq!(
+            default_elf_abi(PowerPC64, Some(Environment::Musl)),
+            Some("elfv2")
+        );
+        assert_eq!(
+            default_elf_abi(PowerPC64le, Some(Environment::GNU)),
+            Some("elfv2")
+        );
+        assert_eq!(default_elf_abi(Architecture::Aarch64, None), None);
+    }
+
+    #[test]
+    fn aliases() {
+        // "powerpc" is the 32-bit architecture only
+        for arch in [&POWERPC64, &POWERPC64LE] {
+            assert!(!arch.alias_names.iter().any(|alias| alias == "powerpc"));
+        }
+        assert_eq!(&*POWERPC.name, "powerpc");
+    }
+}
@@ -0,0 +1,732 @@
+use core::fmt;
+
+use target_tuples::pieces::{Architecture, OS};
+
+use crate::{
+    arch_features,
+    builtin::abi::{ABI_DOUBLEFLOAT, ABI_SINGLEFLOAT, ABI_SOFTFLOAT},
+    helpers::{CowPtr, CowStr},
+    native_atomics,
+    properties::{
+        ExtPropertyValue,
+        abi::{Abi, IEEE764_QUAD, LE_ILP32, LE_LP64, PrimitiveLayouts},
+        arch::{Arch, Asm, Machine},
+    },
+};
+
+arch_features! {
+    /// The list of RISC-V standard extensions.
+    ///
+    /// `g` is treated as a feature that implies `imafd_zicsr_zifencei`.
+    pub static RISCV_FEATURES = [
+        "i",
+        "e",
+        "g" ("i", "m", "a", "f", "d", "zicsr", "zifencei"),
+        "m" ("zmmul"),
+        "a" ("zaamo", "zalrsc"),
+        "f" ("zicsr"),
+        "d" ("f", "zicsr"),
+        "q" ("d", "f", "zicsr"),
+        "c" ("zca"),
+        "b" ("zba", "zbb", "zbs"),
+        "v" ("d", "f", "zicsr", "zve32x", "zve32f", "zve64x", "zve64f", "zve64d", "zvl32b", "zvl64b", "zvl128b"),
+        "h",
+        "zicsr",
+        "zifencei",
+        "zicntr" ("zicsr"),
+        "zihpm" ("zicsr"),
+        "zicond",
+        "zihintpause",
+        "zihintntl",
+        "zicbom",
+        "zicboz",
+        "zicbop",
+        "zmmul",
+        "zaamo",
+        "zalrsc",
+        "zacas" ("zaamo"),
+        "zawrs",
+        "zca",
+        "zcb" ("zca"),
+        "zba",
+        "zbb",
+        "zbc",
+        "zbs",
+        "zbkb",
+        "zbkc",
+        "zbkx",
+        "zfh" ("zfhmin", "f", "zicsr"),
+        "zfhmin" ("f", "zicsr"),
+        "zfa" ("f", "zicsr"),
+        "zkt",
+        "zkn" ("zbkb", "zbkc", "zbkx", "zkne", "zknd", "zknh"),
+        "zkne",
+        "zknd",
+        "zknh",
+        "zks" ("zbkb", "zbkc", "zbkx", "zksed", "zksh"),
+        "zksed",
+        "zksh",
+        "zkr",
+        "zve32x" ("zicsr", "zvl32b"),
+        "zve32f" ("zve32x", "f", "zicsr", "zvl32b"),
+        "zve64x" ("zve32x", "zicsr", "zvl32b", "zvl64b"),
+        "zve64f" ("zve32x", "zve32f", "zve64x", "f", "zicsr", "zvl32b", "zvl64b"),
+        "zve64d" ("zve32x", "zve32f", "zve64x", "zve64f", "d", "f", "zicsr", "zvl32b", "zvl64b"),
+        "zvfh" ("zvfhmin", "zve32f", "zve32x", "zfhmin", "f", "zicsr", "zvl32b"),
+        "zvfhmin" ("zve32f", "zve32x", "f", "zicsr", "zvl32b"),
+        "zvbb" ("zvkb"),
+        "zvkb",
+        "zvkn" ("zvkned", "zvknhb", "zvkb", "zvkt"),
+        "zvkned",
+        "zvknhb",
+        "zvkt",
+        "zvl32b",
+        "zvl64b" ("zvl32b"),
+        "zvl128b" ("zvl32b", "zvl64b"),
+        "zvl256b" ("zvl32b", "zvl64b", "zvl128b"),
+        "zvl512b" ("zvl32b", "zvl64b", "zvl128b", "zvl256b"),
+        "zvl1024b" ("zvl32b", "zvl64b", "zvl128b", "zvl256b", "zvl512b"),
+        "svinval",
+        "svnapot",
+        "svpbmt",
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of RV32.
+    ///
+    /// Narrower operations are performed on the containing word with `lr.w`/`sc.w`, and `zacas` adds `amocas.d`.
+    pub static RISCV32_ATOMICS = [
+        8 ("zalrsc"),
+        16 ("zalrsc"),
+        32 ("zalrsc"),
+        64 ("zacas"),
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of RV64.
+    ///
+    /// Narrower operations are performed on the containing word with `lr.w`/`sc.w`, and `zacas` adds `amocas.q`.
+    pub static RISCV64_ATOMICS = [
+        8 ("zalrsc"),
+        16 ("zalrsc"),
+        32 ("zalrsc"),
+        64 ("zalrsc"),
+        128 ("zacas"),
+    ];
+}
+
+/// The list of known RV32 machines
+pub static RISCV32_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("rv32gc"),
+        features: slice![cowstr!("g"), cowstr!("c")],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(32))],
+    },
+    Machine {
+        name: cowstr!("rv32i"),
+        features: slice![cowstr!("i")],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(32))],
+    },
+    Machine {
+        name: cowstr!("rv32e"),
+        features: slice![cowstr!("e")],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(32))],
+    },
+    Machine {
+        name: cowstr!("rv32imc"),
+        features: slice![cowstr!("i"), cowstr!("m"), cowstr!("c")],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(32))],
+    },
+    Machine {
+        name: cowstr!("rv32imac"),
+        features: slice![cowstr!("i"), cowstr!("m"), cowstr!("a"), cowstr!("c")],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(32))],
+    },
+    Machine {
+        name: cowstr!("rv32imafc"),
+        features: slice![
+            cowstr!("i"),
+            cowstr!("m"),
+            cowstr!("a"),
+            cowstr!("f"),
+            cowstr!("c")
+        ],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(32))],
+    },
+];
+
+/// The list of known RV64 machines
+pub static RISCV64_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("rv64gc"),
+        features: slice![cowstr!("g"), cowstr!("c")],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(64))],
+    },
+    Machine {
+        name: cowstr!("rv64i"),
+        features: slice![cowstr!("i")],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(64))],
+    },
+    Machine {
+        name: cowstr!("rv64imac"),
+        features: slice![cowstr!("i"), cowstr!("m"), cowstr!("a"), cowstr!("c")],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(64))],
+    },
+    Machine {
+        name: cowstr!("rv64gcv"),
+        features: slice![cowstr!("g"), cowstr!("c"), cowstr!("v")],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(64))],
+    },
+    Machine {
+        name: cowstr!("rva20u64"),
+        features: slice![
+            cowstr!("g"),
+            cowstr!("c"),
+            cowstr!("zicntr"),
+            cowstr!("zihpm")
+        ],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(64))],
+    },
+    Machine {
+        name: cowstr!("rva22u64"),
+        features: slice![
+            cowstr!("g"),
+            cowstr!("c"),
+            cowstr!("b"),
+            cowstr!("zicntr"),
+            cowstr!("zihpm"),
+            cowstr!("zihintpause"),
+            cowstr!("zicbom"),
+            cowstr!("zicbop"),
+            cowstr!("zicboz"),
+            cowstr!("zfhmin"),
+            cowstr!("zkt")
+        ],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(64))],
+    },
+    Machine {
+        name: cowstr!("rva23u64"),
+        features: slice![
+            cowstr!("g"),
+            cowstr!("c"),
+            cowstr!("b"),
+            cowstr!("v"),
+            cowstr!("zicntr"),
+            cowstr!("zihpm"),
+            cowstr!("zihintpause"),
+            cowstr!("zihintntl"),
+            cowstr!("zicbom"),
+            cowstr!("zicbop"),
+            cowstr!("zicboz"),
+            cowstr!("zicond"),
+            cowstr!("zawrs"),
+            cowstr!("zfa"),
+            cowstr!("zfhmin"),
+            cowstr!("zcb"),
+            cowstr!("zkt"),
+            cowstr!("zvfhmin"),
+            cowstr!("zvbb"),
+            cowstr!("zvkt")
+        ],
+        mach_extended_properties: slice![(cowstr!("riscv.xlen"), ExtPropertyValue::Int(64))],
+    },
+];
+
+/// RISC-V call tags. These correspond to the `-mabi` variants
+pub static RISCV_TAGS: &[CowStr] = &[
+    cowstr!("ilp32"),
+    cowstr!("ilp32f"),
+    cowstr!("ilp32d"),
+    cowstr!("ilp32e"),
+    cowstr!("lp64"),
+    cowstr!("lp64f"),
+    cowstr!("lp64d"),
+];
+
+/// The ASM Specification for RISC-V
+pub static RISCV_ASM: Asm = Asm {};
+
+/// RV32
+pub static RISCV32: Arch = Arch {
+    name: cowstr!("riscv32"),
+    alias_names: slice![cowstr!("riscv"), cowstr!("rv32")],
+    machines: cow!(*RISCV32_MACHINES),
+    raw_width: 32,
+    features: cow!(*RISCV_FEATURES),
+    native_atomics: cow!(*RISCV32_ATOMICS),
+    default_machine: cow!(RISCV32_MACHINES[0]),
+    call_tags: cow!(*RISCV_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(RISCV_ASM)),
+};
+
+/// RV64
+pub static RISCV64: Arch = Arch {
+    name: cowstr!("riscv64"),
+    alias_names: slice![cowstr!("riscv"), cowstr!("rv64")],
+    machines: cow!(*RISCV64_MACHINES),
+    raw_width: 64,
+    features: cow!(*RISCV_FEATURES),
+    native_atomics: cow!(*RISCV64_ATOMICS),
+    default_machine: cow!(RISCV64_MACHINES[0]),
+    call_tags: cow!(*RISCV_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(RISCV_ASM)),
+};
+
+/// RV32 for freestanding targets, with `rv32imac` as the default machine
+pub static RISCV32_FREESTANDING: Arch = Arch {
+    name: cowstr!("riscv32"),
+    alias_names: slice![cowstr!("riscv"), cowstr!("rv32")],
+    machines: cow!(*RISCV32_MACHINES),
+    raw_width: 32,
+    features: cow!(*RISCV_FEATURES),
+    native_atomics: cow!(*RISCV32_ATOMICS),
+    default_machine: cow!(RISCV32_MACHINES[4]),
+    call_tags: cow!(*RISCV_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(RISCV_ASM)),
+};
+
+/// RV64 for freestanding targets, with `rv64imac` as the default machine
+pub static RISCV64_FREESTANDING: Arch = Arch {
+    name: cowstr!("riscv64"),
+    alias_names: slice![cowstr!("riscv"), cowstr!("rv64")],
+    machines: cow!(*RISCV64_MACHINES),
+    raw_width: 64,
+    features: cow!(*RISCV_FEATURES),
+    native_atomics: cow!(*RISCV64_ATOMICS),
+    default_machine: cow!(RISCV64_MACHINES[2]),
+    call_tags: cow!(*RISCV_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(RISCV_ASM)),
+};
+
+/// Selects the RISC-V architecture for a target.
+///
+/// Hosted targets default to the `gc` machines (`rv32gc`/`rv64gc`), and freestanding targets to the `imac` machines, which have no FPU
+pub const fn arch_for_os(arch: Architecture, os: OS) -> Option<&'static Arch> {
+    match (arch, os) {
+        (Architecture::RiscV32, OS::None) => Some(&RISCV32_FREESTANDING),
+        (Architecture::RiscV64, OS::None) => Some(&RISCV64_FREESTANDING),
+        (Architecture::RiscV32, _) => Some(&RISCV32),
+        (Architecture::RiscV64, _) => Some(&RISCV64),
+        _ => None,
+    }
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Checks whether `feature` is `target`, or implies it
+const fn feature_implies(feature: &str, target: &str) -> bool {
+    if str_eq(feature, target) {
+        return true;
+    }
+    let mut i = 0;
+    while i < RISCV_FEATURES.len() {
+        if str_eq(RISCV_FEATURES[i].name.as_ref(), feature) {
+            let implies = RISCV_FEATURES[i].implies.as_ref();
+            let mut j = 0;
+            while j < implies.len() {
+                if feature_implies(implies[j].as_ref(), target) {
+                    return true;
+                }
+                j += 1;
+            }
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Checks whether `mach` enables `target`, directly or through implied features
+const fn machine_has(mach: &Machine, target: &str) -> bool {
+    let features = mach.features.as_ref();
+    let mut i = 0;
+    while i < features.len() {
+        if feature_implies(features[i].as_ref(), target) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Default Primitives for RV32. `long double` is ieee754-binary128
+pub static RISCV32_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_ILP32,
+    max_int_align: 8,
+    max_bit_int_align: 8,
+    max_simd_align: 16,
+    ldouble_align: 16,
+    ldouble_format: IEEE764_QUAD,
+};
+
+/// Default Primitives for RV64. `long double` is ieee754-binary128
+pub static RISCV64_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LP64,
+    max_int_align: 16,
+    max_bit_int_align: 8,
+    max_simd_align: 16,
+    ldouble_align: 16,
+    ldouble_format: IEEE764_QUAD,
+};
+
+/// The RISC-V integer/floating-point calling convention variants, as selected by `-mabi`
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RiscvAbi {
+    /// `ilp32`: RV32, no floating-point registers used for argument passing
+    Ilp32,
+    /// `ilp32f`: RV32, `float` passed in floating-point registers
+    Ilp32f,
+    /// `ilp32d`: RV32, `float` and `double` passed in floating-point registers
+    Ilp32d,
+    /// `ilp32e`: RV32E, no floating-point registers and a reduced integer register set
+    Ilp32e,
+    /// `lp64`: RV64, no floating-point registers used for argument passing
+    Lp64,
+    /// `lp64f`: RV64, `float` passed in floating-point registers
+    Lp64f,
+    /// `lp64d`: RV64, `float` and `double` passed in floating-point registers
+    Lp64d,
+}
+
+impl RiscvAbi {
+    /// Parses the value of a `-mabi` flag
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ilp32" => Some(Self::Ilp32),
+            "ilp32f" => Some(Self::Ilp32f),
+            "ilp32d" => Some(Self::Ilp32d),
+            "ilp32e" => Some(Self::Ilp32e),
+            "lp64" => Some(Self::Lp64),
+            "lp64f" => Some(Self::Lp64f),
+            "lp64d" => Some(Self::Lp64d),
+            _ => None,
+        }
+    }
+
+    /// The name of the ABI, which is both the `-mabi` value and the call tag
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Ilp32 => "ilp32",
+            Self::Ilp32f => "ilp32f",
+            Self::Ilp32d => "ilp32d",
+            Self::Ilp32e => "ilp32e",
+            Self::Lp64 => "lp64",
+            Self::Lp64f => "lp64f",
+            Self::Lp64d => "lp64d",
+        }
+    }
+
+    /// The default ABI for a given RISC-V architecture and OS.
+    ///
+    /// This is the ABI of the default machine of [`arch_for_os`]. See [`RiscvAbi::for_machine`]
+    pub const fn default_for(arch: Architecture, os: OS) -> Option<Self> {
+        match arch_for_os(arch, os) {
+            Some(riscv) => Self::for_machine(arch, riscv.default_machine.as_ref()),
+            None => None,
+        }
+    }
+
+    /// The ABI that passes floating-point values in the widest floating-point registers that `mach` has.
+    ///
+    /// The `d` ABIs are used if the machine has the D extension, the `f` ABIs if it only has the F extension,
+    ///  and the integer ABIs otherwise (`ilp32e` for RV32E).
+    pub const fn for_machine(arch: Architecture, mach: &Machine) -> Option<Self> {
+        let (int, single, double) = match arch {
+            Architecture::RiscV32 => {
+                if machine_has(mach, "e") && !machine_has(mach, "i") {
+                    return Some(Self::Ilp32e);
+                }
+                (Self::Ilp32, Self::Ilp32f, Self::Ilp32d)
+            }
+            Architecture::RiscV64 => (Self::Lp64, Self::Lp64f, Self::Lp64d),
+            _ => return None,
+        };
+        Some(if machine_has(mach, "d") {
+            double
+        } else if machine_has(mach, "f") {
+            single
+        } else {
+            int
+        })
+    }
+
+    /// The width of floating-point values passed in floating-point registers, or `0` if none are
+    pub const fn flen(self) -> u16 {
+        match self {
+            Self::Ilp32 | Self::Ilp32e | Self::Lp64 => 0,
+            Self::Ilp32f | Self::Lp64f => 32,
+            Self::Ilp32d | Self::Lp64d => 64,
+        }
+    }
+
+    /// The target feature required to use the ABI, if any
+    pub const fn required_feature(self) -> Option<&'static str> {
+        match self.flen() {
+            32 => Some("f"),
+            64 => Some("d"),
+            _ => None,
+        }
+    }
+
+    /// The [`Abi`] pass overrides for the ABI.
+    ///
+    /// Floating-point types wider than [`RiscvAbi::flen`] are passed in integer registers.
+    pub const fn abi(self) -> &'static Abi {
+        match self.flen() {
+            0 => &ABI_SOFTFLOAT,
+            32 => &ABI_SINGLEFLOAT,
+            _ => &ABI_DOUBLEFLOAT,
+        }
+    }
+
+    /// The [`PrimitiveLayouts`] for the ABI
+    pub const fn primitives(self) -> &'static PrimitiveLayouts {
+        match self {
+            Self::Ilp32 | Self::Ilp32f | Self::Ilp32d | Self::Ilp32e => &RISCV32_PRIMITIVES,
+            Self::Lp64 | Self::Lp64f | Self::Lp64d => &RISCV64_PRIMITIVES,
+        }
+    }
+}
+
+/// An error parsing a RISC-V ISA string
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum IsaParseError {
+    /// The ISA string does not start with `rv32` or `rv64`
+    InvalidPrefix,
+    /// The ISA string does not specify a base ISA after the prefix
+    MissingBase,
+    /// The first extension is not one of the base ISAs `i`, `e`, or `g`
+    InvalidBase(char),
+    /// An extension is not known
+    UnknownExtension(String),
+    /// An extension appears more than once
+    DuplicateExtension(String),
+}
+
+impl fmt::Display for IsaParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPrefix => f.write_str("ISA string must start with `rv32` or `rv64`"),
+            Self::MissingBase => f.write_str("ISA string is missing a base ISA"),
+            Self::InvalidBase(c) => {
+                write!(
+                    f,
+                    "invalid base ISA `{c}` (expected one of `i`, `e`, or `g`)"
+                )
+            }
+            Self::UnknownExtension(ext) => write!(f, "unknown extension `{ext}`"),
+            Self::DuplicateExtension(ext) => write!(f, "duplicate extension `{ext}`"),
+        }
+    }
+}
+
+impl std::error::Error for IsaParseError {}
+
+/// Strips a trailing extension version (`2`, `2p1`) from a multi-letter extension name
+fn strip_version(ext: &str) -> &str {
+    let name = ext.trim_end_matches(|c: char| c.is_ascii_digit());
+    if name.len() == ext.len() {
+        return ext;
+    }
+
+    match name.strip_suffix('p') {
+        Some(major) if major.ends_with(|c: char| c.is_ascii_digit()) => {
+            major.trim_end_matches(|c: char| c.is_ascii_digit())
+        }
+        _ => name,
+    }
+}
+
+/// Parses a RISC-V ISA string (the argument of `-march`, such as `rv64gcv_zba`) into a [`Machine`].
+///
+/// The returned machine has an extended property `riscv.xlen` set to the width in the prefix.
+/// Extension versions (e.g. `rv64i2p1`) are accepted and ignored.
+pub fn parse_isa_string(isa: &str) -> Result<Machine, IsaParseError> {
+    let lower = isa.to_ascii_lowercase();
+    let (xlen, rest) = if let Some(rest) = lower.strip_prefix("rv32") {
+        (32, rest)
+    } else if let Some(rest) = lower.strip_prefix("rv64") {
+        (64, rest)
+    } else {
+        return Err(IsaParseError::InvalidPrefix);
+    };
+
+    let mut features: Vec<CowStr> = Vec::new();
+
+    let mut push = |name: &str| -> Result<(), IsaParseError> {
+        let Some(feature) = RISCV_FEATURES.iter().find(|f| &*f.name == name) else {
+            return Err(IsaParseError::UnknownExtension(name.to_string()));
+        };
+        if features.iter().any(|f| f == name) {
+            return Err(IsaParseError::DuplicateExtension(name.to_string()));
+        }
+        features.push(feature.name.clone());
+        Ok(())
+    };
+
+    let (single, multi) = match rest.find(['_', 'z', 's', 'x']) {
+        Some(pos) => rest.split_at(pos),
+        None => (rest, ""),
+    };
+
+    let mut chars = single.chars().peekable();
+
+    match chars.peek() {
+        Some('i' | 'e' | 'g') => {}
+        Some(&c) => return Err(IsaParseError::InvalidBase(c)),
+        None => return Err(IsaParseError::MissingBase),
+    }
+
+    while let Some(c) = chars.next() {
+        let mut buf = [0u8; 4];
+        push(c.encode_utf8(&mut buf))?;
+        // Skip a version number, such as `2p1`
+        while chars.next_if(|c| c.is_ascii_digit()).is_some() {}
+        if chars.next_if_eq(&'p').is_some() {
+            while chars.next_if(|c| c.is_ascii_digit()).is_some() {}
+        }
+    }
+
+    for ext in multi.split('_').filter(|ext| !ext.is_empty()) {
+        push(strip_version(ext))?;
+    }
+
+    Ok(Machine {
+        name: CowPtr::from(isa.to_string()),
+        features: CowPtr::from(features),
+        mach_extended_properties: CowPtr::from(vec![(
+            cowstr!("riscv.xlen"),
+            ExtPropertyValue::Int(xlen),
+        )]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features(mach: &Machine) -> Vec<&str> {
+        mach.features.iter().map(|f| &**f).collect()
+    }
+
+    #[test]
+    fn parse_isa() {
+        let mach = parse_isa_string("rv64gc_zba_zbb").unwrap();
+        assert_eq!(features(&mach), ["g", "c", "zba", "zbb"]);
+        assert_eq!(
+            &*mach.mach_extended_properties,
+            [(cowstr!("riscv.xlen"), ExtPropertyValue::Int(64))]
+        );
+
+        let mach = parse_isa_string("RV32IMAC").unwrap();
+        assert_eq!(features(&mach), ["i", "m", "a", "c"]);
+    }
+
+    #[test]
+    fn parse_isa_versions() {
+        let mach = parse_isa_string("rv64i2p1m2a_zicsr2p0_zifencei2").unwrap();
+        assert_eq!(features(&mach), ["i", "m", "a", "zicsr", "zifencei"]);
+    }
+
+    #[test]
+    fn parse_isa_errors() {
+        assert_eq!(
+            parse_isa_string("x86_64"),
+            Err(IsaParseError::InvalidPrefix)
+        );
+        assert_eq!(parse_isa_string("rv64"), Err(IsaParseError::MissingBase));
+        assert_eq!(
+            parse_isa_string("rv64mac"),
+            Err(IsaParseError::InvalidBase('m'))
+        );
+        assert_eq!(
+            parse_isa_string("rv64i_zfoo"),
+            Err(IsaParseError::UnknownExtension("zfoo".to_string()))
+        );
+        assert_eq!(
+            parse_isa_string("rv64imm"),
+            Err(IsaParseError::DuplicateExtension("m".to_string()))
+        );
+    }
+
+    #[test]
+    fn abi_names() {
+        for abi in [
+            RiscvAbi::Ilp32,
+            RiscvAbi::Ilp32f,
+            RiscvAbi::Ilp32d,
+            RiscvAbi::Ilp32e,
+            RiscvAbi::Lp64,
+            RiscvAbi::Lp64f,
+            RiscvAbi::Lp64d,
+        ] {
+            assert_eq!(RiscvAbi::from_name(abi.name()), Some(abi));
+        }
+        assert_eq!(RiscvAbi::from_name("lp64q"), None);
+    }
+
+    #[test]
+    fn abi_float_registers() {
+        assert_eq!(RiscvAbi::Lp64.abi(), &ABI_SOFTFLOAT);
+        assert_eq!(RiscvAbi::Ilp32f.abi().float_reg_max_width, Some(32));
+        assert_eq!(RiscvAbi::Lp64f.abi().float_reg_max_width, Some(32));
+        assert_eq!(RiscvAbi::Lp64d.abi().float_reg_max_width, Some(64));
+        assert_eq!(RiscvAbi::Lp64d.abi().float_pass_override, None);
+    }
+
+    #[test]
+    fn abi_for_machine() {
+        let abi = |arch, isa| RiscvAbi::for_machine(arch, &parse_isa_string(isa).unwrap());
+        assert_eq!(abi(Architecture::RiscV64, "rv64gc"), Some(RiscvAbi::Lp64d));
+        assert_eq!(
+            abi(Architecture::RiscV64, "rv64imafc"),
+            Some(RiscvAbi::Lp64f)
+        );
+        assert_eq!(abi(Architecture::RiscV64, "rv64imac"), Some(RiscvAbi::Lp64));
+        assert_eq!(abi(Architecture::RiscV64, "rv64iv"), Some(RiscvAbi::Lp64d));
+        assert_eq!(
+            abi(Architecture::RiscV32, "rv32imafc"),
+            Some(RiscvAbi::Ilp32f)
+        );
+        assert_eq!(abi(Architecture::RiscV32, "rv32e"), Some(RiscvAbi::Ilp32e));
+        assert_eq!(abi(Architecture::RiscV32, "rv32i"), Some(RiscvAbi::Ilp32));
+    }
+
+    #[test]
+    fn default_abi() {
+        assert_eq!(
+            RiscvAbi::default_for(Architecture::RiscV64, OS::Linux),
+            Some(RiscvAbi::Lp64d)
+        );
+        assert_eq!(
+            RiscvAbi::default_for(Architecture::RiscV32, OS::Linux),
+            Some(RiscvAbi::Ilp32d)
+        );
+        assert_eq!(
+            RiscvAbi::default_for(Architecture::RiscV64, OS::None),
+            Some(RiscvAbi::Lp64)
+        );
+        assert_eq!(
+            RiscvAbi::default_for(Architecture::RiscV32, OS::None),
+            Some(RiscvAbi::Ilp32)
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{
+    arch_features, native_atomics,
+    properties::{
+        abi::{BE_ILP32, BE_LP64, IEEE764_QUAD, LE_ILP32, PrimitiveLayouts},
+        arch::{Arch, Asm, Machine},
+    },
+};
+
+arch_features! {
+    /// The list of SPARC features
+    pub static SPARC_FEATURES = [
+        "v8",
+        "v9" ("v8"),
+        "deprecated-v8",
+        "hard-quad-float",
+        "vis" ("v9"),
+        "vis2" ("v9", "vis"),
+        "vis3" ("v9", "vis", "vis2"),
+        "popc",
+        "leon" ("v8"),
+        "hasleoncasa" ("leon"),
+        "hasumacsmac",
+        "soft-float",
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of 32-bit SPARC.
+    ///
+    /// SPARC V8 only has `ldstub` and `swap`, so compare-and-swap requires V9 (`cas`) or the LEON `casa` extension.
+    pub static SPARC_ATOMICS = [
+        8 ("v9", "hasleoncasa"),
+        16 ("v9", "hasleoncasa"),
+        32 ("v9", "hasleoncasa"),
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of SPARC V9, which has `cas` and `casx`
+    pub static SPARCV9_ATOMICS = [
+        8 ("v9"),
+        16 ("v9"),
+        32 ("v9"),
+        64 ("v9"),
+    ];
+}
+
+/// The list of known 32-bit SPARC machines
+pub static SPARC32_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("v8"),
+        features: slice![cowstr!("v8")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("v7"),
+        features: slice![],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("supersparc"),
+        features: slice![cowstr!("v8")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("leon2"),
+        features: slice![cowstr!("leon")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("leon3"),
+        features: slice![
+            cowstr!("leon"),
+            cowstr!("hasleoncasa"),
+            cowstr!("hasumacsmac")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("leon4"),
+        features: slice![
+            cowstr!("leon"),
+            cowstr!("hasleoncasa"),
+            cowstr!("hasumacsmac")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("v9"),
+        features: slice![cowstr!("v9")],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// The list of known 64-bit SPARC machines
+pub static SPARC64_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("v9"),
+        features: slice![cowstr!("v9")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("ultrasparc"),
+        features: slice![cowstr!("v9"), cowstr!("vis")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("ultrasparc3"),
+        features: slice![cowstr!("v9"), cowstr!("vis2")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("niagara"),
+        features: slice![cowstr!("v9"), cowstr!("vis2")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("niagara2"),
+        features: slice![cowstr!("v9"), cowstr!("vis2"), cowstr!("popc")],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("niagara4"),
+        features: slice![cowstr!("v9"), cowstr!("vis3"), cowstr!("popc")],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// The ASM Specification for SPARC
+pub static SPARC_ASM: Asm = Asm {};
+
+/// 32-bit SPARC (V8), big-endian
+pub static SPARC: Arch = Arch {
+    name: cowstr!("sparc"),
+    alias_names: slice![],
+    machines: cow!(*SPARC32_MACHINES),
+    raw_width: 32,
+    features: cow!(*SPARC_FEATURES),
+    native_atomics: cow!(*SPARC_ATOMICS),
+    default_machine: cow!(SPARC32_MACHINES[0]),
+    call_tags: slice![cowstr!("C")],
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(SPARC_ASM)),
+};
+
+/// 32-bit SPARC, little-endian (LEON)
+pub static SPARCEL: Arch = Arch {
+    name: cowstr!("sparcel"),
+    alias_names: slice![cowstr!("sparc")],
+    machines: cow!(*SPARC32_MACHINES),
+    raw_width: 32,
+    features: cow!(*SPARC_FEATURES),
+    native_atomics: cow!(*SPARC_ATOMICS),
+    default_machine: cow!(SPARC32_MACHINES[0]),
+    call_tags: slice![cowstr!("C")],
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(SPARC_ASM)),
+};
+
+/// 64-bit SPARC (V9)
+pub static SPARCV9: Arch = Arch {
+    name: cowstr!("sparcv9"),
+    alias_names: slice![cowstr!("sparc64"), cowstr!("sparc")],
+    machines: cow!(*SPARC64_MACHINES),
+    raw_width: 64,
+    features: cow!(*SPARC_FEATURES),
+    native_atomics: cow!(*SPARCV9_ATOMICS),
+    default_machine: cow!(SPARC64_MACHINES[0]),
+    call_tags: slice![cowstr!("C")],
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(SPARC_ASM)),
+};
+
+/// Default primitives for 32-bit SPARC.
+///
+/// `long double` is binary128, but only 8-byte aligned.
+pub static SPARC_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_ILP32,
+    max_int_align: 8,
+    max_bit_int_align: 8,
+    max_simd_align: 8,
+    ldouble_align: 8,
+    ldouble_format: IEEE764_QUAD,
+};
+
+/// Default primitives for little-endian 32-bit SPARC
+pub static SPARCEL_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_ILP32,
+    ..SPARC_PRIMITIVES
+};
+
+/// Default primitives for 64-bit SPARC. `long double` is binary128
+pub static SPARCV9_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: BE_LP64,
+    max_int_align: 16,
+    max_bit_int_align: 8,
+    max_simd_align: 16,
+    ldouble_align: 16,
+    ldouble_format: IEEE764_QUAD,
+};
@@ -0,0 +1,147 @@
+use crate::{
+    arch_features, native_atomics,
+    properties::{
+        abi::{IEEE764_QUAD, LE_ILP32, LE_LP64, PrimitiveLayouts},
+        arch::{Arch, Asm, Machine},
+    },
+};
+
+arch_features! {
+    /// The list of WebAssembly features
+    pub static WASM_FEATURES = [
+        "simd128",
+        "relaxed-simd" ("simd128"),
+        "bulk-memory",
+        "bulk-memory-opt",
+        "mutable-globals",
+        "sign-ext",
+        "nontrapping-fptoint",
+        "multivalue",
+        "reference-types",
+        "call-indirect-overlong",
+        "tail-call",
+        "exception-handling",
+        "extended-const",
+        "multimemory",
+        "memory64",
+        "atomics" ("bulk-memory", "mutable-globals"),
+        "fp16",
+        "gc" ("reference-types"),
+    ];
+}
+
+native_atomics! {
+    /// The native atomic widths of WebAssembly.
+    ///
+    /// Without `atomics` there is only one thread, so atomic operations are lowered to ordinary loads and stores.
+    pub static WASM_ATOMICS = [8, 16, 32, 64];
+}
+
+/// The list of known WebAssembly machines
+pub static WASM_MACHINES: &[Machine] = &[
+    Machine {
+        name: cowstr!("generic"),
+        features: slice![
+            cowstr!("bulk-memory"),
+            cowstr!("bulk-memory-opt"),
+            cowstr!("mutable-globals"),
+            cowstr!("sign-ext"),
+            cowstr!("nontrapping-fptoint"),
+            cowstr!("multivalue"),
+            cowstr!("reference-types"),
+            cowstr!("call-indirect-overlong")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("mvp"),
+        features: slice![],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("lime1"),
+        features: slice![
+            cowstr!("bulk-memory-opt"),
+            cowstr!("mutable-globals"),
+            cowstr!("sign-ext"),
+            cowstr!("nontrapping-fptoint"),
+            cowstr!("multivalue"),
+            cowstr!("call-indirect-overlong"),
+            cowstr!("extended-const")
+        ],
+        mach_extended_properties: slice![],
+    },
+    Machine {
+        name: cowstr!("bleeding-edge"),
+        features: slice![
+            cowstr!("bulk-memory"),
+            cowstr!("bulk-memory-opt"),
+            cowstr!("mutable-globals"),
+            cowstr!("sign-ext"),
+            cowstr!("nontrapping-fptoint"),
+            cowstr!("multivalue"),
+            cowstr!("reference-types"),
+            cowstr!("call-indirect-overlong"),
+            cowstr!("simd128"),
+            cowstr!("relaxed-simd"),
+            cowstr!("tail-call"),
+            cowstr!("exception-handling"),
+            cowstr!("extended-const"),
+            cowstr!("multimemory"),
+            cowstr!("atomics"),
+            cowstr!("fp16"),
+            cowstr!("gc")
+        ],
+        mach_extended_properties: slice![],
+    },
+];
+
+/// WebAssembly call tags
+pub static WASM_TAGS: &[crate::helpers::CowStr] = &[cowstr!("C"), cowstr!("wasm")];
+
+/// The ASM Specification for WebAssembly
+pub static WASM_ASM: Asm = Asm {};
+
+/// 32-bit WebAssembly
+pub static WASM32: Arch = Arch {
+    name: cowstr!("wasm32"),
+    alias_names: slice![cowstr!("wasm")],
+    machines: cow!(*WASM_MACHINES),
+    raw_width: 32,
+    features: cow!(*WASM_FEATURES),
+    native_atomics: cow!(*WASM_ATOMICS),
+    default_machine: cow!(WASM_MACHINES[0]),
+    call_tags: cow!(*WASM_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(WASM_ASM)),
+};
+
+/// 64-bit WebAssembly (memory64)
+pub static WASM64: Arch = Arch {
+    name: cowstr!("wasm64"),
+    alias_names: slice![cowstr!("wasm")],
+    machines: cow!(*WASM_MACHINES),
+    raw_width: 64,
+    features: cow!(*WASM_FEATURES),
+    native_atomics: cow!(*WASM_ATOMICS),
+    default_machine: cow!(WASM_MACHINES[0]),
+    call_tags: cow!(*WASM_TAGS),
+    arch_extended_properties: slice![],
+    asm_spec: Some(cow!(WASM_ASM)),
+};
+
+/// Default Primitives for wasm32
+pub static WASM32_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_ILP32,
+    max_int_align: 16,
+    max_bit_int_align: 8,
+    max_simd_align: 16,
+    ldouble_align: 16,
+    ldouble_format: IEEE764_QUAD,
+};
+
+/// Default Primitives for wasm64
+pub static WASM64_PRIMITIVES: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LP64,
+    ..WASM32_PRIMITIVES
+};
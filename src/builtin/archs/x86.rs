@@ -3,8 +3,8 @@ use crate::{
     helpers::{CowPtr, CowSlice, CowStr},
     properties::{
         abi::{
-            IEEE754_DOUBLE, IntLayouts, LE_ILP32, LE_IP16, LE_IP16_NEAR_FAR, LE_LP32_NEAR_FAR,
-            LE_LP64, PrimitiveLayouts, X87_DOUBLE_EXTENDED,
+            IEEE754_DOUBLE, IEEE764_QUAD, IntLayouts, LE_ILP32, LE_IP16, LE_IP16_NEAR_FAR,
+            LE_LLP64, LE_LP32_NEAR_FAR, LE_LP64, PrimitiveLayouts, X87_DOUBLE_EXTENDED,
         },
         arch::{Arch, Asm, Machine, TargetFeature},
     },
@@ -293,6 +293,43 @@ pub mod tags {
     pub static X86_64: &[CowStr] = &[cowstr!("sysv64"), cowstr!("win64"), cowstr!("vectorcall")];
 }
 
+/// x86 native atomic widths.
+///
+/// Lock-free atomics require `cmpxchg` (`cx`), introduced in the i486.
+/// Double-width operations require `cmpxchg8b` (`cx8`) or, in 64-bit mode, `cmpxchg16b` (`cx16`).
+pub mod atomics {
+    use crate::native_atomics;
+
+    native_atomics! {
+        /// x86-16 native atomic widths
+        pub static X86_16 = [
+            8 ("cx"),
+            16 ("cx"),
+        ];
+    }
+
+    native_atomics! {
+        /// x86-32 native atomic widths
+        pub static X86_32 = [
+            8 ("cx"),
+            16 ("cx"),
+            32 ("cx"),
+            64 ("cx8"),
+        ];
+    }
+
+    native_atomics! {
+        /// x86-64 native atomic widths
+        pub static X86_64 = [
+            8 ("cx"),
+            16 ("cx"),
+            32 ("cx"),
+            64 ("cx8"),
+            128 ("cx16"),
+        ];
+    }
+}
+
 macro_rules! x86_archs {
     {
         $($(#[$meta:meta])* $vis:vis static $name:ident ($name_canon:literal) {
@@ -315,6 +352,7 @@ macro_rules! x86_archs {
                 arch_extended_properties: slice![],
                 asm_spec: Some(cow!(asm:: $base)),
                 features: cow!(*X86_FEATURES),
+                native_atomics: cow!(*atomics:: $base),
             };
         )*
     };
@@ -471,3 +509,67 @@ pub static X86_64_F64_LONG_DOUBLE: PrimitiveLayouts = PrimitiveLayouts {
     ldouble_align: 8,
     ldouble_format: IEEE754_DOUBLE,
 };
+
+/// Default Primitives for x86-32 with the Microsoft ABI.
+/// `long double` is the same as `double`, and `long long` and `double` are 8-byte aligned
+pub static X86_32_PRIMITIVES_MSVC: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_ILP32,
+    max_int_align: 8,
+    max_bit_int_align: 8,
+    max_simd_align: 64,
+    ldouble_align: 8,
+    ldouble_format: IEEE754_DOUBLE,
+};
+
+/// Default Primitives for MinGW x86-32.
+/// `long long` and `double` are 8-byte aligned as with MSVC, but `long double` is the x87 extended format with 4-byte alignment
+pub static X86_32_PRIMITIVES_MINGW: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_ILP32,
+    max_int_align: 8,
+    max_bit_int_align: 8,
+    max_simd_align: 64,
+    ldouble_align: 4,
+    ldouble_format: X87_DOUBLE_EXTENDED,
+};
+
+/// Default Primitives for Microsoft x86-64 (LLP64).
+/// `long double` is the same as `double`
+pub static X86_64_PRIMITIVES_MSVC: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LLP64,
+    max_int_align: 16,
+    max_bit_int_align: 8,
+    max_simd_align: 64,
+    ldouble_align: 8,
+    ldouble_format: IEEE754_DOUBLE,
+};
+
+/// Default Primitives for MinGW x86-64 (LLP64).
+/// Unlike MSVC, `long double` is the x87 extended format
+pub static X86_64_PRIMITIVES_MINGW: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LLP64,
+    max_int_align: 16,
+    max_bit_int_align: 8,
+    max_simd_align: 64,
+    ldouble_align: 16,
+    ldouble_format: X87_DOUBLE_EXTENDED,
+};
+
+/// Primitives for x86-64 Android, where `long double` is IEEE binary128
+pub static X86_64_PRIMITIVES_ANDROID: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_LP64,
+    max_int_align: 16,
+    max_bit_int_align: 8,
+    max_simd_align: 64,
+    ldouble_align: 16,
+    ldouble_format: IEEE764_QUAD,
+};
+
+/// Primitives for x86-32 Android, where `long double` is the same as `double`
+pub static X86_32_PRIMITIVES_ANDROID: PrimitiveLayouts = PrimitiveLayouts {
+    int_layout: LE_ILP32,
+    max_int_align: 4,
+    max_bit_int_align: 4,
+    max_simd_align: 64,
+    ldouble_align: 4,
+    ldouble_format: IEEE754_DOUBLE,
+};
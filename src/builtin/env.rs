@@ -0,0 +1,264 @@
+use target_tuples::pieces::{Environment, OS};
+
+use crate::properties::{
+    ExtPropertyValue,
+    env::{Env, EnvLibraries, ThreadingModel},
+};
+
+/// Freestanding environment with no C library
+pub static ENV_FREESTANDING: Env = Env {
+    name: cowstr!("none"),
+    libc_name: None,
+    threading_model: ThreadingModel::Single,
+    has_std: false,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// Kernel environments. There is no C library, and the kernel provides its own threading
+pub static ENV_KERNEL: Env = Env {
+    name: cowstr!("kernel"),
+    libc_name: None,
+    threading_model: ThreadingModel::Native,
+    has_std: false,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// GNU C Library (glibc)
+pub static ENV_GLIBC: Env = Env {
+    name: cowstr!("gnu"),
+    libc_name: Some(cowstr!("glibc")),
+    threading_model: ThreadingModel::Posix,
+    has_std: true,
+    link_libraries: EnvLibraries {
+        dynamic: slice![cowstr!("gcc"), cowstr!("gcc_s")],
+        static_exe: slice![cowstr!("gcc"), cowstr!("gcc_eh")],
+    },
+    env_extended_properties: slice![],
+};
+
+/// musl libc
+pub static ENV_MUSL: Env = Env {
+    name: cowstr!("musl"),
+    libc_name: Some(cowstr!("musl")),
+    threading_model: ThreadingModel::Posix,
+    has_std: true,
+    link_libraries: EnvLibraries {
+        dynamic: slice![cowstr!("gcc")],
+        static_exe: slice![cowstr!("gcc"), cowstr!("gcc_eh")],
+    },
+    env_extended_properties: slice![(cowstr!("libc.time64"), ExtPropertyValue::Bool(true))],
+};
+
+/// Android (Bionic libc)
+pub static ENV_BIONIC: Env = Env {
+    name: cowstr!("android"),
+    libc_name: Some(cowstr!("bionic")),
+    threading_model: ThreadingModel::Posix,
+    has_std: true,
+    link_libraries: EnvLibraries {
+        dynamic: slice![cowstr!("dl")],
+        static_exe: slice![cowstr!("dl")],
+    },
+    env_extended_properties: slice![],
+};
+
+/// Microsoft Visual C++ runtime, with the Universal CRT
+pub static ENV_MSVC: Env = Env {
+    name: cowstr!("msvc"),
+    libc_name: Some(cowstr!("ucrt")),
+    threading_model: ThreadingModel::Win32,
+    has_std: true,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// MinGW-w64 runtime
+pub static ENV_MINGW: Env = Env {
+    name: cowstr!("mingw"),
+    libc_name: Some(cowstr!("mingw-w64")),
+    threading_model: ThreadingModel::Win32,
+    has_std: true,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// Apple's `libSystem`
+pub static ENV_LIBSYSTEM: Env = Env {
+    name: cowstr!("darwin"),
+    libc_name: Some(cowstr!("libSystem")),
+    threading_model: ThreadingModel::Posix,
+    has_std: true,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// The C library provided by the base system of the BSDs
+pub static ENV_BSD_LIBC: Env = Env {
+    name: cowstr!("bsd"),
+    libc_name: Some(cowstr!("libc")),
+    threading_model: ThreadingModel::Posix,
+    has_std: true,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// Fuchsia's C library (derived from musl)
+pub static ENV_FUCHSIA: Env = Env {
+    name: cowstr!("fuchsia"),
+    libc_name: Some(cowstr!("fuchsia-libc")),
+    threading_model: ThreadingModel::Posix,
+    has_std: true,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// wasi-libc
+pub static ENV_WASI: Env = Env {
+    name: cowstr!("wasi"),
+    libc_name: Some(cowstr!("wasi-libc")),
+    threading_model: ThreadingModel::Single,
+    has_std: true,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// The Lilium C library, implemented on top of USI
+pub static ENV_LILIUM: Env = Env {
+    name: cowstr!("lilium"),
+    libc_name: Some(cowstr!("lilium-libc")),
+    threading_model: ThreadingModel::Native,
+    has_std: true,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// AbleOS userspace
+pub static ENV_ABLEOS: Env = Env {
+    name: cowstr!("ableos"),
+    libc_name: None,
+    threading_model: ThreadingModel::Native,
+    has_std: true,
+    link_libraries: EnvLibraries::NONE,
+    env_extended_properties: slice![],
+};
+
+/// Obtains the [`Env`] properties for a given os and environment
+pub const fn from_target(os: OS, env: Option<Environment>) -> Option<&'static Env> {
+    match (os, env) {
+        (_, Some(Environment::Kernel)) => Some(&ENV_KERNEL),
+        (
+            OS::Linux,
+            Some(
+                Environment::GNU
+                | Environment::GNUX32
+                | Environment::GNUEABI
+                | Environment::GNUEABIHF
+                | Environment::GNUABIN32
+                | Environment::GNUABI64,
+            ),
+        ) => Some(&ENV_GLIBC),
+        (OS::Linux, Some(Environment::Musl | Environment::MuslEABI | Environment::MuslEABIHF)) => {
+            Some(&ENV_MUSL)
+        }
+        (OS::Linux, Some(Environment::Android)) => Some(&ENV_BIONIC),
+        (OS::Win32, Some(Environment::MSVC)) => Some(&ENV_MSVC),
+        (OS::Win32, Some(Environment::GNU)) => Some(&ENV_MINGW),
+        (OS::Darwin | OS::MacOSX | OS::IOS, _) => Some(&ENV_LIBSYSTEM),
+        (OS::FreeBSD | OS::NetBSD | OS::OpenBSD, _) => Some(&ENV_BSD_LIBC),
+        (OS::Fuchsia, _) => Some(&ENV_FUCHSIA),
+        (OS::WASI, _) => Some(&ENV_WASI),
+        (OS::Lilium | OS::CleverOS, _) => Some(&ENV_LILIUM),
+        (OS::AbleOS, _) => Some(&ENV_ABLEOS),
+        (OS::None | OS::Unknown | OS::SNES | OS::NES, _) => Some(&ENV_FREESTANDING),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::properties::link::OutputKind;
+
+    fn env_name(os: OS, env: Option<Environment>) -> Option<&'static str> {
+        from_target(os, env).map(|env| &*env.name)
+    }
+
+    #[test]
+    fn linux_environments() {
+        assert_eq!(env_name(OS::Linux, Some(Environment::GNU)), Some("gnu"));
+        assert_eq!(env_name(OS::Linux, Some(Environment::GNUX32)), Some("gnu"));
+        assert_eq!(
+            env_name(OS::Linux, Some(Environment::GNUEABIHF)),
+            Some("gnu")
+        );
+        assert_eq!(env_name(OS::Linux, Some(Environment::Musl)), Some("musl"));
+        assert_eq!(
+            env_name(OS::Linux, Some(Environment::MuslEABI)),
+            Some("musl")
+        );
+        assert_eq!(
+            env_name(OS::Linux, Some(Environment::Android)),
+            Some("android")
+        );
+        assert_eq!(
+            env_name(OS::Linux, Some(Environment::Kernel)),
+            Some("kernel")
+        );
+    }
+
+    #[test]
+    fn windows_environments() {
+        assert_eq!(env_name(OS::Win32, Some(Environment::MSVC)), Some("msvc"));
+        assert_eq!(env_name(OS::Win32, Some(Environment::GNU)), Some("mingw"));
+        assert_ne!(ENV_MINGW.name, ENV_GLIBC.name);
+        assert_eq!(ENV_MINGW.threading_model, ThreadingModel::Win32);
+    }
+
+    #[test]
+    fn os_environments() {
+        assert_eq!(env_name(OS::MacOSX, None), Some("darwin"));
+        assert_eq!(env_name(OS::FreeBSD, None), Some("bsd"));
+        assert_eq!(env_name(OS::WASI, None), Some("wasi"));
+        assert_eq!(env_name(OS::Lilium, None), Some("lilium"));
+        assert_eq!(
+            env_name(OS::Lilium, Some(Environment::Kernel)),
+            Some("kernel")
+        );
+        assert_eq!(env_name(OS::None, None), Some("none"));
+        assert_eq!(env_name(OS::Solaris, None), None);
+    }
+
+    #[test]
+    fn freestanding_has_no_std() {
+        assert_eq!(ENV_FREESTANDING.libc_name, None);
+        assert!(!ENV_FREESTANDING.has_std);
+        assert!(!ENV_KERNEL.has_std);
+        assert!(ENV_GLIBC.has_std);
+        assert_eq!(ENV_GLIBC.libc_name.as_deref(), Some("glibc"));
+    }
+
+    #[test]
+    fn static_libraries() {
+        let names = |kind| {
+            ENV_GLIBC
+                .link_libraries
+                .for_kind(kind)
+                .iter()
+                .map(|lib| &**lib)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(OutputKind::Pie), ["gcc", "gcc_s"]);
+        assert_eq!(names(OutputKind::Dylib), ["gcc", "gcc_s"]);
+        assert_eq!(names(OutputKind::StaticExe), ["gcc", "gcc_eh"]);
+        assert_eq!(names(OutputKind::StaticPie), ["gcc", "gcc_eh"]);
+        assert!(
+            !ENV_MUSL
+                .link_libraries
+                .for_kind(OutputKind::StaticExe)
+                .iter()
+                .any(|lib| lib == "gcc_s")
+        );
+    }
+}
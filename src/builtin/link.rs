@@ -2,13 +2,31 @@ use target_tuples::pieces::{Architecture, Environment, OS, ObjectFormat, System}
 
 use crate::properties::link::Link;
 
+pub mod arm;
+pub mod avr;
 pub mod clever;
+pub mod holeybytes;
+pub mod m65;
+pub mod m68k;
+pub mod mips;
+pub mod ppc;
+pub mod riscv;
+pub mod sparc;
+pub mod wasm;
 pub mod x86;
 
 pub mod lilium;
 
 pub mod linux;
 
+pub mod darwin;
+
+pub mod bsd;
+
+pub mod fuchsia;
+
+pub mod windows;
+
 /// Obtains the [`Link`] properties for a given arch, os, env triple
 pub const fn from_target(arch: Architecture, sys: System) -> Option<&'static Link> {
     let os = match sys.os() {
@@ -27,9 +45,37 @@ pub const fn from_target(arch: Architecture, sys: System) -> Option<&'static Lin
         (Architecture::X86_64 { .. }, OS::Linux, Some(Environment::GNU), _) => {
             Some(&linux::X86_64_LINUX_GNU_LINK)
         }
+        (Architecture::X86_64 { .. }, OS::Linux, Some(Environment::Musl), _) => {
+            Some(&linux::X86_64_LINUX_MUSL_LINK)
+        }
+        (Architecture::X86_64 { .. }, OS::Linux, Some(Environment::Android), _) => {
+            Some(&linux::X86_64_LINUX_ANDROID_LINK)
+        }
         (Architecture::X86_64 { .. }, OS::Linux, Some(Environment::GNUX32), _) => {
             Some(&linux::X86_64_LINUX_GNUX32_LINK)
         }
+        (Architecture::X86_64 { .. }, OS::FreeBSD, _, _) => Some(&bsd::X86_64_FREEBSD_LINK),
+        (Architecture::X86_64 { .. }, OS::NetBSD, _, _) => Some(&bsd::X86_64_NETBSD_LINK),
+        (Architecture::X86_64 { .. }, OS::OpenBSD, _, _) => Some(&bsd::X86_64_OPENBSD_LINK),
+        (Architecture::X86_64 { .. }, OS::Fuchsia, _, _) => Some(&fuchsia::X86_64_FUCHSIA_LINK),
+        (Architecture::X86_64 { .. }, OS::Darwin | OS::MacOSX, _, _) => {
+            Some(&darwin::X86_64_DARWIN_LINK)
+        }
+        (Architecture::Aarch64, OS::Darwin | OS::MacOSX, _, _) => {
+            Some(&darwin::AARCH64_DARWIN_LINK)
+        }
+        (Architecture::X86_64 { .. }, OS::Win32, Some(Environment::MSVC), _) => {
+            Some(&windows::X86_64_WINDOWS_MSVC_LINK)
+        }
+        (Architecture::X86_64 { .. }, OS::Win32, Some(Environment::GNU), _) => {
+            Some(&windows::X86_64_WINDOWS_GNU_LINK)
+        }
+        (Architecture::X86_32(_), OS::Win32, Some(Environment::MSVC), _) => {
+            Some(&windows::X86_32_WINDOWS_MSVC_LINK)
+        }
+        (Architecture::X86_32(_), OS::Win32, Some(Environment::GNU), _) => {
+            Some(&windows::X86_32_WINDOWS_GNU_LINK)
+        }
         (Architecture::X86_64 { .. }, _, _, Some(ObjectFormat::Elf)) => {
             Some(&x86::ELF_X86_64_FREESTANDING_LINK)
         }
@@ -37,9 +83,126 @@ pub const fn from_target(arch: Architecture, sys: System) -> Option<&'static Lin
         (Architecture::X86_32(_), OS::Linux, Some(Environment::GNU), _) => {
             Some(&linux::X86_32LINUX_GNU_LINK)
         }
+        (Architecture::X86_32(_), OS::Linux, Some(Environment::Musl), _) => {
+            Some(&linux::X86_32_LINUX_MUSL_LINK)
+        }
+        (Architecture::X86_32(_), OS::Linux, Some(Environment::Android), _) => {
+            Some(&linux::X86_32_LINUX_ANDROID_LINK)
+        }
         (Architecture::X86_32(_), _, _, Some(ObjectFormat::Elf)) => {
             Some(&x86::ELF_X86_32_FREESTANDING_LINK)
         }
+        (Architecture::Arm, OS::Linux, Some(Environment::GNUEABIHF), _) => {
+            Some(&linux::ARM_LINUX_GNUEABIHF_LINK)
+        }
+        (Architecture::Arm, OS::Linux, Some(Environment::GNUEABI), _) => {
+            Some(&linux::ARM_LINUX_GNUEABI_LINK)
+        }
+        (Architecture::Arm, OS::None, _, _)
+        | (Architecture::Arm, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&arm::ELF_ARM_FREESTANDING_LINK)
+        }
+        (Architecture::ArmBe, OS::Linux, Some(Environment::GNUEABIHF), _) => {
+            Some(&linux::ARMEB_LINUX_GNUEABIHF_LINK)
+        }
+        (Architecture::ArmBe, OS::Linux, Some(Environment::GNUEABI), _) => {
+            Some(&linux::ARMEB_LINUX_GNUEABI_LINK)
+        }
+        (Architecture::ArmBe, OS::None, _, _)
+        | (Architecture::ArmBe, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&arm::ELF_ARMEB_FREESTANDING_LINK)
+        }
+        (Architecture::PowerPC32, OS::Linux, Some(Environment::GNU), _) => {
+            Some(&linux::PPC32_LINUX_GNU_LINK)
+        }
+        (Architecture::PowerPC32, OS::None, _, _)
+        | (Architecture::PowerPC32, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&ppc::ELF_PPC32_FREESTANDING_LINK)
+        }
+        (Architecture::PowerPC64, OS::Linux, Some(Environment::GNU), _) => {
+            Some(&linux::PPC64_LINUX_GNU_LINK)
+        }
+        (Architecture::PowerPC64, OS::None, _, _)
+        | (Architecture::PowerPC64, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&ppc::ELF_PPC64_FREESTANDING_LINK)
+        }
+        (Architecture::PowerPC64le, OS::Linux, Some(Environment::GNU), _) => {
+            Some(&linux::PPC64LE_LINUX_GNU_LINK)
+        }
+        (Architecture::PowerPC64le, OS::None, _, _)
+        | (Architecture::PowerPC64le, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&ppc::ELF_PPC64LE_FREESTANDING_LINK)
+        }
+        (Architecture::Mips, OS::Linux, Some(Environment::GNU), _) => {
+            Some(&linux::MIPS_LINUX_GNU_LINK)
+        }
+        (Architecture::MipsLE, OS::Linux, Some(Environment::GNU), _) => {
+            Some(&linux::MIPSEL_LINUX_GNU_LINK)
+        }
+        (Architecture::Mips64, OS::Linux, Some(Environment::GNUABIN32), _) => {
+            Some(&linux::MIPS64_LINUX_GNUABIN32_LINK)
+        }
+        (Architecture::Mips64LE, OS::Linux, Some(Environment::GNUABIN32), _) => {
+            Some(&linux::MIPS64EL_LINUX_GNUABIN32_LINK)
+        }
+        (Architecture::Mips64, OS::Linux, Some(Environment::GNUABI64 | Environment::GNU), _) => {
+            Some(&linux::MIPS64_LINUX_GNUABI64_LINK)
+        }
+        (Architecture::Mips64LE, OS::Linux, Some(Environment::GNUABI64 | Environment::GNU), _) => {
+            Some(&linux::MIPS64EL_LINUX_GNUABI64_LINK)
+        }
+        (Architecture::Mips, OS::None, _, _)
+        | (Architecture::Mips, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&mips::ELF_MIPS_FREESTANDING_LINK)
+        }
+        (Architecture::MipsLE, OS::None, _, _)
+        | (Architecture::MipsLE, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&mips::ELF_MIPSEL_FREESTANDING_LINK)
+        }
+        (Architecture::Mips64, OS::None, _, _)
+        | (Architecture::Mips64, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&mips::ELF_MIPS64_FREESTANDING_LINK)
+        }
+        (Architecture::Mips64LE, OS::None, _, _)
+        | (Architecture::Mips64LE, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&mips::ELF_MIPS64EL_FREESTANDING_LINK)
+        }
+        (Architecture::Sparc, OS::Linux, Some(Environment::GNU), _) => {
+            Some(&linux::SPARC_LINUX_GNU_LINK)
+        }
+        (Architecture::SparcV9, OS::Linux, Some(Environment::GNU), _) => {
+            Some(&linux::SPARCV9_LINUX_GNU_LINK)
+        }
+        (Architecture::Sparc, OS::None, _, _)
+        | (Architecture::Sparc, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&sparc::ELF_SPARC_FREESTANDING_LINK)
+        }
+        (Architecture::SparcEL, OS::None, _, _)
+        | (Architecture::SparcEL, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&sparc::ELF_SPARCEL_FREESTANDING_LINK)
+        }
+        (Architecture::SparcV9, OS::None, _, _)
+        | (Architecture::SparcV9, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&sparc::ELF_SPARCV9_FREESTANDING_LINK)
+        }
+        (Architecture::RiscV64, OS::Linux, Some(Environment::GNU), _) => {
+            Some(&linux::RISCV64_LINUX_GNU_LINK)
+        }
+        (Architecture::RiscV64, OS::None, _, _)
+        | (Architecture::RiscV64, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&riscv::ELF_RISCV64_FREESTANDING_LINK)
+        }
+        (Architecture::RiscV32, OS::Linux, Some(Environment::GNU), _) => {
+            Some(&linux::RISCV32_LINUX_GNU_LINK)
+        }
+        (Architecture::RiscV32, OS::None, _, _)
+        | (Architecture::RiscV32, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&riscv::ELF_RISCV32_FREESTANDING_LINK)
+        }
+        (Architecture::Wasm32, OS::WASI, _, _) => Some(&wasm::WASM32_WASI_LINK),
+        (Architecture::Wasm32, OS::None | OS::Unknown, _, _) => Some(&wasm::WASM32_UNKNOWN_LINK),
+        (Architecture::Wasm64, OS::WASI, _, _) => Some(&wasm::WASM64_WASI_LINK),
+        (Architecture::Wasm64, OS::None | OS::Unknown, _, _) => Some(&wasm::WASM64_UNKNOWN_LINK),
         (Architecture::Clever, OS::Lilium | OS::CleverOS, Some(Environment::Kernel), _) => {
             Some(&lilium::CLEVER_LILIUM_KERNEL_LINK)
         }
@@ -49,6 +212,32 @@ pub const fn from_target(arch: Architecture, sys: System) -> Option<&'static Lin
         (Architecture::Clever, _, _, Some(ObjectFormat::Elf)) => {
             Some(&clever::ELF_CLEVER_FREESTANDING_LINK)
         }
+        (Architecture::HoleyBytes, OS::AbleOS, _, _) => Some(&holeybytes::HOLEYBYTES_ABLEOS_LINK),
+        (Architecture::HoleyBytes, OS::None, _, _)
+        | (Architecture::HoleyBytes, _, _, Some(ObjectFormat::Elf)) => {
+            Some(&holeybytes::ELF_HOLEYBYTES_FREESTANDING_LINK)
+        }
+        (Architecture::M6502 | Architecture::M65C02, OS::NES, _, _) => {
+            Some(&m65::NES_NROM_256_LINK)
+        }
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use target_tuples::TargetRef;
+
+    use super::*;
+
+    #[test]
+    fn nes_cartridge() {
+        let targ = TargetRef::parse("6502-unknown-nes");
+        let link = from_target(targ.arch, targ.sys).unwrap();
+        assert!(core::ptr::eq(link, &m65::NES_NROM_256_LINK));
+        assert_eq!(
+            link.memory_layout.as_deref(),
+            Some(&m65::NES_NROM_256_LAYOUT)
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! linking properties common to 32-bit ARM targets
+
+use crate::properties::{
+    binfmt,
+    link::{
+        ArchiveFormat, CODE_MODELS_SMALL, DYLIB_POLICY_NONE, FILENAMES_ELF, HARDENING_NONE, Link,
+        LinkFormat, LinkerFlavour, NxStackMode, RELOC_MODELS_DEFAULT_PIE,
+        RELOC_MODELS_NO_DEFAULT_PIE, RelocModel, RelocModels, SEARCH_UNIX_DEFAULT,
+        StaticLibraryFormat, SupportedArtifacts,
+    },
+};
+
+/// Relocation models for bare-metal ARM, which additionally supports ROPI and RWPI
+pub static ARM_EABI_RELOC_MODELS: RelocModels = RelocModels {
+    supported: slice![
+        RelocModel::Static,
+        RelocModel::Pic,
+        RelocModel::Pie,
+        RelocModel::Ropi,
+        RelocModel::Rwpi,
+        RelocModel::RopiRwpi
+    ],
+    ..RELOC_MODELS_NO_DEFAULT_PIE
+};
+
+/// Elf format for little-endian ARM
+pub static ELF_ARM: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_LITTLEARM,
+    exec_binfmt: binfmt::ELF32_LITTLEARM,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for big-endian ARM
+pub static ELF_ARMEB: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_BIGARM,
+    exec_binfmt: binfmt::ELF32_BIGARM,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding little-endian ARM
+pub static ELF_ARM_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_LITTLEARM,
+    exec_binfmt: binfmt::ELF32_LITTLEARM,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(ARM_EABI_RELOC_MODELS),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding big-endian ARM
+pub static ELF_ARMEB_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_BIGARM,
+    exec_binfmt: binfmt::ELF32_BIGARM,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(ARM_EABI_RELOC_MODELS),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Linking behaviour for Freestanding little-endian ARM (`arm-none-eabi`)
+pub static ELF_ARM_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_ARM_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking behaviour for Freestanding big-endian ARM (`armeb-none-eabi`)
+pub static ELF_ARMEB_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_ARMEB_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
@@ -0,0 +1,35 @@
+//! linking properties for AVR targets
+
+use crate::properties::{
+    binfmt,
+    link::{
+        ArchiveFormat, CODE_MODELS_SMALL, DYLIB_POLICY_NONE, FILENAMES_ELF, HARDENING_NONE, Link,
+        LinkFormat, LinkerFlavour, NxStackMode, RELOC_MODELS_STATIC, SEARCH_UNIX_DEFAULT,
+        StaticLibraryFormat, SupportedArtifacts,
+    },
+};
+
+/// Elf format for AVR. Only static, non-position independent executables are supported
+pub static ELF_AVR: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_AVR,
+    exec_binfmt: binfmt::ELF32_AVR,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::EXE,
+    reloc_models: cow!(RELOC_MODELS_STATIC),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Linking behaviour for AVR
+pub static ELF_AVR_LINK: Link = Link {
+    formats: cow!(ELF_AVR),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
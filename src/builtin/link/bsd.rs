@@ -0,0 +1,150 @@
+//! FreeBSD, NetBSD, and OpenBSD
+
+use crate::{
+    builtin::link::x86::{CODE_MODELS_X86_64, ELF_X86_64},
+    properties::link::{
+        DYLIB_POLICY_ELF, DefaultLinking, DylibPolicy, FILENAMES_ELF, HARDENING_NONE,
+        LibrarySearch, Link, NxStackMode, SonameVersion, StartupFiles,
+    },
+};
+
+/// Library search for FreeBSD and NetBSD. Libraries are installed in the base system and `/usr/local`
+pub const SEARCH_BSD: LibrarySearch = LibrarySearch {
+    base_dirs: slice![cowstr!("/"), cowstr!("/usr"), cowstr!("/usr/local")],
+    search_dirs: slice![cowstr!("lib")],
+    staticlib_prefixes: slice![cowstr!("lib")],
+    staticlib_suffixes: slice![cowstr!(".a")],
+    dylib_prefixes: slice![cowstr!("lib")],
+    dylib_suffixes: slice![cowstr!(".so")],
+    use_target_stem_dirs: false,
+};
+
+/// Library search for OpenBSD. There is no `/lib`, and base libraries are in `/usr/lib`
+pub static SEARCH_OPENBSD: LibrarySearch = LibrarySearch {
+    base_dirs: slice![cowstr!("/usr"), cowstr!("/usr/local")],
+    ..SEARCH_BSD
+};
+
+/// Link filenames for FreeBSD
+pub static FREEBSD_LIBRARIES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles {
+        exe: slice![cowstr!("crt1.o"), cowstr!("crti.o"), cowstr!("crtbegin.o")],
+        pie: slice![
+            cowstr!("Scrt1.o"),
+            cowstr!("crti.o"),
+            cowstr!("crtbeginS.o")
+        ],
+        static_exe: slice![cowstr!("crt1.o"), cowstr!("crti.o"), cowstr!("crtbeginT.o")],
+        static_pie: slice![
+            cowstr!("Scrt1.o"),
+            cowstr!("crti.o"),
+            cowstr!("crtbeginS.o")
+        ],
+        dylib: slice![cowstr!("crti.o"), cowstr!("crtbeginS.o")],
+    },
+    end_files: StartupFiles {
+        exe: slice![cowstr!("crtend.o"), cowstr!("crtn.o")],
+        pie: slice![cowstr!("crtendS.o"), cowstr!("crtn.o")],
+        static_exe: slice![cowstr!("crtend.o"), cowstr!("crtn.o")],
+        static_pie: slice![cowstr!("crtendS.o"), cowstr!("crtn.o")],
+        dylib: slice![cowstr!("crtendS.o"), cowstr!("crtn.o")],
+    },
+    libraries: slice![cowstr!("c")],
+    static_libraries: None,
+};
+
+/// Link filenames for NetBSD
+pub static NETBSD_LIBRARIES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles {
+        exe: slice![cowstr!("crt0.o"), cowstr!("crti.o"), cowstr!("crtbegin.o")],
+        pie: slice![cowstr!("crt0.o"), cowstr!("crti.o"), cowstr!("crtbeginS.o")],
+        static_exe: slice![cowstr!("crt0.o"), cowstr!("crti.o"), cowstr!("crtbeginT.o")],
+        static_pie: slice![cowstr!("crt0.o"), cowstr!("crti.o"), cowstr!("crtbeginS.o")],
+        dylib: slice![cowstr!("crti.o"), cowstr!("crtbeginS.o")],
+    },
+    end_files: StartupFiles {
+        exe: slice![cowstr!("crtend.o"), cowstr!("crtn.o")],
+        pie: slice![cowstr!("crtendS.o"), cowstr!("crtn.o")],
+        static_exe: slice![cowstr!("crtend.o"), cowstr!("crtn.o")],
+        static_pie: slice![cowstr!("crtendS.o"), cowstr!("crtn.o")],
+        dylib: slice![cowstr!("crtendS.o"), cowstr!("crtn.o")],
+    },
+    libraries: slice![cowstr!("c")],
+    static_libraries: None,
+};
+
+/// Link filenames for OpenBSD. `crtbegin.o` provides the `_init`/`_fini` sections, so there is no `crti.o`
+pub static OPENBSD_LIBRARIES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles {
+        exe: slice![cowstr!("crt0.o"), cowstr!("crtbegin.o")],
+        pie: slice![cowstr!("crt0.o"), cowstr!("crtbegin.o")],
+        static_exe: slice![cowstr!("crt0.o"), cowstr!("crtbegin.o")],
+        static_pie: slice![cowstr!("rcrt0.o"), cowstr!("crtbegin.o")],
+        dylib: slice![cowstr!("crtbeginS.o")],
+    },
+    end_files: StartupFiles {
+        exe: slice![cowstr!("crtend.o")],
+        pie: slice![cowstr!("crtend.o")],
+        static_exe: slice![cowstr!("crtend.o")],
+        static_pie: slice![cowstr!("crtend.o")],
+        dylib: slice![cowstr!("crtendS.o")],
+    },
+    libraries: slice![cowstr!("c")],
+    static_libraries: None,
+};
+
+/// Shared library policy for FreeBSD. The system linker (lld) records `DT_RUNPATH` by default
+pub static FREEBSD_DYLIB_POLICY: DylibPolicy = DylibPolicy {
+    requires_new_dtags: false,
+    ..DYLIB_POLICY_ELF
+};
+
+/// Shared library policy for OpenBSD. Sonames include the major and minor version (`libfoo.so.1.2`),
+///  and the system linker (lld) records `DT_RUNPATH` by default
+pub static OPENBSD_DYLIB_POLICY: DylibPolicy = DylibPolicy {
+    soname_version: SonameVersion::Full,
+    requires_new_dtags: false,
+    ..DYLIB_POLICY_ELF
+};
+
+/// Linking for x86_64-unknown-freebsd
+pub static X86_64_FREEBSD_LINK: Link = Link {
+    formats: cow!(ELF_X86_64),
+    search: cow!(SEARCH_BSD),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/libexec/ld-elf.so.1")),
+    dylib_policy: cow!(FREEBSD_DYLIB_POLICY),
+    default_libraries: Some(cow!(FREEBSD_LIBRARIES)),
+    memory_layout: None,
+};
+
+/// Linking for x86_64-unknown-netbsd
+pub static X86_64_NETBSD_LINK: Link = Link {
+    formats: cow!(ELF_X86_64),
+    search: cow!(SEARCH_BSD),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/usr/libexec/ld.elf_so")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(NETBSD_LIBRARIES)),
+    memory_layout: None,
+};
+
+/// Linking for x86_64-unknown-openbsd. OpenBSD enforces W^X, so the stack is never executable
+pub static X86_64_OPENBSD_LINK: Link = Link {
+    formats: cow!(ELF_X86_64),
+    search: cow!(SEARCH_OPENBSD),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/usr/libexec/ld.so")),
+    dylib_policy: cow!(OPENBSD_DYLIB_POLICY),
+    default_libraries: Some(cow!(OPENBSD_LIBRARIES)),
+    memory_layout: None,
+};
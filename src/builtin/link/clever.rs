@@ -1,29 +1,38 @@
 //! linking properties common to x86 targets
 
-use crate::properties::link::{
-    FILENAMES_ELF, Link, LinkFormat, SEARCH_UNIX_DEFAULT, SupportedArtifacts,
+use crate::properties::{
+    binfmt,
+    link::{
+        CODE_MODELS_SMALL, DYLIB_POLICY_NONE, FILENAMES_ELF, HARDENING_NONE, Link, LinkFormat,
+        RELOC_MODELS_DEFAULT_PIE, RELOC_MODELS_NO_DEFAULT_PIE, SEARCH_UNIX_DEFAULT,
+        SupportedArtifacts,
+    },
 };
 
 /// Elf format for Clever-ISA
 pub static ELF_CLEVER: LinkFormat = LinkFormat {
-    object_binfmt: cowstr!("elf64-clever"),
-    exec_binfmt: cowstr!("elf64-clever"),
+    object_binfmt: binfmt::ELF64_CLEVER,
+    exec_binfmt: binfmt::ELF64_CLEVER,
     staticlib_format: crate::properties::link::StaticLibraryFormat::Archive(
         crate::properties::link::ArchiveFormat::SysV,
     ),
     supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
     default_linker_format: crate::properties::link::LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
 };
 
 /// Elf format for Freestanding Clever-ISA
 pub static ELF_CLEVER_FREESTANDING: LinkFormat = LinkFormat {
-    object_binfmt: cowstr!("elf64-clever"),
-    exec_binfmt: cowstr!("elf64-clever"),
+    object_binfmt: binfmt::ELF64_CLEVER,
+    exec_binfmt: binfmt::ELF64_CLEVER,
     staticlib_format: crate::properties::link::StaticLibraryFormat::Archive(
         crate::properties::link::ArchiveFormat::SysV,
     ),
     supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
     default_linker_format: crate::properties::link::LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
 };
 
 /// Linking for Freestanding Clever-ISA
@@ -32,6 +41,10 @@ pub static ELF_CLEVER_FREESTANDING_LINK: Link = Link {
     search: cow!(SEARCH_UNIX_DEFAULT),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: crate::properties::link::NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
     dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
     default_libraries: None,
+    memory_layout: None,
 };
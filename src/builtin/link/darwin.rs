@@ -0,0 +1,97 @@
+//! Darwin (macOS)
+
+use crate::{
+    builtin::link::x86::CODE_MODELS_X86_64,
+    properties::{
+        binfmt,
+        link::{
+            ArchiveFormat, CODE_MODELS_SMALL, DYLIB_POLICY_MACHO, DefaultLinking, FILENAMES_MACHO,
+            HARDENING_NONE, Link, LinkFormat, LinkerFlavour, NxStackMode, RelocModel, RelocModels,
+            SEARCH_DARWIN, StartupFiles, StaticLibraryFormat, SupportedArtifacts,
+        },
+    },
+};
+
+/// Supported artifacts on Darwin. Executables are always dynamically linked, and PIE by default
+const DARWIN_ARTIFACTS: SupportedArtifacts = SupportedArtifacts::EXE
+    .union(SupportedArtifacts::DYLIB)
+    .union(SupportedArtifacts::PIC)
+    .union(SupportedArtifacts::PIE)
+    .union(SupportedArtifacts::DEFAULT_PIE)
+    .union(SupportedArtifacts::DEFAULT_DYNAMIC);
+
+/// Relocation models for x86-64 Darwin. Code is position independent by default, as all executables are PIE
+pub static DARWIN_RELOC_MODELS: RelocModels = RelocModels {
+    supported: slice![
+        RelocModel::Static,
+        RelocModel::Pic,
+        RelocModel::Pie,
+        RelocModel::DynamicNoPic
+    ],
+    default: RelocModel::Pic,
+};
+
+/// Relocation models for arm64 Darwin. Executables are always PIE, so `-mdynamic-no-pic` is not supported
+pub static DARWIN_ARM64_RELOC_MODELS: RelocModels = RelocModels {
+    supported: slice![RelocModel::Static, RelocModel::Pic, RelocModel::Pie],
+    default: RelocModel::Pic,
+};
+
+/// Mach-O format for x86-64
+pub static MACHO_X86_64: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::MACHO_X86_64,
+    exec_binfmt: binfmt::MACHO_X86_64,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::Bsd),
+    supported_artifacts: DARWIN_ARTIFACTS,
+    reloc_models: cow!(DARWIN_RELOC_MODELS),
+    default_linker_format: LinkerFlavour::MachLd,
+    symbol_prefix: cowstr!("_"),
+};
+
+/// Mach-O format for AArch64
+pub static MACHO_AARCH64: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::MACHO_ARM64,
+    exec_binfmt: binfmt::MACHO_ARM64,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::Bsd),
+    supported_artifacts: DARWIN_ARTIFACTS,
+    reloc_models: cow!(DARWIN_ARM64_RELOC_MODELS),
+    default_linker_format: LinkerFlavour::MachLd,
+    symbol_prefix: cowstr!("_"),
+};
+
+/// Library set for Darwin. `libSystem` provides libc, libm, and the startup code.
+/// `libSystem` is only available as a dylib, so static executables (such as kernels) link no default libraries
+pub static DARWIN_LIBRARIES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles::NONE,
+    end_files: StartupFiles::NONE,
+    libraries: slice![cowstr!("System")],
+    static_libraries: Some(slice![]),
+};
+
+/// Linking for x86_64-apple-darwin
+pub static X86_64_DARWIN_LINK: Link = Link {
+    formats: cow!(MACHO_X86_64),
+    search: cow!(SEARCH_DARWIN),
+    output_filename: cow!(FILENAMES_MACHO),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/usr/lib/dyld")),
+    dylib_policy: cow!(DYLIB_POLICY_MACHO),
+    default_libraries: Some(cow!(DARWIN_LIBRARIES)),
+    memory_layout: None,
+};
+
+/// Linking for aarch64-apple-darwin
+pub static AARCH64_DARWIN_LINK: Link = Link {
+    formats: cow!(MACHO_AARCH64),
+    search: cow!(SEARCH_DARWIN),
+    output_filename: cow!(FILENAMES_MACHO),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/usr/lib/dyld")),
+    dylib_policy: cow!(DYLIB_POLICY_MACHO),
+    default_libraries: Some(cow!(DARWIN_LIBRARIES)),
+    memory_layout: None,
+};
@@ -0,0 +1,55 @@
+//! Fuchsia
+
+use crate::{
+    builtin::link::x86::{CODE_MODELS_X86_64, ELF_X86_64},
+    properties::link::{
+        DYLIB_POLICY_NONE, DefaultLinking, DylibPolicy, FILENAMES_ELF, HARDENING_NONE,
+        LibrarySearch, Link, NxStackMode, SonameMode, SonameVersion, StartupFiles,
+    },
+};
+
+/// Library search for Fuchsia. Libraries are only found in the SDK sysroot
+pub static SEARCH_FUCHSIA: LibrarySearch = LibrarySearch {
+    base_dirs: slice![cowstr!("/")],
+    search_dirs: slice![cowstr!("lib")],
+    staticlib_prefixes: slice![cowstr!("lib")],
+    staticlib_suffixes: slice![cowstr!(".a")],
+    dylib_prefixes: slice![cowstr!("lib")],
+    dylib_suffixes: slice![cowstr!(".so")],
+    use_target_stem_dirs: false,
+};
+
+/// Link filenames for Fuchsia. All executables are PIE, so the `Scrt1.o` startfile is used
+pub static FUCHSIA_LIBRARIES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles {
+        exe: slice![cowstr!("Scrt1.o")],
+        pie: slice![cowstr!("Scrt1.o")],
+        static_exe: slice![cowstr!("Scrt1.o")],
+        static_pie: slice![cowstr!("Scrt1.o")],
+        dylib: slice![],
+    },
+    end_files: StartupFiles::NONE,
+    libraries: slice![cowstr!("c"), cowstr!("zircon")],
+    static_libraries: None,
+};
+
+/// Shared library policy for Fuchsia. Sonames are unversioned, and the dynamic linker does not support runtime search paths
+pub static FUCHSIA_DYLIB_POLICY: DylibPolicy = DylibPolicy {
+    soname: SonameMode::Soname,
+    soname_version: SonameVersion::None,
+    ..DYLIB_POLICY_NONE
+};
+
+/// Linking for x86_64-unknown-fuchsia
+pub static X86_64_FUCHSIA_LINK: Link = Link {
+    formats: cow!(ELF_X86_64),
+    search: cow!(SEARCH_FUCHSIA),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("ld.so.1")),
+    dylib_policy: cow!(FUCHSIA_DYLIB_POLICY),
+    default_libraries: Some(cow!(FUCHSIA_LIBRARIES)),
+    memory_layout: None,
+};
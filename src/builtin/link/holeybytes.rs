@@ -0,0 +1,72 @@
+//! linking properties for Holey Bytes targets
+
+use crate::properties::{
+    binfmt,
+    link::{
+        CODE_MODELS_SMALL, DYLIB_POLICY_ELF, DYLIB_POLICY_NONE, DefaultLinking, FILENAMES_ELF,
+        HARDENING_NONE, Link, LinkFormat, RELOC_MODELS_STATIC_PIE, SEARCH_UNIX_DEFAULT,
+        StartupFiles, SupportedArtifacts,
+    },
+};
+
+/// Elf format for Holey Bytes
+pub static ELF_HOLEYBYTES: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_HOLEYBYTES,
+    exec_binfmt: binfmt::ELF64_HOLEYBYTES,
+    staticlib_format: crate::properties::link::StaticLibraryFormat::Archive(
+        crate::properties::link::ArchiveFormat::SysV,
+    ),
+    supported_artifacts: SupportedArtifacts::NO_DYNLINKER,
+    reloc_models: cow!(RELOC_MODELS_STATIC_PIE),
+    default_linker_format: crate::properties::link::LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding Holey Bytes
+pub static ELF_HOLEYBYTES_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_HOLEYBYTES,
+    exec_binfmt: binfmt::ELF64_HOLEYBYTES,
+    staticlib_format: crate::properties::link::StaticLibraryFormat::Archive(
+        crate::properties::link::ArchiveFormat::SysV,
+    ),
+    supported_artifacts: SupportedArtifacts::NO_DYNLINKER,
+    reloc_models: cow!(RELOC_MODELS_STATIC_PIE),
+    default_linker_format: crate::properties::link::LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Library set for AbleOS
+pub static ABLEOS_LIBRARIES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles::NONE,
+    end_files: StartupFiles::NONE,
+    libraries: slice![cowstr!("c")],
+    static_libraries: None,
+};
+
+/// Linking for holeybytes-ableos
+pub static HOLEYBYTES_ABLEOS_LINK: Link = Link {
+    formats: cow!(ELF_HOLEYBYTES),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(ABLEOS_LIBRARIES)),
+    memory_layout: None,
+};
+
+/// Linking for Freestanding Holey Bytes
+pub static ELF_HOLEYBYTES_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_HOLEYBYTES_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
@@ -3,16 +3,44 @@
 use crate::{
     builtin::link::{
         clever::ELF_CLEVER,
-        x86::{ELF_X86_32, ELF_X86_64},
+        x86::{CODE_MODELS_X86_64, ELF_X86_32, ELF_X86_64},
+    },
+    properties::binfmt,
+    properties::link::{
+        ArchiveFormat, CODE_MODELS_SMALL, CodeModel, CodeModels, DYLIB_POLICY_ELF,
+        DYLIB_POLICY_NONE, DefaultLinking, FILENAMES_ELF, Hardening, Link, LinkFormat,
+        LinkerFlavour, MemoryLayout, NxStackMode, RELOC_MODELS_STATIC, RelroMode,
+        SEARCH_UNIX_DEFAULT, SectionPlacement, StackProtector, StartupFiles, StaticLibraryFormat,
+        SupportedArtifacts,
     },
-    properties::link::{DefaultLinking, FILENAMES_ELF, Link, NxStackMode, SEARCH_UNIX_DEFAULT},
 };
 
 /// Library set for Lilium
 pub static LILIUM_LIBRARIES: DefaultLinking = DefaultLinking {
-    start_files: slice![cowstr!("liblilium-init.o")],
-    end_files: slice![],
+    start_files: StartupFiles {
+        exe: slice![cowstr!("liblilium-init.o")],
+        pie: slice![cowstr!("liblilium-init.o")],
+        static_exe: slice![cowstr!("liblilium-init.o")],
+        static_pie: slice![cowstr!("liblilium-init.o")],
+        ..StartupFiles::NONE
+    },
+    end_files: StartupFiles::NONE,
     libraries: slice![cowstr!("c"), cowstr!("usi"), cowstr!("usi-support")],
+    static_libraries: None,
+};
+
+/// Hardening for Lilium userspace. `_FORTIFY_SOURCE` is not enabled by default
+pub static LILIUM_HARDENING: Hardening = Hardening {
+    relro: RelroMode::Full,
+    stack_protector: StackProtector::Strong,
+    stack_clash_protection: true,
+    ..Hardening::NONE
+};
+
+/// Hardening for the Lilium kernel. Only the stack protector is enabled by default
+pub static LILIUM_KERNEL_HARDENING: Hardening = Hardening {
+    stack_protector: StackProtector::Strong,
+    ..Hardening::NONE
 };
 
 /// Linking for x86_64-lilium
@@ -21,8 +49,12 @@ pub static X86_64_LILIUM_LINK: Link = Link {
     search: cow!(SEARCH_UNIX_DEFAULT),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(LILIUM_HARDENING),
     dynlinker_name: Some(cowstr!("/lib/ld-lilium-x86_64.so.0")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
     default_libraries: Some(cow!(LILIUM_LIBRARIES)),
+    memory_layout: None,
 };
 
 /// Linking for i686-lilium and i786-lilium
@@ -31,8 +63,12 @@ pub static X86_32_LILIUM_LINK: Link = Link {
     search: cow!(SEARCH_UNIX_DEFAULT),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LILIUM_HARDENING),
     dynlinker_name: Some(cowstr!("/lib/ld-lilium-i686.so.0")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
     default_libraries: Some(cow!(LILIUM_LIBRARIES)),
+    memory_layout: None,
 };
 
 /// Linking for clever-lilium and clever-cleveros
@@ -41,36 +77,170 @@ pub static CLEVER_LILIUM_LINK: Link = Link {
     search: cow!(SEARCH_UNIX_DEFAULT),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LILIUM_HARDENING),
     dynlinker_name: Some(cowstr!("/lib/ld-lilium-clever.so.0")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
     default_libraries: Some(cow!(LILIUM_LIBRARIES)),
+    memory_layout: None,
+};
+
+const KERNEL_PAGE_ALIGN: u64 = 4096;
+
+const KERNEL_RODATA: SectionPlacement = SectionPlacement {
+    name: cowstr!(".rodata"),
+    inputs: slice![cowstr!(".rodata"), cowstr!(".rodata.*")],
+    align: Some(KERNEL_PAGE_ALIGN),
+    ..SectionPlacement::DEFAULT
+};
+
+const KERNEL_DATA: SectionPlacement = SectionPlacement {
+    name: cowstr!(".data"),
+    inputs: slice![cowstr!(".data"), cowstr!(".data.*")],
+    align: Some(KERNEL_PAGE_ALIGN),
+    ..SectionPlacement::DEFAULT
+};
+
+const KERNEL_BSS: SectionPlacement = SectionPlacement {
+    name: cowstr!(".bss"),
+    inputs: slice![cowstr!(".bss"), cowstr!(".bss.*"), cowstr!("COMMON")],
+    align: Some(KERNEL_PAGE_ALIGN),
+    noload: true,
+    ..SectionPlacement::DEFAULT
+};
+
+const fn kernel_text(base: u64) -> SectionPlacement {
+    SectionPlacement {
+        name: cowstr!(".text"),
+        inputs: slice![cowstr!(".text"), cowstr!(".text.*")],
+        address: Some(base),
+        align: Some(KERNEL_PAGE_ALIGN),
+        ..SectionPlacement::DEFAULT
+    }
+}
+
+/// Memory layout of the x86_64 Lilium kernel, as loaded by `/lilium-loader.x86_64`.
+/// The kernel is linked in the top 2GiB of the address space
+pub static X86_64_LILIUM_KERNEL_LAYOUT: MemoryLayout = MemoryLayout {
+    entry: Some(cowstr!("_start")),
+    regions: slice![],
+    sections: slice![
+        kernel_text(0xFFFF_FFFF_8000_0000),
+        KERNEL_RODATA,
+        KERNEL_DATA,
+        KERNEL_BSS
+    ],
+};
+
+/// Memory layout of the i686 Lilium kernel, as loaded by `/lilium-loader.i686`.
+/// The kernel is linked in the top 1GiB of the address space
+pub static X86_32_LILIUM_KERNEL_LAYOUT: MemoryLayout = MemoryLayout {
+    entry: Some(cowstr!("_start")),
+    regions: slice![],
+    sections: slice![
+        kernel_text(0xC000_0000),
+        KERNEL_RODATA,
+        KERNEL_DATA,
+        KERNEL_BSS
+    ],
+};
+
+/// Memory layout of the Clever Lilium kernel, as loaded by `/lilium-loader.clever`.
+/// The kernel is linked in the top 2GiB of the address space
+pub static CLEVER_LILIUM_KERNEL_LAYOUT: MemoryLayout = MemoryLayout {
+    entry: Some(cowstr!("_start")),
+    regions: slice![],
+    sections: slice![
+        kernel_text(0xFFFF_FFFF_8000_0000),
+        KERNEL_RODATA,
+        KERNEL_DATA,
+        KERNEL_BSS
+    ],
+};
+
+/// Code models for the x86-64 Lilium kernel, which is linked in the top 2GiB of the address space
+pub static X86_64_LILIUM_KERNEL_CODE_MODELS: CodeModels = CodeModels {
+    default: CodeModel::Kernel,
+    ..CODE_MODELS_X86_64
+};
+
+/// Code models for the Clever Lilium kernel, which is linked in the top 2GiB of the address space, as on x86-64
+pub static CLEVER_LILIUM_KERNEL_CODE_MODELS: CodeModels = CodeModels {
+    supported: slice![CodeModel::Small, CodeModel::Kernel],
+    default: CodeModel::Kernel,
+};
+
+/// Elf format for the x86-64 Lilium kernel. The kernel is a position dependent executable, and does not use dynamic libraries
+pub static ELF_X86_64_LILIUM_KERNEL: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_X86_64,
+    exec_binfmt: binfmt::ELF64_X86_64,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::EXE,
+    reloc_models: cow!(RELOC_MODELS_STATIC),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for the x86-32 Lilium kernel. The kernel is a position dependent executable, and does not use dynamic libraries
+pub static ELF_X86_32_LILIUM_KERNEL: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_X86,
+    exec_binfmt: binfmt::ELF32_X86,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::EXE,
+    reloc_models: cow!(RELOC_MODELS_STATIC),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for the Clever Lilium kernel. The kernel is a position dependent executable, and does not use dynamic libraries
+pub static ELF_CLEVER_LILIUM_KERNEL: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_CLEVER,
+    exec_binfmt: binfmt::ELF64_CLEVER,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::EXE,
+    reloc_models: cow!(RELOC_MODELS_STATIC),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
 };
 
 /// Linking for x86_64-lilium-kernel
 pub static X86_64_LILIUM_KERNEL_LINK: Link = Link {
-    formats: cow!(ELF_X86_64),
+    formats: cow!(ELF_X86_64_LILIUM_KERNEL),
     search: cow!(SEARCH_UNIX_DEFAULT),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: NxStackMode::Default,
+    code_models: cow!(X86_64_LILIUM_KERNEL_CODE_MODELS),
+    hardening: cow!(LILIUM_KERNEL_HARDENING),
     dynlinker_name: Some(cowstr!("/lilium-loader.x86_64")),
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
     default_libraries: None,
+    memory_layout: Some(cow!(X86_64_LILIUM_KERNEL_LAYOUT)),
 };
 
 /// Linking for i686-lilium-kernel
 pub static X86_32_LILIUM_KERNEL_LINK: Link = Link {
-    formats: cow!(ELF_X86_32),
+    formats: cow!(ELF_X86_32_LILIUM_KERNEL),
     search: cow!(SEARCH_UNIX_DEFAULT),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LILIUM_KERNEL_HARDENING),
     dynlinker_name: Some(cowstr!("/lilium-loader.i686")),
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
     default_libraries: None,
+    memory_layout: Some(cow!(X86_32_LILIUM_KERNEL_LAYOUT)),
 };
 
 /// Linking for clever-lilium-kernel and clever-cleveros-kernel
 pub static CLEVER_LILIUM_KERNEL_LINK: Link = Link {
-    formats: cow!(ELF_CLEVER),
+    formats: cow!(ELF_CLEVER_LILIUM_KERNEL),
     search: cow!(SEARCH_UNIX_DEFAULT),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: NxStackMode::Default,
+    code_models: cow!(CLEVER_LILIUM_KERNEL_CODE_MODELS),
+    hardening: cow!(LILIUM_KERNEL_HARDENING),
     dynlinker_name: Some(cowstr!("/lilium-loader.clever")),
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
     default_libraries: None,
+    memory_layout: Some(cow!(CLEVER_LILIUM_KERNEL_LAYOUT)),
 };
@@ -1,18 +1,137 @@
 //! Linux
 
 use crate::{
+    builtin::link::arm::{ELF_ARM, ELF_ARMEB},
+    builtin::link::m68k::ELF_M68K,
+    builtin::link::mips::{
+        ELF_MIPS, ELF_MIPS_N32, ELF_MIPS_N32_MULTILIB, ELF_MIPS_N64_MULTILIB, ELF_MIPS64,
+        ELF_MIPS64EL, ELF_MIPSEL, ELF_MIPSEL_N32,
+    },
+    builtin::link::ppc::{
+        CODE_MODELS_PPC64, ELF_PPC32, ELF_PPC64, ELF_PPC64_MULTILIB, ELF_PPC64LE,
+    },
+    builtin::link::riscv::{
+        CODE_MODELS_RISCV, CODE_MODELS_RISCV_MEDANY, ELF_RISCV32, ELF_RISCV32_MULTILIB,
+        ELF_RISCV64, ELF_RISCV64_MULTILIB,
+    },
+    builtin::link::sparc::{ELF_SPARC, ELF_SPARCV9, ELF_SPARCV9_MULTILIB},
     builtin::link::x86::{
-        ELF_X86_32, ELF_X86_32_MULTILIB, ELF_X86_64, ELF_X86_64_MULTILIB, ELF_X86_64_MULTILIBX32,
-        ELF_X86_64_X32,
+        CODE_MODELS_X86_64, ELF_X86_32, ELF_X86_32_MULTILIB, ELF_X86_32_MUSL, ELF_X86_64,
+        ELF_X86_64_MULTILIB, ELF_X86_64_MULTILIBX32, ELF_X86_64_MUSL, ELF_X86_64_X32,
     },
-    properties::link::{DefaultLinking, FILENAMES_ELF, Link},
+    properties::link::{
+        CODE_MODELS_SMALL, CfProtection, DYLIB_POLICY_ELF, DefaultLinking, DylibPolicy,
+        FILENAMES_ELF, HARDENING_NONE, Hardening, LibrarySearch, Link, RelroMode,
+        SEARCH_UNIX_DEFAULT, SonameVersion, StackProtector, StartupFiles,
+    },
+};
+
+/// Startfiles for the GNU toolchain using glibc-style crt objects
+pub const GNU_START_FILES: StartupFiles = StartupFiles {
+    exe: slice![cowstr!("crt1.o"), cowstr!("crti.o"), cowstr!("crtbegin.o")],
+    pie: slice![
+        cowstr!("Scrt1.o"),
+        cowstr!("crti.o"),
+        cowstr!("crtbeginS.o")
+    ],
+    static_exe: slice![cowstr!("crt1.o"), cowstr!("crti.o"), cowstr!("crtbeginT.o")],
+    static_pie: slice![
+        cowstr!("rcrt1.o"),
+        cowstr!("crti.o"),
+        cowstr!("crtbeginS.o")
+    ],
+    dylib: slice![cowstr!("crti.o"), cowstr!("crtbeginS.o")],
+};
+
+/// Endfiles for the GNU toolchain using glibc-style crt objects
+pub const GNU_END_FILES: StartupFiles = StartupFiles {
+    exe: slice![cowstr!("crtend.o"), cowstr!("crtn.o")],
+    pie: slice![cowstr!("crtendS.o"), cowstr!("crtn.o")],
+    static_exe: slice![cowstr!("crtend.o"), cowstr!("crtn.o")],
+    static_pie: slice![cowstr!("crtendS.o"), cowstr!("crtn.o")],
+    dylib: slice![cowstr!("crtendS.o"), cowstr!("crtn.o")],
 };
 
 /// Link filenames for linux
 pub static LINUX_FILENAMES: DefaultLinking = DefaultLinking {
-    start_files: slice![cowstr!("crt1.o"), cowstr!("crti.o")],
-    end_files: slice![cowstr!("crto.o")],
+    start_files: GNU_START_FILES,
+    end_files: GNU_END_FILES,
+    libraries: slice![cowstr!("c")],
+    static_libraries: None,
+};
+
+/// Link filenames for linux with musl
+pub static MUSL_FILENAMES: DefaultLinking = DefaultLinking {
+    start_files: GNU_START_FILES,
+    end_files: GNU_END_FILES,
+    libraries: slice![cowstr!("c")],
+    static_libraries: None,
+};
+
+/// Link filenames for Android (Bionic)
+pub static BIONIC_FILENAMES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles {
+        exe: slice![cowstr!("crtbegin_dynamic.o")],
+        pie: slice![cowstr!("crtbegin_dynamic.o")],
+        static_exe: slice![cowstr!("crtbegin_static.o")],
+        static_pie: slice![cowstr!("crtbegin_static.o")],
+        dylib: slice![cowstr!("crtbegin_so.o")],
+    },
+    end_files: StartupFiles {
+        exe: slice![cowstr!("crtend_android.o")],
+        pie: slice![cowstr!("crtend_android.o")],
+        static_exe: slice![cowstr!("crtend_android.o")],
+        static_pie: slice![cowstr!("crtend_android.o")],
+        dylib: slice![cowstr!("crtend_so.o")],
+    },
+    libraries: slice![cowstr!("c")],
+    static_libraries: None,
+};
+
+/// Link filenames for linux with uClibc
+pub static UCLIBC_FILENAMES: DefaultLinking = DefaultLinking {
+    start_files: GNU_START_FILES,
+    end_files: GNU_END_FILES,
     libraries: slice![cowstr!("c")],
+    static_libraries: None,
+};
+
+/// Shared library policy for Android. Sonames are unversioned, and `DT_RUNPATH` is only honored since API level 24
+pub static ANDROID_DYLIB_POLICY: DylibPolicy = DylibPolicy {
+    soname_version: SonameVersion::None,
+    ..DYLIB_POLICY_ELF
+};
+
+/// Hardening for Linux GNU distributions: full RELRO, `-fstack-protector-strong`, `_FORTIFY_SOURCE=2`, and stack clash protection
+pub const LINUX_GNU_HARDENING: Hardening = Hardening {
+    relro: RelroMode::Full,
+    stack_protector: StackProtector::Strong,
+    fortify_source: 2,
+    stack_clash_protection: true,
+    ..Hardening::NONE
+};
+
+/// Hardening for Linux GNU distributions on x86, which additionally enables CET shadow stacks and indirect branch tracking
+pub static LINUX_GNU_CET_HARDENING: Hardening = Hardening {
+    cf_protection: CfProtection::all(),
+    gnu_property_note: true,
+    ..LINUX_GNU_HARDENING
+};
+
+/// Library Search for 64-bit Android
+pub static SEARCH_ANDROID64: LibrarySearch = LibrarySearch {
+    base_dirs: slice![cowstr!("/system"), cowstr!("/vendor")],
+    search_dirs: slice![cowstr!("lib64")],
+    use_target_stem_dirs: false,
+    ..SEARCH_UNIX_DEFAULT
+};
+
+/// Library Search for 32-bit Android
+pub static SEARCH_ANDROID: LibrarySearch = LibrarySearch {
+    base_dirs: slice![cowstr!("/system"), cowstr!("/vendor")],
+    search_dirs: slice![cowstr!("lib")],
+    use_target_stem_dirs: false,
+    ..SEARCH_UNIX_DEFAULT
 };
 
 /// Linking for x86_64-linux-gnu
@@ -21,8 +140,12 @@ pub static X86_64_LINUX_GNU_LINK: Link = Link {
     search: cow!(ELF_X86_64_MULTILIB),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: crate::properties::link::NxStackMode::GnuStack,
-    dynlinker_name: Some(cowstr!("/lib/ld-linux-x86_64.so.2")),
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(LINUX_GNU_CET_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib64/ld-linux-x86-64.so.2")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
     default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
 };
 
 /// Linking for x86_64-linux-gnux32
@@ -31,8 +154,12 @@ pub static X86_64_LINUX_GNUX32_LINK: Link = Link {
     search: cow!(ELF_X86_64_MULTILIBX32),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: crate::properties::link::NxStackMode::GnuStack,
-    dynlinker_name: Some(cowstr!("/lib/ld-linux-x32.so.2")),
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_CET_HARDENING),
+    dynlinker_name: Some(cowstr!("/libx32/ld-linux-x32.so.2")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
     default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
 };
 
 /// Linking for i*86-linux-gnu
@@ -41,6 +168,350 @@ pub static X86_32LINUX_GNU_LINK: Link = Link {
     search: cow!(ELF_X86_32_MULTILIB),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_CET_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld-linux.so.2")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for arm-linux-gnueabi (softfloat)
+pub static ARM_LINUX_GNUEABI_LINK: Link = Link {
+    formats: cow!(ELF_ARM),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld-linux.so.3")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for arm-linux-gnueabihf (hardfloat)
+pub static ARM_LINUX_GNUEABIHF_LINK: Link = Link {
+    formats: cow!(ELF_ARM),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld-linux-armhf.so.3")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for armeb-linux-gnueabi (softfloat)
+pub static ARMEB_LINUX_GNUEABI_LINK: Link = Link {
+    formats: cow!(ELF_ARMEB),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld-linux.so.3")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for armeb-linux-gnueabihf (hardfloat)
+pub static ARMEB_LINUX_GNUEABIHF_LINK: Link = Link {
+    formats: cow!(ELF_ARMEB),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld-linux-armhf.so.3")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for riscv64-linux-gnu (lp64d)
+pub static RISCV64_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_RISCV64),
+    search: cow!(ELF_RISCV64_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_RISCV_MEDANY),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld-linux-riscv64-lp64d.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for riscv32-linux-gnu (ilp32d)
+pub static RISCV32_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_RISCV32),
+    search: cow!(ELF_RISCV32_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_RISCV),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld-linux-riscv32-ilp32d.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for powerpc-linux-gnu
+pub static PPC32_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_PPC32),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for powerpc64-linux-gnu (ELFv1)
+pub static PPC64_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_PPC64),
+    search: cow!(ELF_PPC64_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_PPC64),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib64/ld64.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for powerpc64le-linux-gnu (ELFv2)
+pub static PPC64LE_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_PPC64LE),
+    search: cow!(ELF_PPC64_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_PPC64),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib64/ld64.so.2")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for mips-linux-gnu (o32)
+pub static MIPS_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_MIPS),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for mipsel-linux-gnu (o32)
+pub static MIPSEL_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_MIPSEL),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for mips64-linux-gnuabin32 (n32)
+pub static MIPS64_LINUX_GNUABIN32_LINK: Link = Link {
+    formats: cow!(ELF_MIPS_N32),
+    search: cow!(ELF_MIPS_N32_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib32/ld.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for mips64el-linux-gnuabin32 (n32)
+pub static MIPS64EL_LINUX_GNUABIN32_LINK: Link = Link {
+    formats: cow!(ELF_MIPSEL_N32),
+    search: cow!(ELF_MIPS_N32_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib32/ld.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for mips64-linux-gnuabi64 (n64)
+pub static MIPS64_LINUX_GNUABI64_LINK: Link = Link {
+    formats: cow!(ELF_MIPS64),
+    search: cow!(ELF_MIPS_N64_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib64/ld.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for mips64el-linux-gnuabi64 (n64)
+pub static MIPS64EL_LINUX_GNUABI64_LINK: Link = Link {
+    formats: cow!(ELF_MIPS64EL),
+    search: cow!(ELF_MIPS_N64_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib64/ld.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for m68k-linux-gnu
+pub static M68K_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_M68K),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib/ld.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for sparc-linux-gnu
+pub static SPARC_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_SPARC),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
     dynlinker_name: Some(cowstr!("/lib/ld-linux.so.2")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for sparc64-linux-gnu
+pub static SPARCV9_LINUX_GNU_LINK: Link = Link {
+    formats: cow!(ELF_SPARCV9),
+    search: cow!(ELF_SPARCV9_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(LINUX_GNU_HARDENING),
+    dynlinker_name: Some(cowstr!("/lib64/ld-linux.so.2")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
     default_libraries: Some(cow!(LINUX_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for x86_64-linux-musl
+pub static X86_64_LINUX_MUSL_LINK: Link = Link {
+    formats: cow!(ELF_X86_64_MUSL),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/lib/ld-musl-x86_64.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(MUSL_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for i*86-linux-musl
+pub static X86_32_LINUX_MUSL_LINK: Link = Link {
+    formats: cow!(ELF_X86_32_MUSL),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/lib/ld-musl-i386.so.1")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(MUSL_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for x86_64-linux-android
+pub static X86_64_LINUX_ANDROID_LINK: Link = Link {
+    formats: cow!(ELF_X86_64),
+    search: cow!(SEARCH_ANDROID64),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/system/bin/linker64")),
+    dylib_policy: cow!(ANDROID_DYLIB_POLICY),
+    default_libraries: Some(cow!(BIONIC_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for i686-linux-android
+pub static X86_32_LINUX_ANDROID_LINK: Link = Link {
+    formats: cow!(ELF_X86_32),
+    search: cow!(SEARCH_ANDROID),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/system/bin/linker")),
+    dylib_policy: cow!(ANDROID_DYLIB_POLICY),
+    default_libraries: Some(cow!(BIONIC_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for x86_64-linux-uclibc.
+///
+/// `target_tuples` does not have a uClibc environment, so this is not returned by [`crate::builtin::link::from_target`]
+pub static X86_64_LINUX_UCLIBC_LINK: Link = Link {
+    formats: cow!(ELF_X86_64),
+    search: cow!(ELF_X86_64_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/lib/ld64-uClibc.so.0")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(UCLIBC_FILENAMES)),
+    memory_layout: None,
+};
+
+/// Linking for i*86-linux-uclibc.
+///
+/// `target_tuples` does not have a uClibc environment, so this is not returned by [`crate::builtin::link::from_target`]
+pub static X86_32_LINUX_UCLIBC_LINK: Link = Link {
+    formats: cow!(ELF_X86_32),
+    search: cow!(ELF_X86_32_MULTILIB),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: crate::properties::link::NxStackMode::GnuStack,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: Some(cowstr!("/lib/ld-uClibc.so.0")),
+    dylib_policy: cow!(DYLIB_POLICY_ELF),
+    default_libraries: Some(cow!(UCLIBC_FILENAMES)),
+    memory_layout: None,
 };
@@ -0,0 +1,157 @@
+//! 6502 and derivatives
+
+use crate::{
+    helpers::CowSlice,
+    properties::{
+        binfmt,
+        link::{
+            ArchiveFormat, CODE_MODELS_SMALL, DYLIB_POLICY_NONE, FILENAMES_ELF, HARDENING_NONE,
+            Link, LinkFormat, LinkerFlavour, MemoryLayout, MemoryRegion, NxStackMode,
+            RELOC_MODELS_STATIC, RegionAttributes, SEARCH_UNIX_DEFAULT, SectionPlacement,
+            StaticLibraryFormat, SupportedArtifacts,
+        },
+    },
+};
+
+const ZP: MemoryRegion = MemoryRegion {
+    name: cowstr!("zp"),
+    attributes: RegionAttributes::READ.union(RegionAttributes::WRITE),
+    origin: 0x0000,
+    length: 0x0100,
+};
+
+const NES_RAM: MemoryRegion = MemoryRegion {
+    name: cowstr!("ram"),
+    attributes: RegionAttributes::READ.union(RegionAttributes::WRITE),
+    origin: 0x0200,
+    length: 0x0600,
+};
+
+const VECTORS: MemoryRegion = MemoryRegion {
+    name: cowstr!("vectors"),
+    attributes: RegionAttributes::READ,
+    origin: 0xFFFA,
+    length: 0x0006,
+};
+
+const PRG_ATTRIBUTES: RegionAttributes = RegionAttributes::READ.union(RegionAttributes::EXEC);
+
+/// Section placement shared by cartridge maps.
+///
+/// Initialized data is loaded from `prg` and copied to `ram` by the startup code,
+///  and the interrupt vectors (NMI, RESET, IRQ) are placed in the `vectors` region at the top of the address space
+const CARTRIDGE_SECTIONS: CowSlice<SectionPlacement> = slice![
+    SectionPlacement {
+        name: cowstr!(".zp"),
+        inputs: slice![cowstr!(".zp"), cowstr!(".zp.*")],
+        region: Some(cowstr!("zp")),
+        noload: true,
+        ..SectionPlacement::DEFAULT
+    },
+    SectionPlacement {
+        name: cowstr!(".text"),
+        inputs: slice![cowstr!(".text"), cowstr!(".text.*")],
+        region: Some(cowstr!("prg")),
+        ..SectionPlacement::DEFAULT
+    },
+    SectionPlacement {
+        name: cowstr!(".rodata"),
+        inputs: slice![cowstr!(".rodata"), cowstr!(".rodata.*")],
+        region: Some(cowstr!("prg")),
+        ..SectionPlacement::DEFAULT
+    },
+    SectionPlacement {
+        name: cowstr!(".data"),
+        inputs: slice![cowstr!(".data"), cowstr!(".data.*")],
+        region: Some(cowstr!("ram")),
+        load_region: Some(cowstr!("prg")),
+        ..SectionPlacement::DEFAULT
+    },
+    SectionPlacement {
+        name: cowstr!(".bss"),
+        inputs: slice![cowstr!(".bss"), cowstr!(".bss.*"), cowstr!("COMMON")],
+        region: Some(cowstr!("ram")),
+        noload: true,
+        ..SectionPlacement::DEFAULT
+    },
+    SectionPlacement {
+        name: cowstr!(".vectors"),
+        inputs: slice![cowstr!(".vectors")],
+        keep: true,
+        region: Some(cowstr!("vectors")),
+        ..SectionPlacement::DEFAULT
+    },
+];
+
+/// Memory layout for an NES NROM-256 cartridge (32KiB of PRG ROM mapped at `$8000`)
+pub static NES_NROM_256_LAYOUT: MemoryLayout = MemoryLayout {
+    entry: Some(cowstr!("_start")),
+    regions: slice![
+        ZP,
+        NES_RAM,
+        MemoryRegion {
+            name: cowstr!("prg"),
+            attributes: PRG_ATTRIBUTES,
+            origin: 0x8000,
+            length: 0x7FFA,
+        },
+        VECTORS,
+    ],
+    sections: CARTRIDGE_SECTIONS,
+};
+
+/// Memory layout for an NES NROM-128 cartridge (16KiB of PRG ROM mapped at `$C000`, mirrored at `$8000`)
+pub static NES_NROM_128_LAYOUT: MemoryLayout = MemoryLayout {
+    entry: Some(cowstr!("_start")),
+    regions: slice![
+        ZP,
+        NES_RAM,
+        MemoryRegion {
+            name: cowstr!("prg"),
+            attributes: PRG_ATTRIBUTES,
+            origin: 0xC000,
+            length: 0x3FFA,
+        },
+        VECTORS,
+    ],
+    sections: CARTRIDGE_SECTIONS,
+};
+
+/// Elf format for the 6502 (`elf32-mos`). Only static, non-position independent executables are supported
+pub static ELF_MOS: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_MOS,
+    exec_binfmt: binfmt::ELF32_MOS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::EXE,
+    reloc_models: cow!(RELOC_MODELS_STATIC),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Linking for an NES NROM-256 cartridge. This is the default for `6502-unknown-nes`
+pub static NES_NROM_256_LINK: Link = Link {
+    formats: cow!(ELF_MOS),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: Some(cow!(NES_NROM_256_LAYOUT)),
+};
+
+/// Linking for an NES NROM-128 cartridge
+pub static NES_NROM_128_LINK: Link = Link {
+    formats: cow!(ELF_MOS),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: Some(cow!(NES_NROM_128_LAYOUT)),
+};
@@ -0,0 +1,46 @@
+//! linking properties common to m68k targets
+
+use crate::properties::{
+    binfmt,
+    link::{
+        ArchiveFormat, CODE_MODELS_SMALL, DYLIB_POLICY_NONE, FILENAMES_ELF, HARDENING_NONE, Link,
+        LinkFormat, LinkerFlavour, NxStackMode, RELOC_MODELS_DEFAULT_PIE,
+        RELOC_MODELS_NO_DEFAULT_PIE, SEARCH_UNIX_DEFAULT, StaticLibraryFormat, SupportedArtifacts,
+    },
+};
+
+/// Elf format for m68k
+pub static ELF_M68K: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_M68K,
+    exec_binfmt: binfmt::ELF32_M68K,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding m68k
+pub static ELF_M68K_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_M68K,
+    exec_binfmt: binfmt::ELF32_M68K,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Linking behaviour for Freestanding m68k
+pub static ELF_M68K_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_M68K_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
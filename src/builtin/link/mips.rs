@@ -0,0 +1,188 @@
+//! linking properties common to MIPS targets
+
+use crate::properties::{
+    binfmt,
+    link::{
+        ArchiveFormat, CODE_MODELS_SMALL, DYLIB_POLICY_NONE, FILENAMES_ELF, HARDENING_NONE,
+        LibrarySearch, Link, LinkFormat, LinkerFlavour, NxStackMode, RELOC_MODELS_DEFAULT_PIE,
+        RELOC_MODELS_NO_DEFAULT_PIE, SEARCH_UNIX_DEFAULT, StaticLibraryFormat, SupportedArtifacts,
+    },
+};
+
+/// Elf format for big-endian MIPS o32
+pub static ELF_MIPS: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_TRADBIGMIPS,
+    exec_binfmt: binfmt::ELF32_TRADBIGMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for little-endian MIPS o32
+pub static ELF_MIPSEL: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_TRADLITTLEMIPS,
+    exec_binfmt: binfmt::ELF32_TRADLITTLEMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for big-endian MIPS n32
+pub static ELF_MIPS_N32: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_NTRADBIGMIPS,
+    exec_binfmt: binfmt::ELF32_NTRADBIGMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for little-endian MIPS n32
+pub static ELF_MIPSEL_N32: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_NTRADLITTLEMIPS,
+    exec_binfmt: binfmt::ELF32_NTRADLITTLEMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for big-endian MIPS n64
+pub static ELF_MIPS64: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_TRADBIGMIPS,
+    exec_binfmt: binfmt::ELF64_TRADBIGMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for little-endian MIPS n64
+pub static ELF_MIPS64EL: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_TRADLITTLEMIPS,
+    exec_binfmt: binfmt::ELF64_TRADLITTLEMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Library Search for MIPS n32 with gnu multilib
+pub static ELF_MIPS_N32_MULTILIB: LibrarySearch = LibrarySearch {
+    search_dirs: slice![cowstr!("lib32"), cowstr!("lib")],
+    ..SEARCH_UNIX_DEFAULT
+};
+
+/// Library Search for MIPS n64 with gnu multilib
+pub static ELF_MIPS_N64_MULTILIB: LibrarySearch = LibrarySearch {
+    search_dirs: slice![cowstr!("lib64"), cowstr!("lib")],
+    ..SEARCH_UNIX_DEFAULT
+};
+
+/// Elf format for Freestanding big-endian MIPS o32
+pub static ELF_MIPS_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_TRADBIGMIPS,
+    exec_binfmt: binfmt::ELF32_TRADBIGMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding little-endian MIPS o32
+pub static ELF_MIPSEL_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_TRADLITTLEMIPS,
+    exec_binfmt: binfmt::ELF32_TRADLITTLEMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding big-endian MIPS n64
+pub static ELF_MIPS64_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_TRADBIGMIPS,
+    exec_binfmt: binfmt::ELF64_TRADBIGMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding little-endian MIPS n64
+pub static ELF_MIPS64EL_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_TRADLITTLEMIPS,
+    exec_binfmt: binfmt::ELF64_TRADLITTLEMIPS,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Linking behaviour for Freestanding big-endian MIPS o32
+pub static ELF_MIPS_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_MIPS_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking behaviour for Freestanding little-endian MIPS o32
+pub static ELF_MIPSEL_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_MIPSEL_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking behaviour for Freestanding big-endian MIPS n64
+pub static ELF_MIPS64_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_MIPS64_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking behaviour for Freestanding little-endian MIPS n64
+pub static ELF_MIPS64EL_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_MIPS64EL_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
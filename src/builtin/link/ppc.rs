@@ -0,0 +1,131 @@
+//! linking properties common to PowerPC targets
+
+use crate::properties::{
+    binfmt,
+    link::{
+        ArchiveFormat, CODE_MODELS_SMALL, CodeModel, CodeModels, DYLIB_POLICY_NONE, FILENAMES_ELF,
+        HARDENING_NONE, LibrarySearch, Link, LinkFormat, LinkerFlavour, NxStackMode,
+        RELOC_MODELS_DEFAULT_PIE, RELOC_MODELS_NO_DEFAULT_PIE, SEARCH_UNIX_DEFAULT,
+        StaticLibraryFormat, SupportedArtifacts,
+    },
+};
+
+/// Elf format for 32-bit PowerPC
+pub static ELF_PPC32: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_POWERPC,
+    exec_binfmt: binfmt::ELF32_POWERPC,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for big-endian 64-bit PowerPC
+pub static ELF_PPC64: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_POWERPC,
+    exec_binfmt: binfmt::ELF64_POWERPC,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for little-endian 64-bit PowerPC
+pub static ELF_PPC64LE: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_POWERPCLE,
+    exec_binfmt: binfmt::ELF64_POWERPCLE,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Library Search for 64-bit PowerPC with gnu multilib
+pub static ELF_PPC64_MULTILIB: LibrarySearch = LibrarySearch {
+    search_dirs: slice![cowstr!("lib"), cowstr!("lib64")],
+    ..SEARCH_UNIX_DEFAULT
+};
+
+/// Elf format for Freestanding 32-bit PowerPC
+pub static ELF_PPC32_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_POWERPC,
+    exec_binfmt: binfmt::ELF32_POWERPC,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding big-endian 64-bit PowerPC
+pub static ELF_PPC64_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_POWERPC,
+    exec_binfmt: binfmt::ELF64_POWERPC,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding little-endian 64-bit PowerPC
+pub static ELF_PPC64LE_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_POWERPCLE,
+    exec_binfmt: binfmt::ELF64_POWERPCLE,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Code models for 64-bit PowerPC, defaulting to the medium code model (TOC-relative data within 2GiB)
+pub static CODE_MODELS_PPC64: CodeModels = CodeModels {
+    supported: slice![CodeModel::Small, CodeModel::Medium, CodeModel::Large],
+    default: CodeModel::Medium,
+};
+
+/// Linking behaviour for Freestanding 32-bit PowerPC
+pub static ELF_PPC32_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_PPC32_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking behaviour for Freestanding big-endian 64-bit PowerPC
+pub static ELF_PPC64_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_PPC64_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_PPC64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking behaviour for Freestanding little-endian 64-bit PowerPC
+pub static ELF_PPC64LE_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_PPC64LE_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_PPC64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
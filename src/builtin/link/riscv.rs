@@ -0,0 +1,106 @@
+//! linking properties common to RISC-V targets
+
+use crate::properties::{
+    binfmt,
+    link::{
+        ArchiveFormat, CodeModel, CodeModels, DYLIB_POLICY_NONE, FILENAMES_ELF, HARDENING_NONE,
+        LibrarySearch, Link, LinkFormat, LinkerFlavour, NxStackMode, RELOC_MODELS_DEFAULT_PIE,
+        RELOC_MODELS_NO_DEFAULT_PIE, SEARCH_UNIX_DEFAULT, StaticLibraryFormat, SupportedArtifacts,
+    },
+};
+
+/// Elf format for RV64
+pub static ELF_RISCV64: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_LITTLERISCV,
+    exec_binfmt: binfmt::ELF64_LITTLERISCV,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for RV32
+pub static ELF_RISCV32: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_LITTLERISCV,
+    exec_binfmt: binfmt::ELF32_LITTLERISCV,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Library Search for RV64 with the lp64d multilib layout
+pub static ELF_RISCV64_MULTILIB: LibrarySearch = LibrarySearch {
+    search_dirs: slice![cowstr!("lib"), cowstr!("lib64/lp64d"), cowstr!("lib64")],
+    ..SEARCH_UNIX_DEFAULT
+};
+
+/// Library Search for RV32 with the ilp32d multilib layout
+pub static ELF_RISCV32_MULTILIB: LibrarySearch = LibrarySearch {
+    search_dirs: slice![cowstr!("lib"), cowstr!("lib32/ilp32d"), cowstr!("lib32")],
+    ..SEARCH_UNIX_DEFAULT
+};
+
+/// Elf format for Freestanding RV64
+pub static ELF_RISCV64_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_LITTLERISCV,
+    exec_binfmt: binfmt::ELF64_LITTLERISCV,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding RV32
+pub static ELF_RISCV32_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_LITTLERISCV,
+    exec_binfmt: binfmt::ELF32_LITTLERISCV,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Code models for RISC-V (`medlow` and `medany`), defaulting to `medlow`
+pub const CODE_MODELS_RISCV: CodeModels = CodeModels {
+    supported: slice![CodeModel::Small, CodeModel::Medium],
+    default: CodeModel::Small,
+};
+
+/// Code models for RISC-V, defaulting to `medany`. This is the default on 64-bit Linux
+pub static CODE_MODELS_RISCV_MEDANY: CodeModels = CodeModels {
+    default: CodeModel::Medium,
+    ..CODE_MODELS_RISCV
+};
+
+/// Linking behaviour for Freestanding RV64
+pub static ELF_RISCV64_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_RISCV64_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_RISCV),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking behaviour for Freestanding RV32
+pub static ELF_RISCV32_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_RISCV32_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_RISCV),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
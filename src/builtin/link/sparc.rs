@@ -0,0 +1,113 @@
+//! linking properties common to SPARC targets
+
+use crate::properties::{
+    binfmt,
+    link::{
+        ArchiveFormat, CODE_MODELS_SMALL, DYLIB_POLICY_NONE, FILENAMES_ELF, HARDENING_NONE,
+        LibrarySearch, Link, LinkFormat, LinkerFlavour, NxStackMode, RELOC_MODELS_DEFAULT_PIE,
+        RELOC_MODELS_NO_DEFAULT_PIE, SEARCH_UNIX_DEFAULT, StaticLibraryFormat, SupportedArtifacts,
+    },
+};
+
+/// Elf format for 32-bit SPARC
+pub static ELF_SPARC: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_SPARC,
+    exec_binfmt: binfmt::ELF32_SPARC,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for 64-bit SPARC
+pub static ELF_SPARCV9: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_SPARC,
+    exec_binfmt: binfmt::ELF64_SPARC,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Library Search for 64-bit SPARC with gnu multilib
+pub static ELF_SPARCV9_MULTILIB: LibrarySearch = LibrarySearch {
+    search_dirs: slice![cowstr!("lib64"), cowstr!("lib")],
+    ..SEARCH_UNIX_DEFAULT
+};
+
+/// Elf format for Freestanding 32-bit SPARC
+pub static ELF_SPARC_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_SPARC,
+    exec_binfmt: binfmt::ELF32_SPARC,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding little-endian 32-bit SPARC (LEON)
+pub static ELF_SPARCEL_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_SPARCEL,
+    exec_binfmt: binfmt::ELF32_SPARCEL,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Elf format for Freestanding 64-bit SPARC
+pub static ELF_SPARCV9_FREESTANDING: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_SPARC,
+    exec_binfmt: binfmt::ELF64_SPARC,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Linking behaviour for Freestanding 32-bit SPARC
+pub static ELF_SPARC_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_SPARC_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking behaviour for Freestanding little-endian 32-bit SPARC (LEON)
+pub static ELF_SPARCEL_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_SPARCEL_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking behaviour for Freestanding 64-bit SPARC
+pub static ELF_SPARCV9_FREESTANDING_LINK: Link = Link {
+    formats: cow!(ELF_SPARCV9_FREESTANDING),
+    search: cow!(SEARCH_UNIX_DEFAULT),
+    output_filename: cow!(FILENAMES_ELF),
+    nx_stack: NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
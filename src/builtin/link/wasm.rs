@@ -0,0 +1,108 @@
+//! linking properties for WebAssembly targets
+
+use crate::properties::{
+    binfmt,
+    link::{
+        ArchiveFormat, CODE_MODELS_SMALL, DYLIB_POLICY_NONE, DefaultLinking, FILENAMES_WASM,
+        HARDENING_NONE, LibrarySearch, Link, LinkFormat, LinkerFlavour, NxStackMode,
+        RELOC_MODELS_STATIC, SEARCH_UNIX_DEFAULT, StartupFiles, StaticLibraryFormat,
+        SupportedArtifacts,
+    },
+};
+
+/// Wasm format for wasm32
+pub static WASM32: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::WASM32,
+    exec_binfmt: binfmt::WASM32,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::EXE,
+    reloc_models: cow!(RELOC_MODELS_STATIC),
+    default_linker_format: LinkerFlavour::WasmLd,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Wasm format for wasm64
+pub static WASM64: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::WASM64,
+    exec_binfmt: binfmt::WASM64,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::EXE,
+    reloc_models: cow!(RELOC_MODELS_STATIC),
+    default_linker_format: LinkerFlavour::WasmLd,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Library search for WebAssembly. Only static libraries are searched
+pub static SEARCH_WASM: LibrarySearch = LibrarySearch {
+    base_dirs: slice![cowstr!("/")],
+    dylib_prefixes: slice![],
+    dylib_suffixes: slice![],
+    ..SEARCH_UNIX_DEFAULT
+};
+
+/// Library set for WASI (wasi-libc)
+pub static WASI_LIBRARIES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles {
+        static_exe: slice![cowstr!("crt1-command.o")],
+        ..StartupFiles::NONE
+    },
+    end_files: StartupFiles::NONE,
+    libraries: slice![cowstr!("c")],
+    static_libraries: None,
+};
+
+/// Linking for wasm32-wasi
+pub static WASM32_WASI_LINK: Link = Link {
+    formats: cow!(WASM32),
+    search: cow!(SEARCH_WASM),
+    output_filename: cow!(FILENAMES_WASM),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: Some(cow!(WASI_LIBRARIES)),
+    memory_layout: None,
+};
+
+/// Linking for wasm64-wasi
+pub static WASM64_WASI_LINK: Link = Link {
+    formats: cow!(WASM64),
+    search: cow!(SEARCH_WASM),
+    output_filename: cow!(FILENAMES_WASM),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: Some(cow!(WASI_LIBRARIES)),
+    memory_layout: None,
+};
+
+/// Linking for wasm32 with no OS
+pub static WASM32_UNKNOWN_LINK: Link = Link {
+    formats: cow!(WASM32),
+    search: cow!(SEARCH_WASM),
+    output_filename: cow!(FILENAMES_WASM),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
+
+/// Linking for wasm64 with no OS
+pub static WASM64_UNKNOWN_LINK: Link = Link {
+    formats: cow!(WASM64),
+    search: cow!(SEARCH_WASM),
+    output_filename: cow!(FILENAMES_WASM),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: None,
+    memory_layout: None,
+};
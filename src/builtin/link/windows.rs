@@ -0,0 +1,180 @@
+//! Windows, using either the MSVC or MinGW toolchain
+
+use crate::{
+    builtin::link::x86::CODE_MODELS_X86_64,
+    properties::{
+        binfmt,
+        link::{
+            ArchiveFormat, CODE_MODELS_SMALL, DYLIB_POLICY_NONE, DefaultLinking, FILENAMES_MINGW,
+            FILENAMES_PE, HARDENING_NONE, Link, LinkFormat, LinkerFlavour, NxStackMode, RelocModel,
+            RelocModels, SEARCH_MINGW, SEARCH_MSVC, StartupFiles, StaticLibraryFormat,
+            SupportedArtifacts,
+        },
+    },
+};
+
+/// Supported artifacts on PE targets.
+/// Images are relocated by the loader using base relocations rather than being position independent,
+///  but relocatable (`/DYNAMICBASE`) executables are treated as PIE and are the default
+const PE_ARTIFACTS: SupportedArtifacts = SupportedArtifacts::EXE
+    .union(SupportedArtifacts::DYLIB)
+    .union(SupportedArtifacts::PIE)
+    .union(SupportedArtifacts::DEFAULT_PIE)
+    .union(SupportedArtifacts::DEFAULT_DYNAMIC);
+
+/// Relocation models for PE targets.
+/// Code is not position independent: relocatable images are rebased by the loader using base relocations instead
+pub static PE_RELOC_MODELS: RelocModels = RelocModels {
+    supported: slice![RelocModel::Static],
+    default: RelocModel::Static,
+};
+
+/// PE/COFF format for x86-64 with the MSVC toolchain
+pub static PE_X86_64_MSVC: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::PE_X86_64,
+    exec_binfmt: binfmt::PEI_X86_64,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysVWin),
+    supported_artifacts: PE_ARTIFACTS,
+    reloc_models: cow!(PE_RELOC_MODELS),
+    default_linker_format: LinkerFlavour::Link,
+    symbol_prefix: cowstr!(""),
+};
+
+/// PE/COFF format for x86-32 with the MSVC toolchain
+pub static PE_X86_32_MSVC: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::PE_I386,
+    exec_binfmt: binfmt::PEI_I386,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysVWin),
+    supported_artifacts: PE_ARTIFACTS,
+    reloc_models: cow!(PE_RELOC_MODELS),
+    default_linker_format: LinkerFlavour::Link,
+    symbol_prefix: cowstr!("_"),
+};
+
+/// PE/COFF format for x86-64 with the MinGW toolchain
+pub static PE_X86_64_MINGW: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::PE_X86_64,
+    exec_binfmt: binfmt::PEI_X86_64,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: PE_ARTIFACTS,
+    reloc_models: cow!(PE_RELOC_MODELS),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// PE/COFF format for x86-32 with the MinGW toolchain
+pub static PE_X86_32_MINGW: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::PE_I386,
+    exec_binfmt: binfmt::PEI_I386,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: PE_ARTIFACTS,
+    reloc_models: cow!(PE_RELOC_MODELS),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!("_"),
+};
+
+/// Library set for the MSVC toolchain.
+/// Uses the dynamically linked CRT (`/MD`) by default, and the statically linked CRT (`/MT`) for static executables
+pub static MSVC_LIBRARIES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles::NONE,
+    end_files: StartupFiles::NONE,
+    libraries: slice![
+        cowstr!("msvcrt"),
+        cowstr!("vcruntime"),
+        cowstr!("ucrt"),
+        cowstr!("kernel32")
+    ],
+    static_libraries: Some(slice![
+        cowstr!("libcmt"),
+        cowstr!("libvcruntime"),
+        cowstr!("libucrt"),
+        cowstr!("kernel32")
+    ]),
+};
+
+/// Library set for the MinGW toolchain
+pub static MINGW_LIBRARIES: DefaultLinking = DefaultLinking {
+    start_files: StartupFiles {
+        exe: slice![cowstr!("crt2.o"), cowstr!("crtbegin.o")],
+        pie: slice![cowstr!("crt2.o"), cowstr!("crtbegin.o")],
+        static_exe: slice![cowstr!("crt2.o"), cowstr!("crtbegin.o")],
+        static_pie: slice![cowstr!("crt2.o"), cowstr!("crtbegin.o")],
+        dylib: slice![cowstr!("dllcrt2.o"), cowstr!("crtbegin.o")],
+    },
+    end_files: StartupFiles {
+        exe: slice![cowstr!("crtend.o")],
+        pie: slice![cowstr!("crtend.o")],
+        static_exe: slice![cowstr!("crtend.o")],
+        static_pie: slice![cowstr!("crtend.o")],
+        dylib: slice![cowstr!("crtend.o")],
+    },
+    libraries: slice![
+        cowstr!("mingw32"),
+        cowstr!("gcc"),
+        cowstr!("gcc_eh"),
+        cowstr!("moldname"),
+        cowstr!("mingwex"),
+        cowstr!("msvcrt"),
+        cowstr!("advapi32"),
+        cowstr!("shell32"),
+        cowstr!("user32"),
+        cowstr!("kernel32")
+    ],
+    static_libraries: None,
+};
+
+/// Linking for x86_64-pc-windows-msvc
+pub static X86_64_WINDOWS_MSVC_LINK: Link = Link {
+    formats: cow!(PE_X86_64_MSVC),
+    search: cow!(SEARCH_MSVC),
+    output_filename: cow!(FILENAMES_PE),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: Some(cow!(MSVC_LIBRARIES)),
+    memory_layout: None,
+};
+
+/// Linking for i686-pc-windows-msvc
+pub static X86_32_WINDOWS_MSVC_LINK: Link = Link {
+    formats: cow!(PE_X86_32_MSVC),
+    search: cow!(SEARCH_MSVC),
+    output_filename: cow!(FILENAMES_PE),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: Some(cow!(MSVC_LIBRARIES)),
+    memory_layout: None,
+};
+
+/// Linking for x86_64-pc-windows-gnu
+pub static X86_64_WINDOWS_GNU_LINK: Link = Link {
+    formats: cow!(PE_X86_64_MINGW),
+    search: cow!(SEARCH_MINGW),
+    output_filename: cow!(FILENAMES_MINGW),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: Some(cow!(MINGW_LIBRARIES)),
+    memory_layout: None,
+};
+
+/// Linking for i686-pc-windows-gnu
+pub static X86_32_WINDOWS_GNU_LINK: Link = Link {
+    formats: cow!(PE_X86_32_MINGW),
+    search: cow!(SEARCH_MINGW),
+    output_filename: cow!(FILENAMES_MINGW),
+    nx_stack: NxStackMode::Default,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
+    dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
+    default_libraries: Some(cow!(MINGW_LIBRARIES)),
+    memory_layout: None,
+};
@@ -1,35 +1,67 @@
 //! linking properties common to x86 targets
 
-use crate::properties::link::{
-    ArchiveFormat, FILENAMES_ELF, LibrarySearch, Link, LinkFormat, LinkerFlavour,
-    SEARCH_UNIX_DEFAULT, StaticLibraryFormat, SupportedArtifacts,
+use crate::properties::{
+    binfmt,
+    link::{
+        ArchiveFormat, CODE_MODELS_SMALL, CodeModel, CodeModels, DYLIB_POLICY_NONE, FILENAMES_ELF,
+        HARDENING_NONE, LibrarySearch, Link, LinkFormat, LinkerFlavour, RELOC_MODELS_DEFAULT_PIE,
+        RELOC_MODELS_NO_DEFAULT_PIE, SEARCH_UNIX_DEFAULT, StaticLibraryFormat, SupportedArtifacts,
+    },
 };
 
 /// Linking for Elf x86-64
 pub static ELF_X86_64: LinkFormat = LinkFormat {
-    object_binfmt: cowstr!("elf64-x86_64"),
-    exec_binfmt: cowstr!("elf64-x86_64"),
+    object_binfmt: binfmt::ELF64_X86_64,
+    exec_binfmt: binfmt::ELF64_X86_64,
     staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
     supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
     default_linker_format: crate::properties::link::LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
 };
 
 /// Linking for Elf x86-64 x32
 pub static ELF_X86_64_X32: LinkFormat = LinkFormat {
-    object_binfmt: cowstr!("elf32-x86_64"),
-    exec_binfmt: cowstr!("elf32-x86_64"),
+    object_binfmt: binfmt::ELF32_X86_64,
+    exec_binfmt: binfmt::ELF32_X86_64,
     staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
     supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
     default_linker_format: crate::properties::link::LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
 };
 
 /// Linking for Elf x86-32
 pub static ELF_X86_32: LinkFormat = LinkFormat {
-    object_binfmt: cowstr!("elf32-x86"),
-    exec_binfmt: cowstr!("elf32-x86"),
+    object_binfmt: binfmt::ELF32_X86,
+    exec_binfmt: binfmt::ELF32_X86,
     staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
     supported_artifacts: SupportedArtifacts::all(),
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
     default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Linking for Elf x86-64 with musl, which produces static-pie executables by default
+pub static ELF_X86_64_MUSL: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF64_X86_64,
+    exec_binfmt: binfmt::ELF64_X86_64,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::DEFAULT_STATIC_PIE,
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Linking for Elf x86-32 with musl, which produces static-pie executables by default
+pub static ELF_X86_32_MUSL: LinkFormat = LinkFormat {
+    object_binfmt: binfmt::ELF32_X86,
+    exec_binfmt: binfmt::ELF32_X86,
+    staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
+    supported_artifacts: SupportedArtifacts::DEFAULT_STATIC_PIE,
+    reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+    default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
 };
 
 /// Library Search for x86_64 with gnu multilib
@@ -52,20 +84,35 @@ pub static ELF_X86_32_MULTILIB: LibrarySearch = LibrarySearch {
 
 /// Elf Format for Freestanding x86-64
 pub static ELF_X86_64_FREESTANDING: LinkFormat = LinkFormat {
-    object_binfmt: cowstr!("elf64-x86_64"),
-    exec_binfmt: cowstr!("elf64-x86_64"),
+    object_binfmt: binfmt::ELF64_X86_64,
+    exec_binfmt: binfmt::ELF64_X86_64,
     staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
     supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
     default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
 };
 
 /// Elf Format for Freestanding x86-32
 pub static ELF_X86_32_FREESTANDING: LinkFormat = LinkFormat {
-    object_binfmt: cowstr!("elf32-x86"),
-    exec_binfmt: cowstr!("elf32-x86"),
+    object_binfmt: binfmt::ELF32_X86,
+    exec_binfmt: binfmt::ELF32_X86,
     staticlib_format: StaticLibraryFormat::Archive(ArchiveFormat::SysV),
     supported_artifacts: SupportedArtifacts::NO_DEFAULT_PIE,
+    reloc_models: cow!(RELOC_MODELS_NO_DEFAULT_PIE),
     default_linker_format: LinkerFlavour::Ld,
+    symbol_prefix: cowstr!(""),
+};
+
+/// Code models for x86-64. The tiny code model is not supported
+pub const CODE_MODELS_X86_64: CodeModels = CodeModels {
+    supported: slice![
+        CodeModel::Small,
+        CodeModel::Kernel,
+        CodeModel::Medium,
+        CodeModel::Large
+    ],
+    default: CodeModel::Small,
 };
 
 /// Linking behaviour for Freestanding x86-64
@@ -74,8 +121,12 @@ pub static ELF_X86_64_FREESTANDING_LINK: Link = Link {
     search: cow!(SEARCH_UNIX_DEFAULT),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: crate::properties::link::NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_X86_64),
+    hardening: cow!(HARDENING_NONE),
     dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
     default_libraries: None,
+    memory_layout: None,
 };
 
 /// Linking behaviour for Freestanding x86-32
@@ -84,6 +135,10 @@ pub static ELF_X86_32_FREESTANDING_LINK: Link = Link {
     search: cow!(SEARCH_UNIX_DEFAULT),
     output_filename: cow!(FILENAMES_ELF),
     nx_stack: crate::properties::link::NxStackMode::Unsupported,
+    code_models: cow!(CODE_MODELS_SMALL),
+    hardening: cow!(HARDENING_NONE),
     dynlinker_name: None,
+    dylib_policy: cow!(DYLIB_POLICY_NONE),
     default_libraries: None,
+    memory_layout: None,
 };
@@ -20,14 +20,64 @@ pub mod windows;
 /// Lilium-like targets
 pub mod lilium;
 
-/// Obtains the [`Os`] properties from the target name
+/// WebAssembly System Interface
+pub mod wasi;
+
+/// AbleOS
+pub mod ableos;
+
+/// Darwin and macOS
+pub mod darwin;
+
+/// FreeBSD, NetBSD, and OpenBSD
+pub mod bsd;
+
+/// Fuchsia
+pub mod fuchsia;
+
+/// Obtains the [`Os`] properties from the target name.
+///
+/// Returns [`None`] if the OS is not supported
 pub const fn from_target(os: pieces::OS) -> Option<&'static Os> {
     match os {
         pieces::OS::Linux => Some(&linux::LINUX),
         pieces::OS::Win32 => Some(&windows::WINDOWS),
+        pieces::OS::Darwin | pieces::OS::MacOSX => Some(&darwin::MACOS),
+        pieces::OS::FreeBSD => Some(&bsd::FREEBSD),
+        pieces::OS::NetBSD => Some(&bsd::NETBSD),
+        pieces::OS::OpenBSD => Some(&bsd::OPENBSD),
+        pieces::OS::Fuchsia => Some(&fuchsia::FUCHSIA),
         pieces::OS::CleverOS => Some(&lilium::CLEVEROS),
         pieces::OS::Lilium => Some(&lilium::LILIUM),
-        pieces::OS::SNES | pieces::OS::NES | pieces::OS::None => Some(&OS_STANDALONE),
-        _ => todo!(),
+        pieces::OS::WASI => Some(&wasi::WASI),
+        pieces::OS::AbleOS => Some(&ableos::ABLEOS),
+        pieces::OS::SNES | pieces::OS::NES | pieces::OS::None | pieces::OS::Unknown => {
+            Some(&OS_STANDALONE)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsupported_os() {
+        for os in [pieces::OS::IOS, pieces::OS::AIX, pieces::OS::Solaris] {
+            assert_eq!(from_target(os), None);
+        }
+    }
+
+    #[test]
+    fn unsupported_os_target() {
+        for name in [
+            "aarch64-apple-ios",
+            "powerpc64-ibm-aix",
+            "sparcv9-sun-solaris",
+        ] {
+            let targ = target_tuples::TargetRef::parse(name);
+            assert_eq!(crate::builtin::target::from_target(&targ), None);
+        }
     }
 }
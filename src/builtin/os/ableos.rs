@@ -0,0 +1,10 @@
+use crate::properties::os::Os;
+
+/// AbleOS: https://git.ablecorp.us/AbleOS/ableos
+pub static ABLEOS: Os = Os {
+    name: cowstr!("ableos"),
+    family_names: slice![cowstr!("ableos")],
+    is_unix_like: false,
+    is_windows_like: false,
+    os_extended_properties: slice![],
+};
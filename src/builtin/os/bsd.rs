@@ -0,0 +1,28 @@
+use crate::properties::os::Os;
+
+/// FreeBSD
+pub static FREEBSD: Os = Os {
+    name: cowstr!("freebsd"),
+    family_names: slice![cowstr!("bsd")],
+    is_windows_like: false,
+    is_unix_like: true,
+    os_extended_properties: slice![],
+};
+
+/// NetBSD
+pub static NETBSD: Os = Os {
+    name: cowstr!("netbsd"),
+    family_names: slice![cowstr!("bsd")],
+    is_windows_like: false,
+    is_unix_like: true,
+    os_extended_properties: slice![],
+};
+
+/// OpenBSD
+pub static OPENBSD: Os = Os {
+    name: cowstr!("openbsd"),
+    family_names: slice![cowstr!("bsd")],
+    is_windows_like: false,
+    is_unix_like: true,
+    os_extended_properties: slice![],
+};
@@ -0,0 +1,14 @@
+use crate::properties::{ExtPropertyValue, os::Os};
+
+/// macOS (Darwin).
+/// Dynamic libraries are linked using the two-level namespace by default
+pub static MACOS: Os = Os {
+    name: cowstr!("macos"),
+    family_names: slice![cowstr!("darwin"), cowstr!("apple")],
+    is_windows_like: false,
+    is_unix_like: true,
+    os_extended_properties: slice![(
+        cowstr!("darwin.two-level-namespace"),
+        ExtPropertyValue::Bool(true)
+    )],
+};
@@ -0,0 +1,10 @@
+use crate::properties::os::Os;
+
+/// Fuchsia
+pub static FUCHSIA: Os = Os {
+    name: cowstr!("fuchsia"),
+    family_names: slice![cowstr!("fuchsia")],
+    is_windows_like: false,
+    is_unix_like: true,
+    os_extended_properties: slice![],
+};
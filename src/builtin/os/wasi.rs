@@ -0,0 +1,10 @@
+use crate::properties::os::Os;
+
+/// WebAssembly System Interface
+pub static WASI: Os = Os {
+    name: cowstr!("wasi"),
+    family_names: slice![cowstr!("wasi")],
+    is_unix_like: false,
+    is_windows_like: false,
+    os_extended_properties: slice![],
+};
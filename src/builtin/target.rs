@@ -12,22 +12,77 @@ pub const fn default_tag_for(
     arch: Architecture,
     os: OS,
     objfmt: Option<ObjectFormat>,
+) -> Option<&'static str> {
+    default_tag_for_env(arch, os, None, objfmt)
+}
+
+/// Determines the default (`extern "C"`) tag for the target, including tags that depend on the environment (such as the ARM float ABI)
+pub const fn default_tag_for_env(
+    arch: Architecture,
+    os: OS,
+    env: Option<Environment>,
+    objfmt: Option<ObjectFormat>,
 ) -> Option<&'static str> {
     match (arch, os, objfmt) {
         (
             Architecture::X86_64 { .. },
-            OS::Linux | OS::Lilium | OS::FreeBSD | OS::NetBSD | OS::OpenBSD | OS::MacOSX,
+            OS::Linux
+            | OS::Lilium
+            | OS::FreeBSD
+            | OS::NetBSD
+            | OS::OpenBSD
+            | OS::Fuchsia
+            | OS::Darwin
+            | OS::MacOSX,
             _,
         )
         | (Architecture::X86_64 { .. }, _, Some(ObjectFormat::Elf)) => Some("sysv64"),
         (Architecture::X86_64 { .. }, OS::Win32, _) => Some("win64"),
         (
             Architecture::X86_32(_),
-            OS::Linux | OS::Lilium | OS::FreeBSD | OS::NetBSD | OS::OpenBSD | OS::MacOSX,
+            OS::Linux
+            | OS::Lilium
+            | OS::FreeBSD
+            | OS::NetBSD
+            | OS::OpenBSD
+            | OS::Darwin
+            | OS::MacOSX,
             _,
         )
         | (Architecture::X86_32(_), _, Some(ObjectFormat::Elf)) => Some("cdecl-unix"),
         (Architecture::X86_32(_), OS::Win32, _) => Some("cdecl-ms"),
+        (Architecture::Aarch64, OS::Darwin | OS::MacOSX | OS::IOS, _) => Some("darwinpcs"),
+        (Architecture::Aarch64, OS::Win32, _) => Some("win64"),
+        (Architecture::Aarch64 | Architecture::Aarch64Be, _, _) => Some("aapcs64"),
+        (Architecture::Arm | Architecture::ArmBe, _, _) => {
+            match abi::abi_from_target(arch, os, env) {
+                Some(abi) if abi.float_pass_override.is_none() => Some("aapcs-vfp"),
+                _ => Some("aapcs"),
+            }
+        }
+        (Architecture::PowerPC32 | Architecture::PowerPC64 | Architecture::PowerPC64le, _, _) => {
+            archs::ppc::default_elf_abi(arch, env)
+        }
+        (Architecture::Wasm32 | Architecture::Wasm64, _, _) => Some("C"),
+        (Architecture::HoleyBytes, _, _) => Some("C"),
+        (Architecture::Sparc | Architecture::SparcEL | Architecture::SparcV9, _, _) => Some("C"),
+        (
+            Architecture::Mips
+            | Architecture::MipsLE
+            | Architecture::Mips64
+            | Architecture::Mips64LE,
+            _,
+            _,
+        ) => match archs::mips::MipsAbi::default_for(arch, env) {
+            Some(abi) => Some(abi.name()),
+            None => None,
+        },
+        (Architecture::RiscV32 | Architecture::RiscV64, _, _) => {
+            match archs::riscv::RiscvAbi::default_for(arch, os) {
+                Some(abi) => Some(abi.name()),
+                None => None,
+            }
+        }
         _ => None,
     }
 }
@@ -54,6 +109,62 @@ macro_rules! const_try_option {
     };
 }
 
+/// Target tuples for which [`from_target`] produces a complete target.
+///
+/// When several targets are equally good candidates (for example, when detecting the target of an object file), earlier entries are preferred
+pub static BUILTIN_TARGETS: &[&str] = &[
+    "x86_64-pc-linux-gnu",
+    "x86_64-pc-linux-gnux32",
+    "x86_64-pc-linux-musl",
+    "x86_64-linux-android",
+    "x86_64-pc-lilium-std",
+    "x86_64-pc-lilium-kernel",
+    "x86_64-unknown-freebsd",
+    "x86_64-unknown-netbsd",
+    "x86_64-unknown-openbsd",
+    "x86_64-unknown-fuchsia",
+    "x86_64-apple-darwin",
+    "x86_64-pc-windows-msvc",
+    "x86_64-pc-windows-gnu",
+    "i686-pc-linux-gnu",
+    "i686-pc-linux-musl",
+    "i686-linux-android",
+    "i686-pc-windows-msvc",
+    "i686-pc-windows-gnu",
+    "aarch64-apple-darwin",
+    "arm-linux-gnueabihf",
+    "arm-linux-gnueabi",
+    "arm-none-eabi",
+    "armeb-linux-gnueabihf",
+    "armeb-linux-gnueabi",
+    "armeb-none-eabi",
+    "riscv64-linux-gnu",
+    "riscv64-unknown-elf",
+    "riscv32-linux-gnu",
+    "riscv32-unknown-elf",
+    "mips-linux-gnu",
+    "mips-unknown-elf",
+    "mipsel-linux-gnu",
+    "mips64-linux-gnuabi64",
+    "mips64el-linux-gnuabi64",
+    "mips64-linux-gnuabin32",
+    "mips64el-linux-gnuabin32",
+    "powerpc-linux-gnu",
+    "powerpc-unknown-elf",
+    "powerpc64-linux-gnu",
+    "powerpc64-unknown-elf",
+    "powerpc64le-linux-gnu",
+    "powerpc64le-unknown-elf",
+    "sparc-linux-gnu",
+    "sparc-unknown-elf",
+    "sparcel-unknown-elf",
+    "sparc64-linux-gnu",
+    "sparcv9-unknown-elf",
+    "holeybytes-unknown-ableos",
+    "holeybytes-unknown-elf",
+    "wasm32-wasi",
+];
+
 /// Computers the properties of a specfied [`TargetRef`][target_tuples::TargetRef].
 pub fn from_target(targ: &target_tuples::TargetRef) -> Option<Target> {
     let sysname = targ.sys;
@@ -61,12 +172,17 @@ pub fn from_target(targ: &target_tuples::TargetRef) -> Option<Target> {
         Some(name) => name,
         None => OS::None,
     };
-    let arch = const_try_option!(archs::from_target(targ.arch));
+    let arch = const_try_option!(archs::from_target_env(targ.arch, os_name, sysname.env()));
     let os = const_try_option!(os::from_target(os_name));
+    let env = const_try_option!(env::from_target(os_name, sysname.env()));
     let link = const_try_option!(link::from_target(targ.arch, sysname,));
 
-    let default_tag =
-        const_try_option!(default_tag_for(targ.arch, os_name, sysname.object_format()));
+    let default_tag = const_try_option!(default_tag_for_env(
+        targ.arch,
+        os_name,
+        sysname.env(),
+        sysname.object_format()
+    ));
     let system_tag = match system_tag_for(targ.arch, os_name, sysname.object_format()) {
         Some(tag) => tag,
         None => default_tag,
@@ -78,14 +194,17 @@ pub fn from_target(targ: &target_tuples::TargetRef) -> Option<Target> {
         sysname.env()
     ));
     let abi = const_try_option!(abi::abi_from_target(targ.arch, os_name, sysname.env()));
+    let stack = const_try_option!(abi::stack_from_target(targ.arch, os_name, sysname.env()));
 
     let mut target = Target {
         arch: CowPtr::Borrowed(arch),
         os: CowPtr::Borrowed(os),
+        env: CowPtr::Borrowed(env),
         default_tag: CowPtr::Borrowed(default_tag),
         system_tag: CowPtr::Borrowed(system_tag),
         primitive_layout: CowPtr::Borrowed(primitive_layout),
         abi: CowPtr::Borrowed(abi),
+        stack: CowPtr::Borrowed(stack),
         link: CowPtr::Borrowed(link),
         override_features: slice![],
         extended_properties: slice![],
@@ -128,6 +247,28 @@ pub fn from_target(targ: &target_tuples::TargetRef) -> Option<Target> {
         (Architecture::X86_64 { .. }, OS::Lilium, Some(Environment::Kernel), _) => {
             target.override_features = slice![(cowstr!("xsave"), false)];
         }
+        (Architecture::X86_64 { .. }, OS::Darwin | OS::MacOSX, _, _) => {
+            target.extended_properties = slice![(
+                cowstr!("darwin.min-version"),
+                ExtPropertyValue::String(cowstr!("10.12"))
+            )];
+        }
+        (Architecture::Aarch64, OS::Darwin | OS::MacOSX, _, _) => {
+            target.override_features = slice![
+                (cowstr!("v8.5a"), true),
+                (cowstr!("fp16"), true),
+                (cowstr!("aes"), true),
+                (cowstr!("sha2"), true),
+                (cowstr!("sha3"), true),
+            ];
+            target.extended_properties = slice![(
+                cowstr!("darwin.min-version"),
+                ExtPropertyValue::String(cowstr!("11.0"))
+            )];
+        }
+        (Architecture::Wasm64, _, _, _) => {
+            target.override_features = slice![(cowstr!("memory64"), true)];
+        }
         _ => {}
     }
 
@@ -111,6 +111,12 @@ impl<'a, T: ?Sized + core::fmt::Debug> core::fmt::Debug for CowPtr<'a, T> {
     }
 }
 
+impl<'a, T: ?Sized + core::fmt::Display> core::fmt::Display for CowPtr<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
 impl<'a, T: ?Sized + PartialEq> PartialEq for CowPtr<'a, T> {
     fn eq(&self, other: &Self) -> bool {
         self.as_ref() == other.as_ref()
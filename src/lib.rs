@@ -50,3 +50,5 @@ pub mod helpers;
 pub mod properties;
 
 pub mod builtin;
+
+pub mod linker;
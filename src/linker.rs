@@ -0,0 +1,18 @@
+//! Utilities for driving the linker using the [`Link`][crate::properties::link::Link] properties of a target
+
+pub mod archive;
+
+pub mod command;
+
+pub mod detect;
+
+pub mod object;
+
+pub mod script;
+
+pub mod search;
+
+pub mod validate;
+
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,829 @@
+//! Reading and writing of static library archives in each [`ArchiveFormat`]
+//!
+//! Archives written by [`ArchiveWriter`] are deterministic: timestamps, owners, and group ids are always `0`, and the mode of each member is always `644`.
+//! The symbol index is not computed from the members, and must be supplied by the caller
+
+use std::io;
+
+use crate::properties::{abi::ByteOrder, link::ArchiveFormat};
+
+/// The magic string at the start of every archive
+pub const ARCHIVE_MAGIC: &[u8; 8] = b"!<arch>\n";
+
+const HEADER_SIZE: usize = 60;
+
+const HEADER_END: &[u8; 2] = b"`\n";
+
+/// An error produced when reading or writing an archive
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArchiveError {
+    /// The file does not start with [`ARCHIVE_MAGIC`]
+    BadMagic,
+    /// The member header at the given offset is malformed or truncated
+    BadHeader(usize),
+    /// The long name referred to by the member header at the given offset does not exist
+    BadLongName(usize),
+    /// The symbol index is malformed, or refers to an offset that is not a member
+    BadSymbolTable,
+    /// The member with the given name cannot be represented in the archive format (for example, it is too large)
+    MemberTooLarge(String),
+    /// The archive has more members or symbols than can be represented in its symbol index
+    TooManyMembers,
+}
+
+impl core::fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BadMagic => f.write_str("not an archive"),
+            Self::BadHeader(off) => {
+                f.write_fmt(format_args!("malformed member header at offset {off:#x}"))
+            }
+            Self::BadLongName(off) => f.write_fmt(format_args!(
+                "invalid long member name in header at offset {off:#x}"
+            )),
+            Self::BadSymbolTable => f.write_str("malformed archive symbol index"),
+            Self::MemberTooLarge(name) => {
+                f.write_fmt(format_args!("member {name} is too large for the archive"))
+            }
+            Self::TooManyMembers => {
+                f.write_str("too many members or symbols for the archive symbol index")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArchiveError {}
+
+/// A member of an archive that has been read
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ArchiveMember<'a> {
+    /// The name of the member, with any format-specific terminator removed
+    pub name: &'a str,
+    /// The contents of the member
+    pub data: &'a [u8],
+}
+
+/// An entry in the symbol index of an archive
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ArchiveSymbol<'a> {
+    /// The name of the symbol
+    pub name: &'a str,
+    /// The index into [`Archive::members`] of the member that defines the symbol
+    pub member: usize,
+}
+
+/// An archive read from memory
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Archive<'a> {
+    /// The format of the archive.
+    ///
+    /// If the archive has no symbol index and no long names, this is a best guess based on how the member names are terminated
+    pub format: ArchiveFormat,
+    /// The regular members of the archive, in order. The symbol index and long name table are not included
+    pub members: Vec<ArchiveMember<'a>>,
+    /// The symbol index of the archive, in the order it appears in the archive
+    pub symbols: Vec<ArchiveSymbol<'a>>,
+}
+
+struct RawHeader<'a> {
+    offset: usize,
+    name: &'a str,
+    data: &'a [u8],
+}
+
+fn parse_decimal(field: &[u8]) -> Option<usize> {
+    let field = core::str::from_utf8(field).ok()?.trim_end_matches(' ');
+    if field.is_empty() {
+        return Some(0);
+    }
+    field.parse().ok()
+}
+
+fn read_headers(data: &[u8]) -> Result<Vec<RawHeader<'_>>, ArchiveError> {
+    if !data.starts_with(ARCHIVE_MAGIC) {
+        return Err(ArchiveError::BadMagic);
+    }
+
+    let mut headers = Vec::new();
+    let mut pos = ARCHIVE_MAGIC.len();
+    while pos < data.len() {
+        let header = data
+            .get(pos..pos + HEADER_SIZE)
+            .ok_or(ArchiveError::BadHeader(pos))?;
+        if &header[58..] != HEADER_END {
+            return Err(ArchiveError::BadHeader(pos));
+        }
+        let name = core::str::from_utf8(&header[..16])
+            .map_err(|_| ArchiveError::BadHeader(pos))?
+            .trim_end_matches(' ');
+        let size = parse_decimal(&header[48..58]).ok_or(ArchiveError::BadHeader(pos))?;
+        let start = pos + HEADER_SIZE;
+        let member = data
+            .get(start..start + size)
+            .ok_or(ArchiveError::BadHeader(pos))?;
+        headers.push(RawHeader {
+            offset: pos,
+            name,
+            data: member,
+        });
+        pos = start + size + (size & 1);
+    }
+    Ok(headers)
+}
+
+fn read_u32(data: &[u8], pos: usize, order: ByteOrder) -> Result<u32, ArchiveError> {
+    let bytes: [u8; 4] = data
+        .get(pos..pos + 4)
+        .and_then(|b| b.try_into().ok())
+        .ok_or(ArchiveError::BadSymbolTable)?;
+    Ok(match order {
+        ByteOrder::Little => u32::from_le_bytes(bytes),
+        ByteOrder::Big => u32::from_be_bytes(bytes),
+    })
+}
+
+fn read_cstr(data: &[u8], pos: usize) -> Result<(&str, usize), ArchiveError> {
+    let rest = data.get(pos..).ok_or(ArchiveError::BadSymbolTable)?;
+    let len = rest
+        .iter()
+        .position(|&b| b == 0)
+        .ok_or(ArchiveError::BadSymbolTable)?;
+    let name = core::str::from_utf8(&rest[..len]).map_err(|_| ArchiveError::BadSymbolTable)?;
+    Ok((name, pos + len + 1))
+}
+
+fn read_u64_be(data: &[u8], pos: usize) -> Result<u64, ArchiveError> {
+    data.get(pos..pos + 8)
+        .and_then(|b| b.try_into().ok())
+        .map(u64::from_be_bytes)
+        .ok_or(ArchiveError::BadSymbolTable)
+}
+
+/// Reads the SysV (and first windows linker member) symbol index, producing `(name, member offset)` pairs.
+///
+/// If `wide` is set, this is the GNU `/SYM64/` index, which uses 64-bit words instead of 32-bit words
+fn read_sysv_symbols(data: &[u8], wide: bool) -> Result<Vec<(&str, usize)>, ArchiveError> {
+    let word = if wide { 8 } else { 4 };
+    let read_word = |pos| -> Result<usize, ArchiveError> {
+        if wide {
+            usize::try_from(read_u64_be(data, pos)?).map_err(|_| ArchiveError::BadSymbolTable)
+        } else {
+            Ok(read_u32(data, pos, ByteOrder::Big)? as usize)
+        }
+    };
+    let count = read_word(0)?;
+    if count > data.len() / word {
+        return Err(ArchiveError::BadSymbolTable);
+    }
+    let mut names = word + count * word;
+    let mut symbols = Vec::with_capacity(count);
+    for i in 0..count {
+        let offset = read_word(word + word * i)?;
+        let (name, next) = read_cstr(data, names)?;
+        names = next;
+        symbols.push((name, offset));
+    }
+    Ok(symbols)
+}
+
+/// Reads the BSD `__.SYMDEF` symbol index, producing `(name, member offset)` pairs.
+///
+/// The byte order of the index is that of the target, and is detected from the size of the `ranlib` array
+fn read_bsd_symbols(data: &[u8]) -> Result<Vec<(&str, usize)>, ArchiveError> {
+    let fits = |order| {
+        read_u32(data, 0, order).is_ok_and(|size| {
+            let size = size as usize;
+            size.is_multiple_of(8)
+                && read_u32(data, 4 + size, order)
+                    .is_ok_and(|strsize| 8 + size + strsize as usize <= data.len())
+        })
+    };
+    let order = if fits(ByteOrder::Little) {
+        ByteOrder::Little
+    } else if fits(ByteOrder::Big) {
+        ByteOrder::Big
+    } else {
+        return Err(ArchiveError::BadSymbolTable);
+    };
+
+    let size = read_u32(data, 0, order)? as usize;
+    let strsize = read_u32(data, 4 + size, order)? as usize;
+    let strtab = &data[8 + size..8 + size + strsize];
+    (0..size / 8)
+        .map(|i| {
+            let strx = read_u32(data, 4 + 8 * i, order)? as usize;
+            let offset = read_u32(data, 8 + 8 * i, order)? as usize;
+            let (name, _) = read_cstr(strtab, strx)?;
+            Ok((name, offset))
+        })
+        .collect()
+}
+
+fn long_name(table: &[u8], offset: usize) -> Option<&str> {
+    let rest = table.get(offset..)?;
+    let len = rest
+        .iter()
+        .position(|&b| b == b'\n' || b == 0)
+        .unwrap_or(rest.len());
+    let name = core::str::from_utf8(&rest[..len]).ok()?;
+    Some(name.strip_suffix('/').unwrap_or(name))
+}
+
+impl<'a> Archive<'a> {
+    /// Parses an archive in any [`ArchiveFormat`] from `data`
+    pub fn parse(data: &'a [u8]) -> Result<Self, ArchiveError> {
+        let headers = read_headers(data)?;
+
+        let mut format = None;
+        let mut sysv_symbols = None;
+        let mut bsd_symbols = None;
+        let mut long_names: &[u8] = &[];
+        let mut members = Vec::new();
+        let mut offsets = Vec::new();
+        let mut has_slash_names = false;
+
+        for header in headers {
+            match header.name {
+                "/" if sysv_symbols.is_none() => sysv_symbols = Some((header.data, false)),
+                "/" => format = Some(ArchiveFormat::SysVWin),
+                "/SYM64/" => sysv_symbols = Some((header.data, true)),
+                "//" => long_names = header.data,
+                name if name.starts_with("#1/") => {
+                    let len = parse_decimal(&name.as_bytes()[3..])
+                        .filter(|&len| len <= header.data.len())
+                        .ok_or(ArchiveError::BadLongName(header.offset))?;
+                    let name = core::str::from_utf8(&header.data[..len])
+                        .map_err(|_| ArchiveError::BadLongName(header.offset))?
+                        .trim_end_matches('\0');
+                    format.get_or_insert(ArchiveFormat::Bsd);
+                    if name.starts_with("__.SYMDEF") {
+                        bsd_symbols = Some(&header.data[len..]);
+                    } else {
+                        offsets.push(header.offset);
+                        members.push(ArchiveMember {
+                            name,
+                            data: &header.data[len..],
+                        });
+                    }
+                }
+                "__.SYMDEF" | "__.SYMDEF SORTED" => {
+                    format.get_or_insert(ArchiveFormat::Bsd);
+                    bsd_symbols = Some(header.data);
+                }
+                name => {
+                    let name = if let Some(off) = name.strip_prefix('/') {
+                        has_slash_names = true;
+                        parse_decimal(off.as_bytes())
+                            .and_then(|off| long_name(long_names, off))
+                            .ok_or(ArchiveError::BadLongName(header.offset))?
+                    } else if let Some(name) = name.strip_suffix('/') {
+                        has_slash_names = true;
+                        name
+                    } else {
+                        name
+                    };
+                    offsets.push(header.offset);
+                    members.push(ArchiveMember {
+                        name,
+                        data: header.data,
+                    });
+                }
+            }
+        }
+
+        let format = format.unwrap_or(
+            if sysv_symbols.is_some() || has_slash_names || members.is_empty() {
+                ArchiveFormat::SysV
+            } else {
+                ArchiveFormat::Bsd
+            },
+        );
+
+        let raw_symbols = match (bsd_symbols, sysv_symbols) {
+            (Some(data), _) => read_bsd_symbols(data)?,
+            (None, Some((data, wide))) => read_sysv_symbols(data, wide)?,
+            (None, None) => Vec::new(),
+        };
+
+        let symbols = raw_symbols
+            .into_iter()
+            .map(|(name, offset)| {
+                offsets
+                    .binary_search(&offset)
+                    .map(|member| ArchiveSymbol { name, member })
+                    .map_err(|_| ArchiveError::BadSymbolTable)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            format,
+            members,
+            symbols,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PendingMember {
+    name: String,
+    data: Vec<u8>,
+    symbols: Vec<String>,
+}
+
+/// Builds an archive in a given [`ArchiveFormat`]
+#[derive(Clone, Debug)]
+pub struct ArchiveWriter {
+    format: ArchiveFormat,
+    byte_order: ByteOrder,
+    members: Vec<PendingMember>,
+}
+
+/// The encoded name of a member: the header name, and any bytes prepended to the member data (for BSD long names)
+struct EncodedName {
+    header: String,
+    prefix: Vec<u8>,
+}
+
+fn write_header(out: &mut Vec<u8>, name: &str, size: usize, mode: &str) {
+    out.extend_from_slice(
+        format!("{name:<16}{:<12}{:<6}{:<6}{mode:<8}{size:<10}", 0, 0, 0).as_bytes(),
+    );
+    out.extend_from_slice(HEADER_END);
+}
+
+fn write_u32(out: &mut Vec<u8>, val: usize, order: ByteOrder) -> Result<(), ArchiveError> {
+    let val = u32::try_from(val).map_err(|_| ArchiveError::TooManyMembers)?;
+    out.extend_from_slice(&match order {
+        ByteOrder::Little => val.to_le_bytes(),
+        ByteOrder::Big => val.to_be_bytes(),
+    });
+    Ok(())
+}
+
+impl ArchiveWriter {
+    /// Creates a new, empty, [`ArchiveWriter`] for `format`.
+    ///
+    /// The byte order of the BSD symbol index defaults to [`ByteOrder::Little`]
+    pub const fn new(format: ArchiveFormat) -> Self {
+        Self {
+            format,
+            byte_order: ByteOrder::Little,
+            members: Vec::new(),
+        }
+    }
+
+    /// Sets the byte order of the `__.SYMDEF` symbol index for [`ArchiveFormat::Bsd`]. This should be the byte order of the target.
+    ///
+    /// The symbol index of other formats has a fixed byte order
+    pub fn byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    /// Appends a member named `name` with the given contents.
+    ///
+    /// `symbols` are the symbols defined by the member that are added to the symbol index, in order
+    pub fn member<I: IntoIterator<Item: Into<String>>>(
+        mut self,
+        name: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        symbols: I,
+    ) -> Self {
+        self.members.push(PendingMember {
+            name: name.into(),
+            data: data.into(),
+            symbols: symbols.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    fn encode_names(&self, long_names: &mut Vec<u8>) -> Vec<EncodedName> {
+        self.members
+            .iter()
+            .map(|member| {
+                let name = &member.name;
+                match self.format {
+                    ArchiveFormat::Bsd => {
+                        if name.len() > 16 || name.contains(' ') || name.starts_with("#1/") {
+                            EncodedName {
+                                header: format!("#1/{}", name.len()),
+                                prefix: name.as_bytes().to_vec(),
+                            }
+                        } else {
+                            EncodedName {
+                                header: name.clone(),
+                                prefix: Vec::new(),
+                            }
+                        }
+                    }
+                    _ => {
+                        if name.len() > 15 || name.contains('/') {
+                            let header = format!("/{}", long_names.len());
+                            long_names.extend_from_slice(name.as_bytes());
+                            if self.format == ArchiveFormat::SysVWin {
+                                long_names.push(0);
+                            } else {
+                                long_names.extend_from_slice(b"/\n");
+                            }
+                            EncodedName {
+                                header,
+                                prefix: Vec::new(),
+                            }
+                        } else {
+                            EncodedName {
+                                header: format!("{name}/"),
+                                prefix: Vec::new(),
+                            }
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Computes the symbol index members, given the offset of each member header.
+    /// Returns the `(name, contents)` of each index member, in order
+    fn symbol_index(
+        &self,
+        offsets: &[usize],
+    ) -> Result<Vec<(&'static str, Vec<u8>)>, ArchiveError> {
+        let symbols = self
+            .members
+            .iter()
+            .zip(offsets)
+            .flat_map(|(member, &offset)| member.symbols.iter().map(move |sym| (&**sym, offset)));
+        let count = self.members.iter().map(|m| m.symbols.len()).sum::<usize>();
+
+        let mut tables = Vec::new();
+        match self.format {
+            ArchiveFormat::Bsd => {
+                if count == 0 {
+                    return Ok(tables);
+                }
+                let mut ranlib = Vec::new();
+                let mut strtab = Vec::new();
+                for (name, offset) in symbols {
+                    write_u32(&mut ranlib, strtab.len(), self.byte_order)?;
+                    write_u32(&mut ranlib, offset, self.byte_order)?;
+                    strtab.extend_from_slice(name.as_bytes());
+                    strtab.push(0);
+                }
+                let mut data = Vec::new();
+                write_u32(&mut data, ranlib.len(), self.byte_order)?;
+                data.extend_from_slice(&ranlib);
+                write_u32(&mut data, strtab.len(), self.byte_order)?;
+                data.extend_from_slice(&strtab);
+                tables.push(("__.SYMDEF", data));
+            }
+            _ => {
+                if count == 0 && self.format == ArchiveFormat::SysV {
+                    return Ok(tables);
+                }
+                let mut data = Vec::new();
+                let mut names = Vec::new();
+                write_u32(&mut data, count, ByteOrder::Big)?;
+                for (name, offset) in symbols.clone() {
+                    write_u32(&mut data, offset, ByteOrder::Big)?;
+                    names.extend_from_slice(name.as_bytes());
+                    names.push(0);
+                }
+                data.extend_from_slice(&names);
+                tables.push(("/", data));
+
+                if self.format == ArchiveFormat::SysVWin {
+                    let mut sorted = self
+                        .members
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(idx, member)| {
+                            member.symbols.iter().map(move |sym| (&**sym, idx + 1))
+                        })
+                        .collect::<Vec<_>>();
+                    sorted.sort();
+
+                    let mut data = Vec::new();
+                    write_u32(&mut data, offsets.len(), ByteOrder::Little)?;
+                    for &offset in offsets {
+                        write_u32(&mut data, offset, ByteOrder::Little)?;
+                    }
+                    write_u32(&mut data, sorted.len(), ByteOrder::Little)?;
+                    for &(_, idx) in &sorted {
+                        let idx = u16::try_from(idx).map_err(|_| ArchiveError::TooManyMembers)?;
+                        data.extend_from_slice(&idx.to_le_bytes());
+                    }
+                    for (name, _) in sorted {
+                        data.extend_from_slice(name.as_bytes());
+                        data.push(0);
+                    }
+                    tables.push(("/", data));
+                }
+            }
+        }
+        Ok(tables)
+    }
+
+    /// Writes the archive to a new buffer
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ArchiveError> {
+        const MAX_SIZE: usize = 9_999_999_999;
+
+        let mut long_names = Vec::new();
+        let names = self.encode_names(&mut long_names);
+
+        for (member, name) in self.members.iter().zip(&names) {
+            if member.data.len() + name.prefix.len() > MAX_SIZE {
+                return Err(ArchiveError::MemberTooLarge(member.name.clone()));
+            }
+        }
+
+        let padded = |size: usize| HEADER_SIZE + size + (size & 1);
+
+        // The size of the symbol index does not depend on the offsets, so compute it with placeholders first
+        let placeholder = vec![0; self.members.len()];
+        let mut pos = ARCHIVE_MAGIC.len()
+            + self
+                .symbol_index(&placeholder)?
+                .iter()
+                .map(|(_, data)| padded(data.len()))
+                .sum::<usize>();
+        if !long_names.is_empty() {
+            pos += padded(long_names.len());
+        }
+
+        let mut offsets = Vec::with_capacity(self.members.len());
+        for (member, name) in self.members.iter().zip(&names) {
+            offsets.push(pos);
+            pos += padded(member.data.len() + name.prefix.len());
+        }
+
+        let mut out = Vec::with_capacity(pos);
+        out.extend_from_slice(ARCHIVE_MAGIC);
+        let write_member = |out: &mut Vec<u8>, name: &str, mode: &str, parts: &[&[u8]]| {
+            let size = parts.iter().map(|p| p.len()).sum::<usize>();
+            write_header(out, name, size, mode);
+            for part in parts {
+                out.extend_from_slice(part);
+            }
+            if size & 1 != 0 {
+                out.push(b'\n');
+            }
+        };
+
+        for (name, data) in self.symbol_index(&offsets)? {
+            write_member(&mut out, name, "0", &[&data]);
+        }
+        if !long_names.is_empty() {
+            write_member(&mut out, "//", "", &[&long_names]);
+        }
+        for (member, name) in self.members.iter().zip(&names) {
+            write_member(&mut out, &name.header, "644", &[&name.prefix, &member.data]);
+        }
+
+        debug_assert_eq!(out.len(), pos);
+        Ok(out)
+    }
+
+    /// Writes the archive to `w`
+    pub fn write_to<W: io::Write>(&self, mut w: W) -> io::Result<()> {
+        let bytes = self
+            .to_bytes()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        w.write_all(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LONG_NAME: &str = "a_rather_long_member_name.o";
+
+    fn writer(format: ArchiveFormat) -> ArchiveWriter {
+        ArchiveWriter::new(format)
+            .member("foo.o", b"foo data".to_vec(), ["foo", "foo_helper"])
+            .member(LONG_NAME, b"odd".to_vec(), ["long"])
+            .member("bar.o", b"bar".to_vec(), Vec::<String>::new())
+    }
+
+    fn check_members(archive: &Archive) {
+        assert_eq!(
+            archive.members,
+            [
+                ArchiveMember {
+                    name: "foo.o",
+                    data: b"foo data",
+                },
+                ArchiveMember {
+                    name: LONG_NAME,
+                    data: b"odd",
+                },
+                ArchiveMember {
+                    name: "bar.o",
+                    data: b"bar",
+                },
+            ]
+        );
+    }
+
+    fn check_symbols(archive: &Archive) {
+        assert_eq!(
+            archive.symbols,
+            [
+                ArchiveSymbol {
+                    name: "foo",
+                    member: 0,
+                },
+                ArchiveSymbol {
+                    name: "foo_helper",
+                    member: 0,
+                },
+                ArchiveSymbol {
+                    name: "long",
+                    member: 1,
+                },
+            ]
+        );
+    }
+
+    fn member_names(bytes: &[u8]) -> Vec<&str> {
+        read_headers(bytes)
+            .unwrap()
+            .into_iter()
+            .map(|header| header.name)
+            .collect()
+    }
+
+    #[test]
+    fn sysv_round_trip() {
+        let bytes = writer(ArchiveFormat::SysV).to_bytes().unwrap();
+        assert_eq!(member_names(&bytes), ["/", "//", "foo.o/", "/0", "bar.o/"]);
+
+        let archive = Archive::parse(&bytes).unwrap();
+        assert_eq!(archive.format, ArchiveFormat::SysV);
+        check_members(&archive);
+        check_symbols(&archive);
+    }
+
+    #[test]
+    fn sysv_without_symbols() {
+        let bytes = ArchiveWriter::new(ArchiveFormat::SysV)
+            .member("foo.o", b"foo".to_vec(), Vec::<String>::new())
+            .to_bytes()
+            .unwrap();
+        assert_eq!(member_names(&bytes), ["foo.o/"]);
+
+        let archive = Archive::parse(&bytes).unwrap();
+        assert_eq!(archive.format, ArchiveFormat::SysV);
+        assert!(archive.symbols.is_empty());
+    }
+
+    #[test]
+    fn sysv_win_round_trip() {
+        let bytes = writer(ArchiveFormat::SysVWin).to_bytes().unwrap();
+        assert_eq!(
+            member_names(&bytes),
+            ["/", "/", "//", "foo.o/", "/0", "bar.o/"]
+        );
+
+        let archive = Archive::parse(&bytes).unwrap();
+        assert_eq!(archive.format, ArchiveFormat::SysVWin);
+        check_members(&archive);
+        check_symbols(&archive);
+
+        // The second linker member lists the member offsets, then the symbols sorted by name with 1-based member indices
+        let headers = read_headers(&bytes).unwrap();
+        let second = headers[1].data;
+        assert_eq!(read_u32(second, 0, ByteOrder::Little), Ok(3));
+        for (i, header) in headers[3..].iter().enumerate() {
+            assert_eq!(
+                read_u32(second, 4 + 4 * i, ByteOrder::Little),
+                Ok(header.offset as u32)
+            );
+        }
+        assert_eq!(read_u32(second, 16, ByteOrder::Little), Ok(3));
+        assert_eq!(&second[20..26], [1, 0, 1, 0, 2, 0]);
+        assert_eq!(&second[26..], b"foo\0foo_helper\0long\0");
+    }
+
+    #[test]
+    fn bsd_round_trip() {
+        for order in [ByteOrder::Little, ByteOrder::Big] {
+            let bytes = writer(ArchiveFormat::Bsd)
+                .byte_order(order)
+                .to_bytes()
+                .unwrap();
+            let long_header = format!("#1/{}", LONG_NAME.len());
+            assert_eq!(
+                member_names(&bytes),
+                ["__.SYMDEF", "foo.o", &*long_header, "bar.o"]
+            );
+
+            let archive = Archive::parse(&bytes).unwrap();
+            assert_eq!(archive.format, ArchiveFormat::Bsd);
+            check_members(&archive);
+            check_symbols(&archive);
+        }
+    }
+
+    #[test]
+    fn bsd_long_symdef() {
+        let mut bytes = ARCHIVE_MAGIC.to_vec();
+        let mut symdef = b"__.SYMDEF SORTED".to_vec();
+        symdef.extend_from_slice(&8u32.to_le_bytes());
+        symdef.extend_from_slice(&0u32.to_le_bytes());
+        symdef.extend_from_slice(&(ARCHIVE_MAGIC.len() + HEADER_SIZE + 36).to_le_bytes()[..4]);
+        symdef.extend_from_slice(&4u32.to_le_bytes());
+        symdef.extend_from_slice(b"sym\0");
+        write_header(&mut bytes, "#1/16", symdef.len(), "0");
+        bytes.extend_from_slice(&symdef);
+        write_header(&mut bytes, "#1/8", 10, "644");
+        bytes.extend_from_slice(b"x.o\0\0\0\0\0xx");
+
+        let archive = Archive::parse(&bytes).unwrap();
+        assert_eq!(archive.format, ArchiveFormat::Bsd);
+        assert_eq!(
+            archive.members,
+            [ArchiveMember {
+                name: "x.o",
+                data: b"xx",
+            }]
+        );
+        assert_eq!(
+            archive.symbols,
+            [ArchiveSymbol {
+                name: "sym",
+                member: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn gnu_sym64_index() {
+        let mut index = Vec::new();
+        index.extend_from_slice(&2u64.to_be_bytes());
+        // Two offsets to be filled in once the size of the index is known
+        index.extend_from_slice(&[0; 16]);
+        index.extend_from_slice(b"first\0second\0");
+        let first = ARCHIVE_MAGIC.len() + HEADER_SIZE + index.len() + (index.len() & 1);
+        let second = first + HEADER_SIZE + 2;
+        index[8..16].copy_from_slice(&(first as u64).to_be_bytes());
+        index[16..24].copy_from_slice(&(second as u64).to_be_bytes());
+
+        let mut bytes = ARCHIVE_MAGIC.to_vec();
+        write_header(&mut bytes, "/SYM64/", index.len(), "0");
+        bytes.extend_from_slice(&index);
+        if index.len() & 1 != 0 {
+            bytes.push(b'\n');
+        }
+        write_header(&mut bytes, "a.o/", 2, "644");
+        bytes.extend_from_slice(b"aa");
+        write_header(&mut bytes, "b.o/", 2, "644");
+        bytes.extend_from_slice(b"bb");
+
+        let archive = Archive::parse(&bytes).unwrap();
+        assert_eq!(archive.format, ArchiveFormat::SysV);
+        assert_eq!(archive.members.len(), 2);
+        assert_eq!(
+            archive.symbols,
+            [
+                ArchiveSymbol {
+                    name: "first",
+                    member: 0,
+                },
+                ArchiveSymbol {
+                    name: "second",
+                    member: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_archives() {
+        assert_eq!(
+            Archive::parse(b"not an archive"),
+            Err(ArchiveError::BadMagic)
+        );
+
+        let mut truncated = writer(ArchiveFormat::SysV).to_bytes().unwrap();
+        // Drop the padding byte and the last byte of the final member
+        truncated.truncate(truncated.len() - 2);
+        assert!(matches!(
+            Archive::parse(&truncated),
+            Err(ArchiveError::BadHeader(_))
+        ));
+
+        let mut bytes = ARCHIVE_MAGIC.to_vec();
+        write_header(&mut bytes, "/SYM64/", 8, "0");
+        bytes.extend_from_slice(&u64::MAX.to_be_bytes());
+        assert_eq!(Archive::parse(&bytes), Err(ArchiveError::BadSymbolTable));
+
+        let mut bytes = ARCHIVE_MAGIC.to_vec();
+        write_header(&mut bytes, "/42", 2, "644");
+        bytes.extend_from_slice(b"xx");
+        assert_eq!(
+            Archive::parse(&bytes),
+            Err(ArchiveError::BadLongName(ARCHIVE_MAGIC.len()))
+        );
+    }
+}
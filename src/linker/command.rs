@@ -0,0 +1,851 @@
+//! Construction of linker command lines
+
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    linker::search::LibraryResolver,
+    properties::{
+        ExtPropertyValue,
+        binfmt::{
+            BinaryFormat, CPU_TYPE_ARM, CPU_TYPE_ARM64, CPU_TYPE_X86, CPU_TYPE_X86_64, Container,
+        },
+        link::{
+            BranchProtection, CfProtection, Link, LinkerFlavour, NxStackMode, OutputKind,
+            RelroMode, RunpathMode, SonameMode,
+        },
+        target::Target,
+    },
+};
+
+/// An error produced when building a linker command line
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LinkCommandError {
+    /// The requested [`OutputKind`] is not supported by the target
+    UnsupportedOutput(OutputKind),
+    /// A soname was requested, but the target does not record sonames, or the output is not a dynamic library
+    UnsupportedSoname,
+    /// Runtime search paths were requested, but the target does not support them
+    UnsupportedRunpath,
+}
+
+impl core::fmt::Display for LinkCommandError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedOutput(kind) => f.write_fmt(format_args!(
+                "output kind {kind:?} is not supported by the target"
+            )),
+            Self::UnsupportedSoname => {
+                f.write_str("sonames are not supported by the target for this output kind")
+            }
+            Self::UnsupportedRunpath => {
+                f.write_str("runtime search paths are not supported by the target")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LinkCommandError {}
+
+/// Builds the arguments to pass to the linker for a target.
+///
+/// The arguments do not include the linker program itself
+#[derive(Clone, Debug)]
+pub struct LinkCommand<'a> {
+    link: &'a Link,
+    target: Option<&'a Target>,
+    flavour: LinkerFlavour,
+    output: PathBuf,
+    kind: OutputKind,
+    inputs: Vec<PathBuf>,
+    libraries: Vec<String>,
+    library_dirs: Vec<PathBuf>,
+    sysroot: Option<PathBuf>,
+    target_stem: Option<String>,
+    nostdlib: bool,
+    nostartfiles: bool,
+    soname: Option<String>,
+    runpaths: Vec<String>,
+    min_os_version: Option<String>,
+    sdk_version: Option<String>,
+}
+
+impl<'a> LinkCommand<'a> {
+    /// Creates a new [`LinkCommand`] producing `output` for a target with the given [`Link`] properties.
+    ///
+    /// The linker flavour defaults to [`LinkFormat::default_linker_format`][crate::properties::link::LinkFormat::default_linker_format],
+    ///  and the output kind defaults to the default executable kind for the target
+    pub fn new(link: &'a Link, output: impl Into<PathBuf>) -> Self {
+        Self {
+            link,
+            target: None,
+            flavour: link.formats.default_linker_format,
+            output: output.into(),
+            kind: OutputKind::default_exe(link.formats.supported_artifacts),
+            inputs: Vec::new(),
+            libraries: Vec::new(),
+            library_dirs: Vec::new(),
+            sysroot: None,
+            target_stem: None,
+            nostdlib: false,
+            nostartfiles: false,
+            soname: None,
+            runpaths: Vec::new(),
+            min_os_version: None,
+            sdk_version: None,
+        }
+    }
+
+    /// Creates a new [`LinkCommand`] producing `output` for a target.
+    ///
+    /// This is the same as [`LinkCommand::new`] with the target's [`Link`] properties,
+    ///  except that the libraries required by the environment ([`Env::link_libraries`][crate::properties::env::Env::link_libraries]) are linked after the default libraries
+    pub fn for_target(target: &'a Target, output: impl Into<PathBuf>) -> Self {
+        Self {
+            target: Some(target),
+            ..Self::new(&target.link, output)
+        }
+    }
+
+    /// Sets the flavour of the linker command line
+    pub fn flavour(mut self, flavour: LinkerFlavour) -> Self {
+        self.flavour = flavour;
+        self
+    }
+
+    /// Sets the kind of output to produce
+    pub fn kind(mut self, kind: OutputKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Adds an input file (object file or library)
+    pub fn input(mut self, input: impl Into<PathBuf>) -> Self {
+        self.inputs.push(input.into());
+        self
+    }
+
+    /// Adds each of the input files (object files or libraries)
+    pub fn inputs<I: IntoIterator<Item: Into<PathBuf>>>(mut self, inputs: I) -> Self {
+        self.inputs.extend(inputs.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds a library to link by name, as if by `-l<name>`
+    pub fn library(mut self, name: impl Into<String>) -> Self {
+        self.libraries.push(name.into());
+        self
+    }
+
+    /// Adds a directory to search for libraries, as if by `-L<dir>`.
+    /// These are searched before the target's library search directories
+    pub fn library_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.library_dirs.push(dir.into());
+        self
+    }
+
+    /// Sets the sysroot to resolve the target's library search directories in
+    pub fn sysroot(mut self, sysroot: impl Into<PathBuf>) -> Self {
+        self.sysroot = Some(sysroot.into());
+        self
+    }
+
+    /// Sets the target stem used when [`LibrarySearch::use_target_stem_dirs`][crate::properties::link::LibrarySearch::use_target_stem_dirs] is set
+    pub fn target_stem(mut self, stem: impl Into<String>) -> Self {
+        self.target_stem = Some(stem.into());
+        self
+    }
+
+    /// Sets whether the default libraries and startfiles are omitted (`-nostdlib`)
+    pub fn nostdlib(mut self, nostdlib: bool) -> Self {
+        self.nostdlib = nostdlib;
+        self
+    }
+
+    /// Sets whether the default startfiles and endfiles are omitted (`-nostartfiles`)
+    pub fn nostartfiles(mut self, nostartfiles: bool) -> Self {
+        self.nostartfiles = nostartfiles;
+        self
+    }
+
+    /// Sets the soname (or install name) recorded in a dynamic library.
+    /// See [`Link::soname`] for the default name of a library
+    pub fn soname(mut self, soname: impl Into<String>) -> Self {
+        self.soname = Some(soname.into());
+        self
+    }
+
+    /// Adds a runtime library search path, recorded as specified by [`DylibPolicy::runpath`][crate::properties::link::DylibPolicy::runpath].
+    /// See [`DylibPolicy::origin_relative`][crate::properties::link::DylibPolicy::origin_relative] for paths relative to the output
+    pub fn runpath(mut self, path: impl Into<String>) -> Self {
+        self.runpaths.push(path.into());
+        self
+    }
+
+    /// Sets the minimum OS version recorded by [`LinkerFlavour::MachLd`] (`-platform_version`).
+    /// Defaults to the `darwin.min-version` property of the target, if the command was created by [`LinkCommand::for_target`]
+    pub fn min_os_version(mut self, version: impl Into<String>) -> Self {
+        self.min_os_version = Some(version.into());
+        self
+    }
+
+    /// Sets the SDK version recorded by [`LinkerFlavour::MachLd`] (`-platform_version`). Defaults to the minimum OS version
+    pub fn sdk_version(mut self, version: impl Into<String>) -> Self {
+        self.sdk_version = Some(version.into());
+        self
+    }
+
+    /// A [`LibraryResolver`] for the library search directories of the command
+    pub fn resolver(&self) -> LibraryResolver<'_> {
+        LibraryResolver::new(
+            &self.link.search,
+            self.sysroot.as_deref(),
+            self.target_stem.as_deref(),
+            &self.library_dirs,
+        )
+    }
+
+    /// The directories searched for libraries, in order.
+    /// This is the explicitly added directories followed by the target's library search directories
+    pub fn search_paths(&self) -> Vec<PathBuf> {
+        self.resolver().search_dirs().to_vec()
+    }
+
+    /// Builds the linker arguments
+    pub fn build(&self) -> Result<Vec<OsString>, LinkCommandError> {
+        let defaults = match self.target {
+            Some(target) => target.default_link_files(self.kind),
+            None => self.link.default_link_files(self.kind),
+        }
+        .ok_or(LinkCommandError::UnsupportedOutput(self.kind))?;
+
+        let start_files: &[_] = if self.nostdlib || self.nostartfiles {
+            &[]
+        } else {
+            defaults.start_files
+        };
+        let end_files: &[_] = if self.nostdlib || self.nostartfiles {
+            &[]
+        } else {
+            defaults.end_files
+        };
+        let default_libraries = if self.nostdlib {
+            Vec::new()
+        } else {
+            defaults
+                .libraries
+                .iter()
+                .chain(defaults.env_libraries)
+                .collect()
+        };
+
+        let policy = &self.link.dylib_policy;
+        if self.soname.is_some()
+            && (policy.soname == SonameMode::Unsupported || self.kind != OutputKind::Dylib)
+        {
+            return Err(LinkCommandError::UnsupportedSoname);
+        }
+        if !self.runpaths.is_empty() && policy.runpath == RunpathMode::Unsupported {
+            return Err(LinkCommandError::UnsupportedRunpath);
+        }
+
+        let mut args = Vec::new();
+        let resolver = self.resolver();
+        let search_paths = resolver.search_dirs();
+
+        match self.flavour {
+            LinkerFlavour::Ld | LinkerFlavour::WasmLd => {
+                if let (LinkerFlavour::Ld, Some(sysroot)) = (self.flavour, &self.sysroot) {
+                    args.push(concat_os("--sysroot=", sysroot));
+                }
+                match (self.flavour, self.kind) {
+                    (LinkerFlavour::WasmLd, OutputKind::Exe | OutputKind::StaticExe) => {}
+                    (LinkerFlavour::WasmLd, OutputKind::Pie | OutputKind::StaticPie) => {
+                        args.push("-pie".into())
+                    }
+                    (_, OutputKind::Dylib) => args.push("-shared".into()),
+                    (_, OutputKind::Exe) => self.push_dynlinker(&mut args),
+                    (_, OutputKind::Pie) => {
+                        args.push("-pie".into());
+                        self.push_dynlinker(&mut args);
+                    }
+                    (_, OutputKind::StaticExe) => args.push("-static".into()),
+                    (_, OutputKind::StaticPie) => {
+                        args.push("-static".into());
+                        args.push("-pie".into());
+                        args.push("--no-dynamic-linker".into());
+                        args.push("-z".into());
+                        args.push("text".into());
+                    }
+                }
+                if self.link.nx_stack == NxStackMode::GnuStack {
+                    args.push("-z".into());
+                    args.push("noexecstack".into());
+                }
+                if self.flavour == LinkerFlavour::Ld {
+                    self.push_hardening(&mut args);
+                }
+                if let Some(soname) = &self.soname {
+                    args.push("-soname".into());
+                    args.push(soname.into());
+                }
+                if !self.runpaths.is_empty() {
+                    match policy.runpath {
+                        RunpathMode::Runpath if policy.requires_new_dtags => {
+                            args.push("--enable-new-dtags".into())
+                        }
+                        RunpathMode::Rpath => args.push("--disable-new-dtags".into()),
+                        _ => {}
+                    }
+                }
+                for path in &self.runpaths {
+                    args.push("-rpath".into());
+                    args.push(path.into());
+                }
+                args.push("-o".into());
+                args.push(self.output.clone().into());
+                for dir in search_paths {
+                    args.push(concat_os("-L", dir));
+                }
+                // Startfiles are passed by path, as `-l:<file>` would link them as libraries (and in the wrong order with `--as-needed`)
+                for file in start_files {
+                    args.push(resolve_file(&resolver, file));
+                }
+                for input in &self.inputs {
+                    args.push(input.clone().into());
+                }
+                for lib in &self.libraries {
+                    args.push(format!("-l{lib}").into());
+                }
+                for lib in &default_libraries {
+                    args.push(format!("-l{lib}").into());
+                }
+                for file in end_files {
+                    args.push(resolve_file(&resolver, file));
+                }
+            }
+            LinkerFlavour::MachLd => {
+                let arch = macho_arch_name(&self.link.formats.exec_binfmt);
+                if let Some(arch) = arch {
+                    args.push("-arch".into());
+                    args.push(arch.into());
+                }
+                if let Some(min_version) = self.resolved_min_os_version() {
+                    let platform = self.target.map_or("macos", |target| &target.os.name);
+                    args.push("-platform_version".into());
+                    args.push(platform.into());
+                    args.push(min_version.into());
+                    args.push(self.sdk_version.as_deref().unwrap_or(min_version).into());
+                }
+                if let Some(sysroot) = &self.sysroot {
+                    args.push("-syslibroot".into());
+                    args.push(sysroot.clone().into());
+                }
+                match self.kind {
+                    // Executables are always position independent on arm64, and ld64 rejects `-no_pie`
+                    OutputKind::Exe if arch == Some("arm64") => {}
+                    OutputKind::Exe => args.push("-no_pie".into()),
+                    OutputKind::Pie => args.push("-pie".into()),
+                    OutputKind::StaticExe | OutputKind::StaticPie => args.push("-static".into()),
+                    OutputKind::Dylib => args.push("-dylib".into()),
+                }
+                if let Some(soname) = &self.soname {
+                    args.push("-install_name".into());
+                    args.push(soname.into());
+                }
+                for path in &self.runpaths {
+                    args.push("-rpath".into());
+                    args.push(path.into());
+                }
+                args.push("-o".into());
+                args.push(self.output.clone().into());
+                for dir in search_paths {
+                    args.push(concat_os("-L", dir));
+                }
+                for file in start_files {
+                    args.push(resolve_file(&resolver, file));
+                }
+                for input in &self.inputs {
+                    args.push(input.clone().into());
+                }
+                for lib in self
+                    .libraries
+                    .iter()
+                    .map(|v| &**v)
+                    .chain(default_libraries.iter().map(|v| &***v))
+                {
+                    args.push(format!("-l{lib}").into());
+                }
+                for file in end_files {
+                    args.push(resolve_file(&resolver, file));
+                }
+            }
+            LinkerFlavour::Link => {
+                args.push("/NOLOGO".into());
+                match self.kind {
+                    OutputKind::Exe | OutputKind::StaticExe => args.push("/DYNAMICBASE:NO".into()),
+                    OutputKind::Pie | OutputKind::StaticPie => args.push("/DYNAMICBASE".into()),
+                    OutputKind::Dylib => args.push("/DLL".into()),
+                }
+                if self.link.nx_stack != NxStackMode::Unsupported {
+                    args.push("/NXCOMPAT".into());
+                }
+                if self.nostdlib {
+                    args.push("/NODEFAULTLIB".into());
+                }
+                args.push(concat_os("/OUT:", &self.output));
+                for dir in search_paths {
+                    args.push(concat_os("/LIBPATH:", dir));
+                }
+                for file in start_files {
+                    args.push(resolve_file(&resolver, file));
+                }
+                for input in &self.inputs {
+                    args.push(input.clone().into());
+                }
+                let suffix = self
+                    .link
+                    .search
+                    .dylib_suffixes
+                    .first()
+                    .map_or(".lib", |v| &**v);
+                for lib in self
+                    .libraries
+                    .iter()
+                    .map(|v| &**v)
+                    .chain(default_libraries.iter().map(|v| &***v))
+                {
+                    args.push(format!("{lib}{suffix}").into());
+                }
+                for file in end_files {
+                    args.push(resolve_file(&resolver, file));
+                }
+            }
+        }
+
+        Ok(args)
+    }
+
+    fn resolved_min_os_version(&self) -> Option<&str> {
+        if let Some(version) = &self.min_os_version {
+            return Some(version);
+        }
+        self.target?
+            .extended_properties
+            .iter()
+            .find_map(|(name, val)| match val {
+                ExtPropertyValue::String(version) if name == "darwin.min-version" => {
+                    Some(&**version)
+                }
+                _ => None,
+            })
+    }
+
+    /// Pushes the linker flags for [`Link::hardening`].
+    ///
+    /// Only the protections that the linker takes part in are applied: stack protectors, `_FORTIFY_SOURCE`, and stack clash protection only affect code generation.
+    /// If objects carry `.note.gnu.property`, the linker marks the output for CET or BTI when every input is marked, and only reports inputs that are not.
+    /// Otherwise, the marking is forced
+    fn push_hardening(&self, args: &mut Vec<OsString>) {
+        let hardening = &self.link.hardening;
+        let mut z = |flag: &str| {
+            args.push("-z".into());
+            args.push(flag.into());
+        };
+        match hardening.relro {
+            RelroMode::None => {}
+            RelroMode::Partial => z("relro"),
+            RelroMode::Full => {
+                z("relro");
+                z("now");
+            }
+        }
+
+        // With property notes, the linker marks the output from its inputs
+        let cf = hardening.cf_protection;
+        if !hardening.gnu_property_note {
+            if cf.contains(CfProtection::IBT) {
+                z("ibt");
+            }
+            if cf.contains(CfProtection::SHSTK) {
+                z("shstk");
+            }
+        }
+
+        let bp = hardening.branch_protection;
+        if bp.contains(BranchProtection::BTI) {
+            z(if hardening.gnu_property_note {
+                "bti-report=warning"
+            } else {
+                "force-bti"
+            });
+        }
+        if bp.contains(BranchProtection::PAC_RET) {
+            z("pac-plt");
+        }
+    }
+
+    fn push_dynlinker(&self, args: &mut Vec<OsString>) {
+        if let Some(dynlinker) = &self.link.dynlinker_name {
+            args.push("-dynamic-linker".into());
+            args.push((&**dynlinker).into());
+        }
+    }
+}
+
+/// The name of a Mach-O architecture, as accepted by `ld64 -arch`
+fn macho_arch_name(format: &BinaryFormat) -> Option<&'static str> {
+    match format.container {
+        Container::MachO {
+            cputype: CPU_TYPE_X86_64,
+            ..
+        } => Some("x86_64"),
+        Container::MachO {
+            cputype: CPU_TYPE_ARM64,
+            ..
+        } => Some("arm64"),
+        Container::MachO {
+            cputype: CPU_TYPE_X86,
+            ..
+        } => Some("i386"),
+        Container::MachO {
+            cputype: CPU_TYPE_ARM,
+            ..
+        } => Some("arm"),
+        _ => None,
+    }
+}
+
+/// Resolves a startfile or endfile in the search directories.
+/// If it is not found, the name is passed as-is, so that the linker reports it as missing
+fn resolve_file(resolver: &LibraryResolver, file: &str) -> OsString {
+    resolver
+        .find_file(file)
+        .map_or_else(|| file.into(), Into::into)
+}
+
+fn concat_os(prefix: &str, path: &Path) -> OsString {
+    let mut st = OsString::from(prefix);
+    st.push(path);
+    st
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt::Write as _, fs};
+
+    use target_tuples::TargetRef;
+
+    use super::*;
+    use crate::{
+        builtin::target::{BUILTIN_TARGETS, from_target},
+        helpers::CowPtr,
+        linker::test_util::TempTree,
+        properties::link::Hardening,
+    };
+
+    const KINDS: [OutputKind; 5] = [
+        OutputKind::Exe,
+        OutputKind::Pie,
+        OutputKind::StaticExe,
+        OutputKind::StaticPie,
+        OutputKind::Dylib,
+    ];
+
+    const SNAPSHOT: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/linker/snapshots/command.txt"
+    );
+
+    fn target(name: &str) -> Target {
+        from_target(&TargetRef::parse(name)).unwrap()
+    }
+
+    fn render(args: &[OsString], sysroot: &Path) -> String {
+        let sysroot = sysroot.to_string_lossy();
+        args.iter()
+            .map(|arg| arg.to_string_lossy().replace(&*sysroot, "$SYSROOT"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Snapshots the linker arguments of every builtin target, in its default flavour, for every output kind.
+    ///
+    /// Set `UPDATE_SNAPSHOTS` to rewrite the snapshot
+    #[test]
+    fn builtin_targets_snapshot() {
+        let sysroot = TempTree::new("command-snapshot");
+        let targets = BUILTIN_TARGETS
+            .iter()
+            .map(|&name| (name, target(name)))
+            .collect::<Vec<_>>();
+
+        // Startfiles are resolved from `/usr/lib` in the sysroot, so targets that do not search it show the bare name
+        for (_, target) in &targets {
+            for kind in KINDS {
+                if let Some(files) = target.default_link_files(kind) {
+                    for file in files.start_files.iter().chain(files.end_files) {
+                        sysroot.touch(&format!("usr/lib/{file}"));
+                    }
+                }
+            }
+        }
+
+        let mut snapshot = String::new();
+        for (name, target) in &targets {
+            writeln!(
+                snapshot,
+                "# {name} ({:?})",
+                target.link.formats.default_linker_format
+            )
+            .unwrap();
+            let policy = &target.link.dylib_policy;
+            for kind in KINDS {
+                let mut command = LinkCommand::for_target(target, "out")
+                    .kind(kind)
+                    .sysroot(&sysroot.0)
+                    .target_stem(*name)
+                    .input("main.o")
+                    .library("foo");
+                if kind == OutputKind::Dylib
+                    && let Some(soname) = target.link.soname("out", Some("1.2.3"))
+                {
+                    command = command.soname(soname);
+                }
+                if policy.runpath != RunpathMode::Unsupported {
+                    command = command.runpath("/opt/lib");
+                }
+                match command.build() {
+                    Ok(args) => writeln!(snapshot, "{kind:?}: {}", render(&args, &sysroot.0)),
+                    Err(err) => writeln!(snapshot, "{kind:?}: error: {err}"),
+                }
+                .unwrap();
+            }
+            snapshot.push('\n');
+        }
+
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            fs::write(SNAPSHOT, &snapshot).unwrap();
+        } else {
+            let expected = fs::read_to_string(SNAPSHOT).unwrap();
+            for (line, (found, expected)) in snapshot.lines().zip(expected.lines()).enumerate() {
+                assert_eq!(found, expected, "snapshot mismatch at line {}", line + 1);
+            }
+            assert_eq!(snapshot, expected);
+        }
+    }
+
+    #[test]
+    fn resolves_startfiles() {
+        let sysroot = TempTree::new("command-startfiles");
+        let name = "x86_64-pc-linux-gnu";
+        sysroot.touch(&format!("usr/{name}/lib/Scrt1.o"));
+        sysroot.touch("usr/lib/crti.o");
+
+        let target = target(name);
+        let args = LinkCommand::for_target(&target, "out")
+            .kind(OutputKind::Pie)
+            .sysroot(&sysroot.0)
+            .target_stem(name)
+            .build()
+            .unwrap();
+        let stem_scrt1 = sysroot.0.join(format!("usr/{name}/lib/Scrt1.o"));
+        let crti = sysroot.0.join("usr/lib/crti.o");
+        assert!(args.contains(&stem_scrt1.into_os_string()));
+        assert!(args.contains(&crti.into_os_string()));
+        // Missing files are passed by name, so that the linker reports them
+        assert!(args.contains(&OsString::from("crtn.o")));
+        assert!(
+            !args
+                .iter()
+                .any(|arg| arg.to_string_lossy().starts_with("-l:"))
+        );
+
+        // Every flavour resolves startfiles and endfiles through the search directories
+        let crtn = sysroot.touch("usr/lib/crtn.o").into_os_string();
+        for flavour in [
+            LinkerFlavour::Ld,
+            LinkerFlavour::MachLd,
+            LinkerFlavour::Link,
+        ] {
+            let args = LinkCommand::for_target(&target, "out")
+                .flavour(flavour)
+                .kind(OutputKind::Pie)
+                .sysroot(&sysroot.0)
+                .build()
+                .unwrap();
+            assert!(args.contains(&crtn), "{flavour:?}: {args:?}");
+        }
+    }
+
+    #[test]
+    fn static_libraries() {
+        let libs = |name: &str, kind| {
+            let target = target(name);
+            LinkCommand::for_target(&target, "out")
+                .kind(kind)
+                .build()
+                .unwrap()
+                .into_iter()
+                .map(|arg| arg.into_string().unwrap())
+                .filter(|arg| arg.ends_with(".lib") || arg.starts_with("-l"))
+                .collect::<Vec<_>>()
+        };
+
+        let msvc = "x86_64-pc-windows-msvc";
+        assert_eq!(
+            libs(msvc, OutputKind::Pie),
+            ["msvcrt.lib", "vcruntime.lib", "ucrt.lib", "kernel32.lib"]
+        );
+        assert_eq!(
+            libs(msvc, OutputKind::StaticExe),
+            [
+                "libcmt.lib",
+                "libvcruntime.lib",
+                "libucrt.lib",
+                "kernel32.lib"
+            ]
+        );
+
+        // libSystem is only available as a dylib
+        let darwin = "aarch64-apple-darwin";
+        assert_eq!(libs(darwin, OutputKind::Pie), ["-lSystem"]);
+        assert!(libs(darwin, OutputKind::StaticExe).is_empty());
+    }
+
+    #[test]
+    fn env_libraries() {
+        let target = target("x86_64-pc-linux-gnu");
+        let args = LinkCommand::for_target(&target, "out").build().unwrap();
+        let libs = args
+            .iter()
+            .filter_map(|arg| arg.to_str()?.strip_prefix("-l"))
+            .collect::<Vec<_>>();
+        assert_eq!(libs, ["c", "gcc", "gcc_s"]);
+
+        // libgcc_s is shared-only, so static links use libgcc_eh instead
+        let args = LinkCommand::for_target(&target, "out")
+            .kind(OutputKind::StaticExe)
+            .build()
+            .unwrap();
+        let libs = args
+            .iter()
+            .filter_map(|arg| arg.to_str()?.strip_prefix("-l"))
+            .collect::<Vec<_>>();
+        assert_eq!(libs, ["c", "gcc", "gcc_eh"]);
+
+        // Without the target, only the default libraries of `Link` are known
+        let args = LinkCommand::new(&target.link, "out").build().unwrap();
+        assert!(args.contains(&OsString::from("-lc")));
+        assert!(!args.contains(&OsString::from("-lgcc_s")));
+
+        let args = LinkCommand::for_target(&target, "out")
+            .nostdlib(true)
+            .build()
+            .unwrap();
+        assert!(
+            !args
+                .iter()
+                .any(|arg| arg.to_string_lossy().starts_with("-l"))
+        );
+    }
+
+    #[test]
+    fn mach_ld() {
+        let target = target("aarch64-apple-darwin");
+        let args = LinkCommand::for_target(&target, "out")
+            .kind(OutputKind::Exe)
+            .build()
+            .unwrap();
+        assert_eq!(
+            args[..6],
+            [
+                "-arch",
+                "arm64",
+                "-platform_version",
+                "macos",
+                "11.0",
+                "11.0"
+            ]
+        );
+        assert!(!args.contains(&OsString::from("-no_pie")));
+
+        let target = self::target("x86_64-apple-darwin");
+        let args = LinkCommand::for_target(&target, "out")
+            .kind(OutputKind::Exe)
+            .min_os_version("10.15")
+            .sdk_version("14.0")
+            .build()
+            .unwrap();
+        assert_eq!(
+            args[..6],
+            [
+                "-arch",
+                "x86_64",
+                "-platform_version",
+                "macos",
+                "10.15",
+                "14.0"
+            ]
+        );
+        assert!(args.contains(&OsString::from("-no_pie")));
+    }
+
+    #[test]
+    fn hardening() {
+        let target = target("x86_64-pc-linux-gnu");
+        let with_hardening = |hardening: Hardening| {
+            let link = Link {
+                hardening: CowPtr::Boxed(Box::new(hardening)),
+                ..(*target.link).clone()
+            };
+            let args = LinkCommand::new(&link, "out").build().unwrap();
+            args.windows(2)
+                .filter(|pair| pair[0] == "-z")
+                .map(|pair| pair[1].to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(with_hardening(Hardening::NONE), ["noexecstack"]);
+        assert_eq!(
+            with_hardening(Hardening {
+                relro: RelroMode::Full,
+                ..Hardening::NONE
+            }),
+            ["noexecstack", "relro", "now"]
+        );
+        assert_eq!(
+            with_hardening(Hardening {
+                cf_protection: CfProtection::all(),
+                ..Hardening::NONE
+            }),
+            ["noexecstack", "ibt", "shstk"]
+        );
+        assert_eq!(
+            with_hardening(Hardening {
+                cf_protection: CfProtection::all(),
+                gnu_property_note: true,
+                ..Hardening::NONE
+            }),
+            ["noexecstack"]
+        );
+        assert_eq!(
+            with_hardening(Hardening {
+                branch_protection: BranchProtection::all(),
+                ..Hardening::NONE
+            }),
+            ["noexecstack", "force-bti", "pac-plt"]
+        );
+        assert_eq!(
+            with_hardening(Hardening {
+                branch_protection: BranchProtection::BTI,
+                gnu_property_note: true,
+                ..Hardening::NONE
+            }),
+            ["noexecstack", "bti-report=warning"]
+        );
+    }
+}
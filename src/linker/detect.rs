@@ -0,0 +1,305 @@
+//! Detection of the builtin target of an existing object, executable, or archive
+
+use target_tuples::{
+    TargetRef,
+    pieces::{Environment, OS},
+};
+
+use crate::{
+    builtin::target::{BUILTIN_TARGETS, from_target},
+    linker::{
+        archive::{ARCHIVE_MAGIC, Archive},
+        object::{ObjectHeader, elf_interpreter, elf_notes},
+        validate::check_link_format,
+    },
+    properties::{
+        abi::ByteOrder,
+        binfmt::{Container, ELFOSABI_FREEBSD, ELFOSABI_GNU, ELFOSABI_NETBSD, ELFOSABI_OPENBSD},
+        target::Target,
+    },
+};
+
+const NT_GNU_ABI_TAG: u32 = 1;
+
+/// A builtin target that is compatible with a file
+#[derive(Clone, Debug)]
+pub struct DetectedTarget {
+    /// The target tuple
+    pub tuple: TargetRef<'static>,
+    /// The target properties for [`DetectedTarget::tuple`]
+    pub target: Target,
+    /// How well the target matches the file. Higher scores are better matches.
+    ///
+    /// Every target with a compatible format has a score of at least `1`
+    pub score: u32,
+}
+
+/// Information about the system a file was built for, beyond the format
+#[derive(Default)]
+struct SystemHints<'a> {
+    interp: Option<&'a str>,
+    os: Option<OS>,
+    env: Option<Environment>,
+}
+
+fn system_hints<'a>(data: &'a [u8], header: &ObjectHeader) -> SystemHints<'a> {
+    let mut hints = SystemHints::default();
+    match header.container {
+        Container::Elf { osabi, .. } => {
+            hints.interp = elf_interpreter(data);
+            hints.os = match osabi {
+                ELFOSABI_GNU => Some(OS::Linux),
+                ELFOSABI_NETBSD => Some(OS::NetBSD),
+                ELFOSABI_FREEBSD => Some(OS::FreeBSD),
+                ELFOSABI_OPENBSD => Some(OS::OpenBSD),
+                _ => None,
+            };
+            for note in elf_notes(data) {
+                match note.name {
+                    b"GNU" if note.ty == NT_GNU_ABI_TAG => {
+                        // The first word of the `.note.ABI-tag` descriptor is the OS
+                        let os = note.desc.first_chunk().map(|&os| match header.byte_order {
+                            ByteOrder::Little => u32::from_le_bytes(os),
+                            ByteOrder::Big => u32::from_be_bytes(os),
+                        });
+                        match os {
+                            Some(0) => hints.os = Some(OS::Linux),
+                            Some(3) => hints.os = Some(OS::FreeBSD),
+                            _ => {}
+                        }
+                    }
+                    b"FreeBSD" => hints.os = Some(OS::FreeBSD),
+                    b"NetBSD" => hints.os = Some(OS::NetBSD),
+                    b"OpenBSD" => hints.os = Some(OS::OpenBSD),
+                    b"Android" => {
+                        hints.os = Some(OS::Linux);
+                        hints.env = Some(Environment::Android);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Container::Wasm => {
+            let imports_wasi = |module: &[u8]| data.windows(module.len()).any(|w| w == module);
+            if imports_wasi(b"wasi_snapshot_preview1") || imports_wasi(b"wasi_unstable") {
+                hints.os = Some(OS::WASI);
+            }
+        }
+        _ => {}
+    }
+    hints
+}
+
+/// Finds the first file to inspect: the file itself, or the first recognized member of an archive
+fn object_data(data: &[u8]) -> Option<(&[u8], ObjectHeader)> {
+    if data.starts_with(ARCHIVE_MAGIC) {
+        Archive::parse(data)
+            .ok()?
+            .members
+            .into_iter()
+            .find_map(|member| Some((member.data, ObjectHeader::read(member.data)?)))
+    } else {
+        Some((data, ObjectHeader::read(data)?))
+    }
+}
+
+/// Lists the [`BUILTIN_TARGETS`] that are compatible with an object, executable, dylib, or archive, with the best match first.
+///
+/// A target is compatible if the file's format matches the target's [`LinkFormat`][crate::properties::link::LinkFormat].
+/// Targets are then ranked by:
+/// * Whether the ELF program interpreter (`PT_INTERP`) is the target's [`Link::dynlinker_name`][crate::properties::link::Link::dynlinker_name],
+/// * The OS and environment indicated by the ELF `EI_OSABI`, the `.note.ABI-tag` note, or other OS notes, and
+/// * For WebAssembly, whether the module imports WASI.
+///
+/// Targets with equal scores are listed in the order of [`BUILTIN_TARGETS`].
+/// For archives, the first member that is recognized as an object is used
+pub fn detect_targets(data: &[u8]) -> Vec<DetectedTarget> {
+    let Some((data, header)) = object_data(data) else {
+        return Vec::new();
+    };
+    let hints = system_hints(data, &header);
+
+    let mut detected = BUILTIN_TARGETS
+        .iter()
+        .filter_map(|&name| {
+            let tuple = TargetRef::parse(name);
+            let target = from_target(&tuple)?;
+            if !check_link_format(&header, &target.link.formats).is_empty() {
+                return None;
+            }
+
+            let mut score = 1;
+            if let (Some(interp), Some(dynlinker)) = (hints.interp, &target.link.dynlinker_name)
+                && interp == &**dynlinker
+            {
+                score += 8;
+            }
+            let os = tuple.sys.os().unwrap_or(OS::None);
+            if hints.os == Some(os) {
+                score += 4;
+            }
+            if hints.env.is_some() && hints.env == tuple.sys.env() {
+                score += 4;
+            }
+            Some(DetectedTarget {
+                tuple,
+                target,
+                score,
+            })
+        })
+        .collect::<Vec<_>>();
+    detected.sort_by_key(|detected| core::cmp::Reverse(detected.score));
+    detected
+}
+
+/// Determines the builtin target that best matches an object, executable, dylib, or archive.
+///
+/// Returns [`None`] if the file is not recognized, or no builtin target is compatible with it. See [`detect_targets`]
+pub fn detect_target(data: &[u8]) -> Option<DetectedTarget> {
+    detect_targets(data).into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linker::{archive::ArchiveWriter, object::TestElf};
+    use crate::properties::binfmt::{ELF32_X86_64, ELF64_X86_64};
+    use crate::properties::link::ArchiveFormat;
+
+    /// A little-endian ELF64 x86-64 executable with an optional program interpreter and notes
+    fn elf64(osabi: u8, interp: Option<&str>, notes: &[(&str, u32, &[u8])]) -> Vec<u8> {
+        let mut elf = TestElf::new(&ELF64_X86_64).osabi(osabi);
+        if let Some(interp) = interp {
+            elf = elf.interp(interp);
+        }
+        if !notes.is_empty() {
+            elf = elf.notes(notes);
+        }
+        elf.to_bytes()
+    }
+
+    fn assert_best(data: &[u8], expected: &str) {
+        assert_eq!(
+            detect_target(data).map(|detected| detected.tuple),
+            Some(TargetRef::parse(expected))
+        );
+    }
+
+    #[test]
+    fn glibc_interpreter() {
+        let data = elf64(0, Some("/lib64/ld-linux-x86-64.so.2"), &[]);
+        assert_best(&data, "x86_64-pc-linux-gnu");
+
+        let data = elf64(
+            ELFOSABI_GNU,
+            None,
+            &[(
+                "GNU",
+                NT_GNU_ABI_TAG,
+                &[0, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0],
+            )],
+        );
+        assert_best(&data, "x86_64-pc-linux-gnu");
+    }
+
+    #[test]
+    fn musl_interpreter() {
+        let data = elf64(0, Some("/lib/ld-musl-x86_64.so.1"), &[]);
+        assert_best(&data, "x86_64-pc-linux-musl");
+    }
+
+    #[test]
+    fn x32_interpreter() {
+        let data = TestElf::new(&ELF32_X86_64)
+            .interp("/libx32/ld-linux-x32.so.2")
+            .to_bytes();
+        assert_best(&data, "x86_64-pc-linux-gnux32");
+
+        let detected = detect_targets(&TestElf::new(&ELF32_X86_64).to_bytes());
+        assert!(!detected.is_empty());
+        assert!(
+            detected
+                .iter()
+                .all(|detected| detected.target.link.formats.object_binfmt == ELF32_X86_64)
+        );
+    }
+
+    #[test]
+    fn freebsd_note() {
+        let data = elf64(0, None, &[("FreeBSD", 1, &1_400_000u32.to_le_bytes())]);
+        assert_best(&data, "x86_64-unknown-freebsd");
+
+        let data = elf64(
+            0,
+            None,
+            &[(
+                "GNU",
+                NT_GNU_ABI_TAG,
+                &[3, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            )],
+        );
+        assert_best(&data, "x86_64-unknown-freebsd");
+    }
+
+    #[test]
+    fn android_note() {
+        let data = elf64(0, None, &[("Android", 1, &30u32.to_le_bytes())]);
+        assert_best(&data, "x86_64-linux-android");
+    }
+
+    #[test]
+    fn netbsd_note() {
+        let data = elf64(0, None, &[("NetBSD", 1, &1_000_000_000u32.to_le_bytes())]);
+        assert_best(&data, "x86_64-unknown-netbsd");
+    }
+
+    #[test]
+    fn wasi_module() {
+        let mut module = b"\0asm\x01\0\0\0".to_vec();
+        let import = b"\x01\x16wasi_snapshot_preview1\x08proc_exit\x00\x00";
+        module.push(2);
+        module.push(import.len() as u8);
+        module.extend_from_slice(import);
+
+        let detected = detect_target(&module).unwrap();
+        assert_eq!(detected.tuple, TargetRef::parse("wasm32-wasi"));
+        assert!(detected.score > 1);
+
+        let detected = detect_target(b"\0asm\x01\0\0\0").unwrap();
+        assert_eq!(detected.tuple, TargetRef::parse("wasm32-wasi"));
+        assert_eq!(detected.score, 1);
+    }
+
+    #[test]
+    fn archive() {
+        let archive = ArchiveWriter::new(ArchiveFormat::SysV)
+            .member("notes.txt", b"not an object".to_vec(), Vec::<String>::new())
+            .member(
+                "main.o",
+                elf64(0, Some("/lib/ld-musl-x86_64.so.1"), &[]),
+                ["main"],
+            )
+            .to_bytes()
+            .unwrap();
+        assert_best(&archive, "x86_64-pc-linux-musl");
+    }
+
+    #[test]
+    fn unrecognized() {
+        assert!(detect_targets(b"not an object").is_empty());
+        assert!(detect_targets(b"!<arch>\n").is_empty());
+    }
+
+    #[test]
+    fn ranking() {
+        let data = elf64(0, None, &[]);
+        let detected = detect_targets(&data);
+        assert!(detected.len() > 1);
+        assert!(detected.iter().all(|detected| detected.score == 1));
+        assert!(
+            detected
+                .iter()
+                .all(|detected| detected.target.link.formats.object_binfmt.class.bits() == 64)
+        );
+    }
+}
@@ -0,0 +1,628 @@
+//! Identification of object and executable files from their headers
+
+use crate::properties::{
+    abi::ByteOrder,
+    binfmt::{
+        BinaryFormat, Container, FormatArch, FormatClass, IMAGE_FILE_MACHINE_AMD64,
+        IMAGE_FILE_MACHINE_ARM64, IMAGE_FILE_MACHINE_I386,
+    },
+};
+
+/// The identifying information read from the header of an object or executable file
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ObjectHeader {
+    /// The container, with the machine identification from the header
+    pub container: Container,
+    /// The size of addresses, if the header records it.
+    /// This is [`None`] for WebAssembly modules, which do not record the memory size in the header
+    pub class: Option<FormatClass>,
+    /// The byte order of the file
+    pub byte_order: ByteOrder,
+}
+
+const ELF_MAGIC: &[u8; 4] = b"\x7fELF";
+const WASM_MAGIC: &[u8; 4] = b"\0asm";
+const O65_MAGIC: &[u8; 5] = b"\x01\x00o65";
+
+const MH_MAGIC: u32 = 0xfeed_face;
+const MH_MAGIC_64: u32 = 0xfeed_facf;
+const CPU_SUBTYPE_MASK: u32 = 0xff00_0000;
+
+/// Reads `N` bytes at `pos`, without overflowing if `pos` is out of range
+fn read_bytes<const N: usize>(data: &[u8], pos: usize) -> Option<[u8; N]> {
+    data.get(pos..)?.get(..N)?.try_into().ok()
+}
+
+fn read_u16(data: &[u8], pos: usize, order: ByteOrder) -> Option<u16> {
+    let bytes = read_bytes(data, pos)?;
+    Some(match order {
+        ByteOrder::Little => u16::from_le_bytes(bytes),
+        ByteOrder::Big => u16::from_be_bytes(bytes),
+    })
+}
+
+fn read_u32(data: &[u8], pos: usize, order: ByteOrder) -> Option<u32> {
+    let bytes = read_bytes(data, pos)?;
+    Some(match order {
+        ByteOrder::Little => u32::from_le_bytes(bytes),
+        ByteOrder::Big => u32::from_be_bytes(bytes),
+    })
+}
+
+/// The class of a COFF machine, or [`None`] if the machine is not recognized
+const fn coff_class(machine: u16) -> Option<FormatClass> {
+    match machine {
+        IMAGE_FILE_MACHINE_I386 => Some(FormatClass::Bits32),
+        IMAGE_FILE_MACHINE_AMD64 | IMAGE_FILE_MACHINE_ARM64 => Some(FormatClass::Bits64),
+        _ => None,
+    }
+}
+
+fn read_elf(data: &[u8]) -> Option<ObjectHeader> {
+    let (class, flags_off) = match *data.get(4)? {
+        1 => (FormatClass::Bits32, 36),
+        2 => (FormatClass::Bits64, 48),
+        _ => return None,
+    };
+    let byte_order = match *data.get(5)? {
+        1 => ByteOrder::Little,
+        2 => ByteOrder::Big,
+        _ => return None,
+    };
+    Some(ObjectHeader {
+        container: Container::Elf {
+            machine: read_u16(data, 18, byte_order)?,
+            osabi: *data.get(7)?,
+            flags: read_u32(data, flags_off, byte_order)?,
+        },
+        class: Some(class),
+        byte_order,
+    })
+}
+
+fn read_macho(data: &[u8]) -> Option<ObjectHeader> {
+    let (byte_order, class) = match (
+        read_u32(data, 0, ByteOrder::Little)?,
+        read_u32(data, 0, ByteOrder::Big)?,
+    ) {
+        (MH_MAGIC, _) => (ByteOrder::Little, FormatClass::Bits32),
+        (MH_MAGIC_64, _) => (ByteOrder::Little, FormatClass::Bits64),
+        (_, MH_MAGIC) => (ByteOrder::Big, FormatClass::Bits32),
+        (_, MH_MAGIC_64) => (ByteOrder::Big, FormatClass::Bits64),
+        _ => return None,
+    };
+    Some(ObjectHeader {
+        container: Container::MachO {
+            cputype: read_u32(data, 4, byte_order)?,
+            cpusubtype: read_u32(data, 8, byte_order)? & !CPU_SUBTYPE_MASK,
+        },
+        class: Some(class),
+        byte_order,
+    })
+}
+
+fn read_pe(data: &[u8]) -> Option<ObjectHeader> {
+    let pe = read_u32(data, 0x3c, ByteOrder::Little)? as usize;
+    if &read_bytes::<4>(data, pe)? != b"PE\0\0" {
+        return None;
+    }
+    let machine = read_u16(data, pe.checked_add(4)?, ByteOrder::Little)?;
+    let class = match read_u16(data, pe.checked_add(24)?, ByteOrder::Little)? {
+        0x10b => FormatClass::Bits32,
+        0x20b => FormatClass::Bits64,
+        _ => return None,
+    };
+    Some(ObjectHeader {
+        container: Container::Coff {
+            machine,
+            image: true,
+        },
+        class: Some(class),
+        byte_order: ByteOrder::Little,
+    })
+}
+
+fn read_coff(data: &[u8]) -> Option<ObjectHeader> {
+    let machine = match (
+        read_u16(data, 0, ByteOrder::Little)?,
+        read_u16(data, 2, ByteOrder::Little)?,
+    ) {
+        // Short import objects and bigobj COFF files start with `IMAGE_FILE_MACHINE_UNKNOWN` followed by `0xFFFF`,
+        //  and store the machine after the version
+        (0, 0xffff) => read_u16(data, 6, ByteOrder::Little)?,
+        (machine, _) => machine,
+    };
+    Some(ObjectHeader {
+        container: Container::Coff {
+            machine,
+            image: false,
+        },
+        class: Some(coff_class(machine)?),
+        byte_order: ByteOrder::Little,
+    })
+}
+
+fn read_o65(data: &[u8]) -> Option<ObjectHeader> {
+    let mode = read_u16(data, 6, ByteOrder::Little)?;
+    Some(ObjectHeader {
+        container: Container::O65,
+        class: Some(if mode & 0x2000 != 0 {
+            FormatClass::Bits32
+        } else {
+            FormatClass::Bits16
+        }),
+        byte_order: ByteOrder::Little,
+    })
+}
+
+impl ObjectHeader {
+    /// Reads the header of an ELF, COFF, PE, Mach-O, wasm, or o65 file.
+    ///
+    /// COFF objects do not have a magic number, and are only recognized if the machine is known.
+    /// Returns [`None`] if the file is not recognized
+    pub fn read(data: &[u8]) -> Option<ObjectHeader> {
+        if data.starts_with(ELF_MAGIC) {
+            read_elf(data)
+        } else if data.starts_with(WASM_MAGIC) {
+            Some(ObjectHeader {
+                container: Container::Wasm,
+                class: None,
+                byte_order: ByteOrder::Little,
+            })
+        } else if data.starts_with(O65_MAGIC) {
+            read_o65(data)
+        } else if data.starts_with(b"MZ") {
+            read_pe(data)
+        } else {
+            read_macho(data).or_else(|| read_coff(data))
+        }
+    }
+
+    /// The architecture family of the file, or [`None`] if the machine is not known
+    pub fn arch(&self) -> Option<FormatArch> {
+        FormatArch::from_container(&self.container)
+    }
+
+    /// The [`BinaryFormat`] of the file, if the architecture and class are known
+    pub fn format(&self) -> Option<BinaryFormat> {
+        Some(BinaryFormat {
+            container: self.container,
+            class: self.class?,
+            byte_order: self.byte_order,
+            arch: self.arch()?,
+        })
+    }
+}
+
+const PT_INTERP: u32 = 3;
+const PT_NOTE: u32 = 4;
+const SHT_NOTE: u32 = 7;
+
+/// A note from the `PT_NOTE` segments or `SHT_NOTE` sections of an ELF file
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ElfNote<'a> {
+    /// The owner of the note (such as `GNU`), without the trailing nul
+    pub name: &'a [u8],
+    /// The type of the note, which is interpreted according to the owner
+    pub ty: u32,
+    /// The contents of the note
+    pub desc: &'a [u8],
+}
+
+/// The ELF identification needed to read the program and section headers
+struct ElfLayout {
+    class: FormatClass,
+    byte_order: ByteOrder,
+}
+
+impl ElfLayout {
+    fn new(data: &[u8]) -> Option<Self> {
+        let header = read_elf(data)?;
+        Some(Self {
+            class: header.class?,
+            byte_order: header.byte_order,
+        })
+    }
+
+    fn word(&self, data: &[u8], pos: usize) -> Option<usize> {
+        match self.class {
+            FormatClass::Bits64 => {
+                let bytes = read_bytes(data, pos)?;
+                usize::try_from(match self.byte_order {
+                    ByteOrder::Little => u64::from_le_bytes(bytes),
+                    ByteOrder::Big => u64::from_be_bytes(bytes),
+                })
+                .ok()
+            }
+            _ => read_u32(data, pos, self.byte_order).map(|v| v as usize),
+        }
+    }
+
+    fn table(
+        &self,
+        data: &[u8],
+        off: usize,
+        entsize: usize,
+        num: usize,
+    ) -> Option<(usize, usize, usize)> {
+        let off = self.word(data, off)?;
+        let entsize = read_u16(data, entsize, self.byte_order)? as usize;
+        let num = read_u16(data, num, self.byte_order)? as usize;
+        Some((off, entsize, num))
+    }
+
+    /// The `(p_type, p_offset, p_filesz, p_align)` of each program header
+    fn segments(&self, data: &[u8]) -> Option<Vec<(u32, usize, usize, usize)>> {
+        let (phoff, entsize, num, off_pos, size_pos, align_pos) = match self.class {
+            FormatClass::Bits64 => {
+                let (phoff, entsize, num) = self.table(data, 32, 54, 56)?;
+                (phoff, entsize, num, 8, 32, 48)
+            }
+            _ => {
+                let (phoff, entsize, num) = self.table(data, 28, 42, 44)?;
+                (phoff, entsize, num, 4, 16, 28)
+            }
+        };
+        (0..num)
+            .map(|i| {
+                let ph = phoff.checked_add(i.checked_mul(entsize)?)?;
+                Some((
+                    read_u32(data, ph, self.byte_order)?,
+                    self.word(data, ph.checked_add(off_pos)?)?,
+                    self.word(data, ph.checked_add(size_pos)?)?,
+                    self.word(data, ph.checked_add(align_pos)?)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// The `(sh_type, sh_offset, sh_size, sh_addralign)` of each section header
+    fn sections(&self, data: &[u8]) -> Option<Vec<(u32, usize, usize, usize)>> {
+        let (shoff, entsize, num, off_pos, size_pos, align_pos) = match self.class {
+            FormatClass::Bits64 => {
+                let (shoff, entsize, num) = self.table(data, 40, 58, 60)?;
+                (shoff, entsize, num, 24, 32, 48)
+            }
+            _ => {
+                let (shoff, entsize, num) = self.table(data, 32, 46, 48)?;
+                (shoff, entsize, num, 16, 20, 32)
+            }
+        };
+        (0..num)
+            .map(|i| {
+                let sh = shoff.checked_add(i.checked_mul(entsize)?)?;
+                Some((
+                    read_u32(data, sh.checked_add(4)?, self.byte_order)?,
+                    self.word(data, sh.checked_add(off_pos)?)?,
+                    self.word(data, sh.checked_add(size_pos)?)?,
+                    self.word(data, sh.checked_add(align_pos)?)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Reads the notes in a note segment or section. Notes are padded to 8 bytes if the region is 8-byte aligned, and 4 bytes otherwise
+    fn read_notes<'a>(
+        &self,
+        data: &'a [u8],
+        region_align: usize,
+        notes: &mut Vec<ElfNote<'a>>,
+    ) -> Option<()> {
+        let mask = if region_align == 8 { 7 } else { 3 };
+        let align = |n: usize| n.checked_add(mask).map(|n| n & !mask);
+        let mut pos = 0;
+        while data.len().saturating_sub(pos) >= 12 {
+            let namesz = read_u32(data, pos, self.byte_order)? as usize;
+            let descsz = read_u32(data, pos + 4, self.byte_order)? as usize;
+            let ty = read_u32(data, pos + 8, self.byte_order)?;
+            let name_start = pos + 12;
+            let desc_start = name_start.checked_add(align(namesz)?)?;
+            let name = data.get(name_start..name_start.checked_add(namesz)?)?;
+            let desc = data.get(desc_start..desc_start.checked_add(descsz)?)?;
+            notes.push(ElfNote {
+                name: name.strip_suffix(b"\0").unwrap_or(name),
+                ty,
+                desc,
+            });
+            pos = desc_start.checked_add(align(descsz)?)?;
+        }
+        Some(())
+    }
+}
+
+/// Reads the program interpreter (`PT_INTERP`) of an ELF executable or dylib.
+///
+/// Returns [`None`] if the file is not ELF, or has no program interpreter
+pub fn elf_interpreter(data: &[u8]) -> Option<&str> {
+    let layout = ElfLayout::new(data)?;
+    let (_, off, size, _) = layout
+        .segments(data)?
+        .into_iter()
+        .find(|&(ty, ..)| ty == PT_INTERP)?;
+    let interp = data.get(off..off.checked_add(size)?)?;
+    core::str::from_utf8(interp.strip_suffix(b"\0").unwrap_or(interp)).ok()
+}
+
+/// Reads the notes of an ELF file.
+///
+/// Notes are read from the `PT_NOTE` segments if the file has program headers, and otherwise from the `SHT_NOTE` sections.
+/// Malformed notes are ignored. Returns an empty list if the file is not ELF
+pub fn elf_notes(data: &[u8]) -> Vec<ElfNote<'_>> {
+    let mut notes = Vec::new();
+    let Some(layout) = ElfLayout::new(data) else {
+        return notes;
+    };
+    let segments = layout
+        .segments(data)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|&(ty, ..)| ty == PT_NOTE)
+        .collect::<Vec<_>>();
+    let regions = if segments.is_empty() {
+        layout
+            .sections(data)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|&(ty, ..)| ty == SHT_NOTE)
+            .collect()
+    } else {
+        segments
+    };
+    for (_, off, size, align) in regions {
+        if let Some(region) = off.checked_add(size).and_then(|end| data.get(off..end)) {
+            layout.read_notes(region, align, &mut notes);
+        }
+    }
+    notes
+}
+
+/// Builds ELF executables for tests
+#[cfg(test)]
+pub(crate) struct TestElf {
+    format: BinaryFormat,
+    segments: Vec<(u32, Vec<u8>)>,
+}
+
+#[cfg(test)]
+impl TestElf {
+    /// An ELF executable for `format`, with no segments
+    pub(crate) fn new(format: &BinaryFormat) -> Self {
+        assert!(
+            matches!(format.container, Container::Elf { .. }),
+            "{format} is not ELF"
+        );
+        Self {
+            format: *format,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Sets the `EI_OSABI` byte
+    pub(crate) fn osabi(mut self, value: u8) -> Self {
+        if let Container::Elf { osabi, .. } = &mut self.format.container {
+            *osabi = value;
+        }
+        self
+    }
+
+    /// Adds a `PT_INTERP` segment naming the program interpreter
+    pub(crate) fn interp(mut self, interp: &str) -> Self {
+        let mut contents = interp.as_bytes().to_vec();
+        contents.push(0);
+        self.segments.push((PT_INTERP, contents));
+        self
+    }
+
+    /// Adds a `PT_NOTE` segment with the given `(name, type, desc)` notes
+    pub(crate) fn notes(mut self, notes: &[(&str, u32, &[u8])]) -> Self {
+        let mut contents = Vec::new();
+        for &(name, ty, desc) in notes {
+            self.put_u32(&mut contents, name.len() as u32 + 1);
+            self.put_u32(&mut contents, desc.len() as u32);
+            self.put_u32(&mut contents, ty);
+            contents.extend_from_slice(name.as_bytes());
+            contents.push(0);
+            contents.resize(contents.len().next_multiple_of(4), 0);
+            contents.extend_from_slice(desc);
+            contents.resize(contents.len().next_multiple_of(4), 0);
+        }
+        self.segments.push((PT_NOTE, contents));
+        self
+    }
+
+    fn is_64(&self) -> bool {
+        self.format.class.bits() == 64
+    }
+
+    fn put(&self, data: &mut Vec<u8>, value: u64, size: usize) {
+        let bytes = match self.format.byte_order {
+            ByteOrder::Little => value.to_le_bytes()[..size].to_vec(),
+            ByteOrder::Big => value.to_be_bytes()[8 - size..].to_vec(),
+        };
+        data.extend_from_slice(&bytes);
+    }
+
+    fn put_u16(&self, data: &mut Vec<u8>, value: u16) {
+        self.put(data, value.into(), 2);
+    }
+
+    fn put_u32(&self, data: &mut Vec<u8>, value: u32) {
+        self.put(data, value.into(), 4);
+    }
+
+    fn put_word(&self, data: &mut Vec<u8>, value: u64) {
+        self.put(data, value, if self.is_64() { 8 } else { 4 });
+    }
+
+    /// The ELF header alone, with the given program and section header tables
+    pub(crate) fn header(&self, phoff: u64, phnum: u16, shoff: u64, shnum: u16) -> Vec<u8> {
+        let Container::Elf {
+            machine,
+            osabi,
+            flags,
+        } = self.format.container
+        else {
+            unreachable!()
+        };
+        let (ehsize, phentsize, shentsize) = if self.is_64() {
+            (64, 56, 64)
+        } else {
+            (52, 32, 40)
+        };
+        let mut data = ELF_MAGIC.to_vec();
+        data.push(if self.is_64() { 2 } else { 1 });
+        data.push(match self.format.byte_order {
+            ByteOrder::Little => 1,
+            ByteOrder::Big => 2,
+        });
+        data.push(1);
+        data.push(osabi);
+        data.resize(16, 0);
+        self.put_u16(&mut data, 2);
+        self.put_u16(&mut data, machine);
+        self.put_u32(&mut data, 1);
+        self.put_word(&mut data, 0);
+        self.put_word(&mut data, phoff);
+        self.put_word(&mut data, shoff);
+        self.put_u32(&mut data, flags);
+        self.put_u16(&mut data, ehsize);
+        self.put_u16(&mut data, phentsize);
+        self.put_u16(&mut data, phnum);
+        self.put_u16(&mut data, shentsize);
+        self.put_u16(&mut data, shnum);
+        self.put_u16(&mut data, 0);
+        data
+    }
+
+    /// The contents of the file
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let (ehsize, phentsize) = if self.is_64() { (64, 56) } else { (52, 32) };
+        let phoff = if self.segments.is_empty() { 0 } else { ehsize };
+        let mut data = self.header(phoff, self.segments.len() as u16, 0, 0);
+
+        let mut offset = ehsize + phentsize * self.segments.len() as u64;
+        for (ty, contents) in &self.segments {
+            let size = contents.len() as u64;
+            self.put_u32(&mut data, *ty);
+            if self.is_64() {
+                self.put_u32(&mut data, 0);
+                self.put_word(&mut data, offset);
+                self.put_word(&mut data, 0);
+                self.put_word(&mut data, 0);
+                self.put_word(&mut data, size);
+                self.put_word(&mut data, size);
+                self.put_word(&mut data, 4);
+            } else {
+                self.put_word(&mut data, offset);
+                self.put_word(&mut data, 0);
+                self.put_word(&mut data, 0);
+                self.put_word(&mut data, size);
+                self.put_word(&mut data, size);
+                self.put_u32(&mut data, 0);
+                self.put_word(&mut data, 4);
+            }
+            offset += size;
+        }
+        for (_, contents) in &self.segments {
+            data.extend_from_slice(contents);
+        }
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::properties::binfmt::ELF64_X86_64;
+
+    /// A little-endian ELF64 x86-64 header with the given program and section header tables
+    fn elf64(phoff: u64, phnum: u16, shoff: u64, shnum: u16) -> Vec<u8> {
+        TestElf::new(&ELF64_X86_64).header(phoff, phnum, shoff, shnum)
+    }
+
+    #[test]
+    fn reads_interpreter() {
+        let data = TestElf::new(&ELF64_X86_64)
+            .interp("/lib64/ld-linux-x86-64.so.2")
+            .to_bytes();
+        assert_eq!(elf_interpreter(&data), Some("/lib64/ld-linux-x86-64.so.2"));
+    }
+
+    #[test]
+    fn reads_notes() {
+        use crate::properties::binfmt::ELF32_TRADBIGMIPS;
+
+        for format in [&ELF64_X86_64, &ELF32_TRADBIGMIPS] {
+            let data = TestElf::new(format)
+                .interp("/lib/ld.so.1")
+                .notes(&[("GNU", 1, &[0; 16]), ("FreeBSD", 1, &[1, 2, 3])])
+                .to_bytes();
+            assert_eq!(elf_interpreter(&data), Some("/lib/ld.so.1"), "{format}");
+            assert_eq!(
+                elf_notes(&data),
+                [
+                    ElfNote {
+                        name: b"GNU",
+                        ty: 1,
+                        desc: &[0; 16],
+                    },
+                    ElfNote {
+                        name: b"FreeBSD",
+                        ty: 1,
+                        desc: &[1, 2, 3],
+                    },
+                ],
+                "{format}"
+            );
+        }
+    }
+
+    #[test]
+    fn truncated_header() {
+        let data = elf64(64, 1, 0, 0);
+        for len in 0..data.len() {
+            let data = &data[..len];
+            assert_eq!(elf_interpreter(data), None);
+            assert!(elf_notes(data).is_empty());
+        }
+        assert_eq!(ObjectHeader::read(&data[..6]), None);
+    }
+
+    #[test]
+    fn huge_phoff() {
+        let data = elf64(u64::MAX, 1, 0, 0);
+        assert_eq!(elf_interpreter(&data), None);
+        assert!(elf_notes(&data).is_empty());
+
+        let data = elf64(u64::MAX - 7, 1, 0, 0);
+        assert_eq!(elf_interpreter(&data), None);
+    }
+
+    #[test]
+    fn huge_shoff() {
+        let data = elf64(0, 0, u64::MAX, 1);
+        assert!(elf_notes(&data).is_empty());
+
+        let data = elf64(0, 0, u64::MAX - 3, 1);
+        assert!(elf_notes(&data).is_empty());
+    }
+
+    #[test]
+    fn huge_phnum() {
+        let data = elf64(64, u16::MAX, 0, 0);
+        assert_eq!(elf_interpreter(&data), None);
+        assert!(elf_notes(&data).is_empty());
+
+        let data = elf64(0, 0, 64, u16::MAX);
+        assert!(elf_notes(&data).is_empty());
+    }
+
+    #[test]
+    fn truncated_pe() {
+        let mut data = vec![0; 0x40];
+        data[..2].copy_from_slice(b"MZ");
+        data[0x3c..0x40].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(ObjectHeader::read(&data), None);
+    }
+}
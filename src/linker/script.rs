@@ -0,0 +1,216 @@
+//! Generation of linker scripts from a [`MemoryLayout`]
+
+use core::fmt;
+
+use crate::properties::link::{MemoryLayout, RegionAttributes, SectionPlacement};
+
+/// Formats a [`MemoryLayout`] as a GNU ld linker script.
+///
+/// The script is produced by the [`Display`][fmt::Display] impl, and may be passed to the linker with `-T`
+#[derive(Copy, Clone, Debug)]
+pub struct GnuLdScript<'a> {
+    layout: &'a MemoryLayout,
+}
+
+impl<'a> GnuLdScript<'a> {
+    /// Creates a new [`GnuLdScript`] for `layout`
+    pub const fn new(layout: &'a MemoryLayout) -> Self {
+        Self { layout }
+    }
+}
+
+/// Writes the attributes of a region, including the parentheses.
+/// Nothing is written for empty attributes, as GNU ld rejects `()`
+fn write_attributes(f: &mut fmt::Formatter<'_>, attrs: RegionAttributes) -> fmt::Result {
+    if attrs.is_empty() {
+        return Ok(());
+    }
+    f.write_str(" (")?;
+    if attrs.contains(RegionAttributes::READ) {
+        f.write_str("r")?;
+    }
+    if attrs.contains(RegionAttributes::WRITE) {
+        f.write_str("w")?;
+    }
+    if attrs.contains(RegionAttributes::EXEC) {
+        f.write_str("x")?;
+    }
+    f.write_str(")")
+}
+
+fn write_section(f: &mut fmt::Formatter<'_>, section: &SectionPlacement) -> fmt::Result {
+    f.write_fmt(format_args!("    {}", section.name))?;
+    if let Some(addr) = section.address {
+        f.write_fmt(format_args!(" {addr:#x}"))?;
+    }
+    if section.noload {
+        f.write_str(" (NOLOAD)")?;
+    }
+    f.write_str(" :")?;
+    if let Some(addr) = section.load_address {
+        f.write_fmt(format_args!(" AT({addr:#x})"))?;
+    }
+    if let Some(align) = section.align {
+        f.write_fmt(format_args!(" ALIGN({align:#x})"))?;
+    }
+    f.write_str("\n    {\n")?;
+
+    if !section.inputs.is_empty() {
+        f.write_str("        ")?;
+        if section.keep {
+            f.write_str("KEEP(")?;
+        }
+        f.write_str("*(")?;
+        let mut sep = "";
+        for input in &section.inputs {
+            f.write_fmt(format_args!("{sep}{input}"))?;
+            sep = " ";
+        }
+        f.write_str(")")?;
+        if section.keep {
+            f.write_str(")")?;
+        }
+        f.write_str("\n")?;
+    }
+
+    f.write_str("    }")?;
+    if let Some(region) = &section.region {
+        f.write_fmt(format_args!(" > {region}"))?;
+    }
+    if let Some(region) = &section.load_region {
+        f.write_fmt(format_args!(" AT> {region}"))?;
+    }
+    f.write_str("\n")
+}
+
+impl fmt::Display for GnuLdScript<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(entry) = &self.layout.entry {
+            f.write_fmt(format_args!("ENTRY({entry})\n\n"))?;
+        }
+
+        if !self.layout.regions.is_empty() {
+            f.write_str("MEMORY\n{\n")?;
+            for region in &self.layout.regions {
+                f.write_fmt(format_args!("    {}", region.name))?;
+                write_attributes(f, region.attributes)?;
+                f.write_fmt(format_args!(
+                    " : ORIGIN = {:#x}, LENGTH = {:#x}\n",
+                    region.origin, region.length
+                ))?;
+            }
+            f.write_str("}\n\n")?;
+        }
+
+        f.write_str("SECTIONS\n{\n")?;
+        for section in &self.layout.sections {
+            write_section(f, section)?;
+        }
+        f.write_str("}\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builtin::link::{lilium::X86_64_LILIUM_KERNEL_LAYOUT, m65::NES_NROM_128_LAYOUT},
+        properties::link::MemoryRegion,
+    };
+
+    #[test]
+    fn regions() {
+        let layout = MemoryLayout {
+            entry: None,
+            regions: slice![
+                MemoryRegion {
+                    name: cowstr!("rom"),
+                    attributes: RegionAttributes::READ.union(RegionAttributes::EXEC),
+                    origin: 0x0,
+                    length: 0x4000,
+                },
+                MemoryRegion {
+                    name: cowstr!("any"),
+                    attributes: RegionAttributes::empty(),
+                    origin: 0x8000,
+                    length: 0x100,
+                },
+            ],
+            sections: slice![],
+        };
+        assert_eq!(
+            GnuLdScript::new(&layout).to_string(),
+            "MEMORY\n{\n    rom (rx) : ORIGIN = 0x0, LENGTH = 0x4000\n    any : ORIGIN = 0x8000, LENGTH = 0x100\n}\n\nSECTIONS\n{\n}\n"
+        );
+    }
+
+    #[test]
+    fn sections() {
+        let layout = MemoryLayout {
+            entry: Some(cowstr!("reset")),
+            regions: slice![],
+            sections: slice![
+                SectionPlacement {
+                    name: cowstr!(".vectors"),
+                    inputs: slice![cowstr!(".vectors")],
+                    keep: true,
+                    address: Some(0x100),
+                    ..SectionPlacement::DEFAULT
+                },
+                SectionPlacement {
+                    name: cowstr!(".data"),
+                    inputs: slice![cowstr!(".data"), cowstr!(".data.*")],
+                    align: Some(0x10),
+                    region: Some(cowstr!("ram")),
+                    load_region: Some(cowstr!("rom")),
+                    ..SectionPlacement::DEFAULT
+                },
+                SectionPlacement {
+                    name: cowstr!(".stack"),
+                    load_address: Some(0x2000),
+                    noload: true,
+                    ..SectionPlacement::DEFAULT
+                },
+            ],
+        };
+        assert_eq!(
+            GnuLdScript::new(&layout).to_string(),
+            "ENTRY(reset)\n\n\
+             SECTIONS\n{\n\
+             \x20   .vectors 0x100 :\n    {\n        KEEP(*(.vectors))\n    }\n\
+             \x20   .data : ALIGN(0x10)\n    {\n        *(.data .data.*)\n    } > ram AT> rom\n\
+             \x20   .stack (NOLOAD) : AT(0x2000)\n    {\n    }\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn kernel_layout() {
+        assert_eq!(
+            GnuLdScript::new(&X86_64_LILIUM_KERNEL_LAYOUT).to_string(),
+            "ENTRY(_start)\n\n\
+             SECTIONS\n{\n\
+             \x20   .text 0xffffffff80000000 : ALIGN(0x1000)\n    {\n        *(.text .text.*)\n    }\n\
+             \x20   .rodata : ALIGN(0x1000)\n    {\n        *(.rodata .rodata.*)\n    }\n\
+             \x20   .data : ALIGN(0x1000)\n    {\n        *(.data .data.*)\n    }\n\
+             \x20   .bss (NOLOAD) : ALIGN(0x1000)\n    {\n        *(.bss .bss.* COMMON)\n    }\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn cartridge_layout() {
+        let script = GnuLdScript::new(&NES_NROM_128_LAYOUT).to_string();
+        assert!(script.starts_with(
+            "ENTRY(_start)\n\n\
+             MEMORY\n{\n\
+             \x20   zp (rw) : ORIGIN = 0x0, LENGTH = 0x100\n\
+             \x20   ram (rw) : ORIGIN = 0x200, LENGTH = 0x600\n\
+             \x20   prg (rx) : ORIGIN = 0xc000, LENGTH = 0x3ffa\n\
+             \x20   vectors (r) : ORIGIN = 0xfffa, LENGTH = 0x6\n\
+             }\n\n"
+        ));
+        assert!(script.contains("    } > ram AT> prg\n"));
+        assert!(script.contains("        KEEP(*(.vectors))\n    } > vectors\n"));
+    }
+}
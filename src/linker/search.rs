@@ -0,0 +1,305 @@
+//! Resolution of libraries (`-l<name>`) and files against [`LibrarySearch`]
+
+use std::path::{Path, PathBuf};
+
+use crate::properties::link::LibrarySearch;
+
+/// The preference between static and dynamic libraries when resolving `-l<name>`
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Default)]
+pub enum LinkPreference {
+    /// Dynamic libraries are preferred over static libraries in the same directory (`-Bdynamic`)
+    #[default]
+    Dynamic,
+    /// Only static libraries are considered (`-Bstatic`)
+    Static,
+}
+
+/// A candidate path for a library
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LibraryCandidate {
+    /// The path to the candidate file
+    pub path: PathBuf,
+    /// Whether the candidate is a static library
+    pub is_static: bool,
+}
+
+/// Resolves library names and files against a [`LibrarySearch`]
+#[derive(Clone, Debug)]
+pub struct LibraryResolver<'a> {
+    search: &'a LibrarySearch,
+    dirs: Vec<PathBuf>,
+}
+
+impl<'a> LibraryResolver<'a> {
+    /// Creates a new resolver for the given [`LibrarySearch`], sysroot, and target stem.
+    ///
+    /// `extra_dirs` (the `-L` directories) are searched before the directories from `search`
+    pub fn new(
+        search: &'a LibrarySearch,
+        sysroot: Option<&Path>,
+        target_stem: Option<&str>,
+        extra_dirs: &[PathBuf],
+    ) -> Self {
+        let mut dirs = extra_dirs.to_vec();
+        dirs.extend(search.search_paths(sysroot, target_stem));
+        Self { search, dirs }
+    }
+
+    /// The directories searched, in order. This is the output of `-print-search-dirs` for libraries
+    pub fn search_dirs(&self) -> &[PathBuf] {
+        &self.dirs
+    }
+
+    /// Lists the candidate paths for `-l<name>`, in the order they are searched.
+    ///
+    /// If `name` starts with `:`, the remainder is treated as an exact file name (as by `-l:<file>`),
+    ///  and is considered static if it matches one of the static library suffixes.
+    ///
+    /// For each search directory, dynamic libraries are listed before static libraries, unless `pref` is [`LinkPreference::Static`],
+    ///  in which case only static libraries are listed
+    pub fn candidates(&self, name: &str, pref: LinkPreference) -> Vec<LibraryCandidate> {
+        let mut candidates = Vec::new();
+
+        if let Some(file) = name.strip_prefix(':') {
+            let is_static = self
+                .search
+                .staticlib_suffixes
+                .iter()
+                .any(|suffix| file.ends_with(&**suffix));
+            for dir in &self.dirs {
+                candidates.push(LibraryCandidate {
+                    path: dir.join(file),
+                    is_static,
+                });
+            }
+            return candidates;
+        }
+
+        for dir in &self.dirs {
+            if pref == LinkPreference::Dynamic {
+                for suffix in &self.search.dylib_suffixes {
+                    for prefix in &self.search.dylib_prefixes {
+                        candidates.push(LibraryCandidate {
+                            path: dir.join(format!("{prefix}{name}{suffix}")),
+                            is_static: false,
+                        });
+                    }
+                }
+            }
+            for suffix in &self.search.staticlib_suffixes {
+                for prefix in &self.search.staticlib_prefixes {
+                    candidates.push(LibraryCandidate {
+                        path: dir.join(format!("{prefix}{name}{suffix}")),
+                        is_static: true,
+                    });
+                }
+            }
+        }
+
+        candidates
+    }
+
+    /// Finds the first candidate for `-l<name>` for which `exists` returns `true`
+    pub fn find_library_with<F: FnMut(&Path) -> bool>(
+        &self,
+        name: &str,
+        pref: LinkPreference,
+        mut exists: F,
+    ) -> Option<LibraryCandidate> {
+        self.candidates(name, pref)
+            .into_iter()
+            .find(|candidate| exists(&candidate.path))
+    }
+
+    /// Finds `-l<name>` on the filesystem
+    pub fn find_library(&self, name: &str, pref: LinkPreference) -> Option<LibraryCandidate> {
+        self.find_library_with(name, pref, Path::is_file)
+    }
+
+    /// Finds a file (such as a startfile) in the search directories on the filesystem.
+    /// This is the behaviour of `-print-file-name`
+    pub fn find_file(&self, file: &str) -> Option<PathBuf> {
+        self.dirs
+            .iter()
+            .map(|dir| dir.join(file))
+            .find(|path| path.is_file())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{linker::test_util::TempTree, properties::link::SEARCH_UNIX_DEFAULT};
+
+    const STEM: &str = "x86_64-pc-linux-gnu";
+
+    #[test]
+    fn sysroot_joining() {
+        let search = SEARCH_UNIX_DEFAULT;
+
+        let resolver = LibraryResolver::new(&search, None, None, &[]);
+        assert_eq!(
+            resolver.search_dirs(),
+            [
+                PathBuf::from("/lib"),
+                PathBuf::from("/usr/lib"),
+                PathBuf::from("/usr/local/lib"),
+            ]
+        );
+
+        let sysroot = Path::new("/opt/sysroot");
+        let resolver = LibraryResolver::new(&search, Some(sysroot), None, &[]);
+        assert_eq!(
+            resolver.search_dirs(),
+            [
+                sysroot.join("lib"),
+                sysroot.join("usr/lib"),
+                sysroot.join("usr/local/lib"),
+            ]
+        );
+    }
+
+    #[test]
+    fn target_stem_dirs() {
+        let search = SEARCH_UNIX_DEFAULT;
+        let sysroot = Path::new("/opt/sysroot");
+        let extra = [PathBuf::from("/extra")];
+
+        let resolver = LibraryResolver::new(&search, Some(sysroot), Some(STEM), &extra);
+        assert_eq!(
+            resolver.search_dirs(),
+            [
+                PathBuf::from("/extra"),
+                sysroot.join(STEM).join("lib"),
+                sysroot.join("lib"),
+                sysroot.join("usr").join(STEM).join("lib"),
+                sysroot.join("usr/lib"),
+                sysroot.join("usr/local").join(STEM).join("lib"),
+                sysroot.join("usr/local/lib"),
+            ]
+        );
+
+        let search = LibrarySearch {
+            use_target_stem_dirs: false,
+            ..SEARCH_UNIX_DEFAULT
+        };
+        let resolver = LibraryResolver::new(&search, Some(sysroot), Some(STEM), &[]);
+        assert!(
+            resolver
+                .search_dirs()
+                .iter()
+                .all(|dir| !dir.to_string_lossy().contains(STEM))
+        );
+    }
+
+    #[test]
+    fn candidate_order() {
+        let search = SEARCH_UNIX_DEFAULT;
+        let extra = [PathBuf::from("/a"), PathBuf::from("/b")];
+        let resolver = LibraryResolver::new(&search, Some(Path::new("/sysroot")), None, &extra);
+
+        let dynamic = resolver.candidates("foo", LinkPreference::Dynamic);
+        assert_eq!(
+            &dynamic[..4],
+            [
+                LibraryCandidate {
+                    path: PathBuf::from("/a/libfoo.so"),
+                    is_static: false,
+                },
+                LibraryCandidate {
+                    path: PathBuf::from("/a/libfoo.a"),
+                    is_static: true,
+                },
+                LibraryCandidate {
+                    path: PathBuf::from("/b/libfoo.so"),
+                    is_static: false,
+                },
+                LibraryCandidate {
+                    path: PathBuf::from("/b/libfoo.a"),
+                    is_static: true,
+                },
+            ]
+        );
+        assert_eq!(dynamic.len(), 2 * resolver.search_dirs().len());
+
+        let fully_static = resolver.candidates("foo", LinkPreference::Static);
+        assert_eq!(fully_static.len(), resolver.search_dirs().len());
+        assert!(fully_static.iter().all(|candidate| candidate.is_static));
+        assert_eq!(fully_static[0].path, Path::new("/a/libfoo.a"));
+        assert_eq!(fully_static[1].path, Path::new("/b/libfoo.a"));
+    }
+
+    #[test]
+    fn exact_file_candidates() {
+        let search = SEARCH_UNIX_DEFAULT;
+        let extra = [PathBuf::from("/a")];
+        let resolver = LibraryResolver::new(&search, None, None, &extra);
+
+        let candidates = resolver.candidates(":libfoo.a", LinkPreference::Dynamic);
+        assert_eq!(candidates.len(), resolver.search_dirs().len());
+        assert_eq!(candidates[0].path, Path::new("/a/libfoo.a"));
+        assert!(candidates.iter().all(|candidate| candidate.is_static));
+
+        let candidates = resolver.candidates(":libfoo.so.1", LinkPreference::Static);
+        assert_eq!(candidates[0].path, Path::new("/a/libfoo.so.1"));
+        assert!(candidates.iter().all(|candidate| !candidate.is_static));
+    }
+
+    #[test]
+    fn find_library_in_tree() {
+        let tree = TempTree::new("search-find-library");
+        let search = SEARCH_UNIX_DEFAULT;
+        let resolver = LibraryResolver::new(&search, Some(&tree.0), Some(STEM), &[]);
+
+        let both_so = tree.touch(&format!("usr/{STEM}/lib/libboth.so"));
+        let both_a = tree.touch(&format!("usr/{STEM}/lib/libboth.a"));
+        let stem_so = tree.touch(&format!("usr/{STEM}/lib/libsplit.so"));
+        let plain_a = tree.touch("usr/lib/libsplit.a");
+        let exact = tree.touch("usr/local/lib/libexact.so.1");
+
+        let found = resolver
+            .find_library("both", LinkPreference::Dynamic)
+            .unwrap();
+        assert_eq!(found.path, both_so);
+        assert!(!found.is_static);
+        let found = resolver
+            .find_library("both", LinkPreference::Static)
+            .unwrap();
+        assert_eq!(found.path, both_a);
+        assert!(found.is_static);
+
+        let found = resolver
+            .find_library("split", LinkPreference::Dynamic)
+            .unwrap();
+        assert_eq!(found.path, stem_so);
+        let found = resolver
+            .find_library("split", LinkPreference::Static)
+            .unwrap();
+        assert_eq!(found.path, plain_a);
+
+        let found = resolver
+            .find_library(":libexact.so.1", LinkPreference::Static)
+            .unwrap();
+        assert_eq!(found.path, exact);
+
+        assert_eq!(
+            resolver.find_library("missing", LinkPreference::Dynamic),
+            None
+        );
+    }
+
+    #[test]
+    fn find_file_in_tree() {
+        let tree = TempTree::new("search-find-file");
+        let search = SEARCH_UNIX_DEFAULT;
+        let resolver = LibraryResolver::new(&search, Some(&tree.0), Some(STEM), &[]);
+
+        let crt1 = tree.touch("usr/lib/crt1.o");
+        let stem_crti = tree.touch(&format!("usr/{STEM}/lib/crti.o"));
+        tree.touch("usr/lib/crti.o");
+
+        assert_eq!(resolver.find_file("crt1.o"), Some(crt1));
+        assert_eq!(resolver.find_file("crti.o"), Some(stem_crti));
+        assert_eq!(resolver.find_file("crtn.o"), None);
+    }
+}
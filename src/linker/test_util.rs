@@ -0,0 +1,30 @@
+//! Helpers shared by the linker tests
+
+use std::{fs, path::PathBuf};
+
+/// A temporary directory tree, removed when dropped
+pub(crate) struct TempTree(pub(crate) PathBuf);
+
+impl TempTree {
+    /// Creates an empty tree, unique to `name` and the current process
+    pub(crate) fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("lccc-targets-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        Self(root)
+    }
+
+    /// Creates an empty file at `path` in the tree, along with its parent directories
+    pub(crate) fn touch(&self, path: &str) -> PathBuf {
+        let path = self.0.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, b"").unwrap();
+        path
+    }
+}
+
+impl Drop for TempTree {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
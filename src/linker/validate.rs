@@ -0,0 +1,508 @@
+//! Validation of linker inputs against the [`LinkFormat`][crate::properties::link::LinkFormat] of a target
+
+use core::fmt;
+
+use crate::{
+    linker::{
+        archive::{ARCHIVE_MAGIC, Archive, ArchiveError},
+        object::ObjectHeader,
+    },
+    properties::{
+        abi::ByteOrder,
+        binfmt::{
+            BinaryFormat, Container, EF_MIPS_ABI2, ELFOSABI_GNU, ELFOSABI_NONE, EM_NONE, FormatArch,
+        },
+        link::LinkFormat,
+        target::Target,
+    },
+};
+
+/// A way in which an input file is incompatible with the expected [`BinaryFormat`]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Mismatch {
+    /// The file uses a different container (for example, a Mach-O object in an ELF link)
+    Container {
+        /// The container of the file
+        found: &'static str,
+        /// The container expected by the target
+        expected: &'static str,
+    },
+    /// The file has a different class (for example, an `elf32-x86` object in an `elf64-x86_64` link)
+    Class {
+        /// The address width of the file, in bits
+        found: u32,
+        /// The address width expected by the target, in bits
+        expected: u32,
+    },
+    /// The file has a different byte order
+    ByteOrder {
+        /// The byte order of the file
+        found: ByteOrder,
+        /// The byte order expected by the target
+        expected: ByteOrder,
+    },
+    /// The file is for a different machine
+    Machine {
+        /// The machine of the file, as the format name if known, or the raw machine number
+        found: String,
+        /// The format expected by the target
+        expected: String,
+    },
+    /// The ELF file targets a different OS ABI (`EI_OSABI`)
+    OsAbi {
+        /// The OS ABI of the file
+        found: u8,
+        /// The OS ABI expected by the target
+        expected: u8,
+    },
+    /// The MIPS object uses a different ABI (o32/n64 versus n32)
+    MipsAbi {
+        /// Whether the file uses the n32 ABI
+        found_n32: bool,
+    },
+}
+
+fn byte_order_name(order: ByteOrder) -> &'static str {
+    match order {
+        ByteOrder::Little => "little-endian",
+        ByteOrder::Big => "big-endian",
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Container { found, expected } => f.write_fmt(format_args!(
+                "file is {found}, but the target expects {expected}"
+            )),
+            Self::Class { found, expected } => f.write_fmt(format_args!(
+                "file is {found}-bit, but the target expects {expected}-bit objects"
+            )),
+            Self::ByteOrder { found, expected } => f.write_fmt(format_args!(
+                "file is {}, but the target is {}",
+                byte_order_name(*found),
+                byte_order_name(*expected)
+            )),
+            Self::Machine { found, expected } => f.write_fmt(format_args!(
+                "file is for machine {found}, but the target expects {expected}"
+            )),
+            Self::OsAbi { found, expected } => f.write_fmt(format_args!(
+                "file has OS ABI {found}, but the target expects OS ABI {expected}"
+            )),
+            Self::MipsAbi { found_n32: true } => {
+                f.write_str("file uses the MIPS n32 ABI, but the target does not")
+            }
+            Self::MipsAbi { found_n32: false } => {
+                f.write_str("file does not use the MIPS n32 ABI, but the target does")
+            }
+        }
+    }
+}
+
+fn describe_machine(header: &ObjectHeader) -> String {
+    match (
+        header.format().and_then(|fmt| fmt.bfd_name()),
+        header.container,
+    ) {
+        (Some(name), _) => name.to_string(),
+        (
+            None,
+            Container::Elf {
+                machine: EM_NONE, ..
+            },
+        ) => "e_machine EM_NONE".to_string(),
+        (None, Container::Elf { machine, .. }) => format!("e_machine {machine:#x}"),
+        (None, Container::Coff { machine, .. }) => format!("COFF machine {machine:#x}"),
+        (None, Container::MachO { cputype, .. }) => format!("cputype {cputype:#x}"),
+        (None, container) => container.kind_name().to_string(),
+    }
+}
+
+/// Checks the header of a file against an expected [`BinaryFormat`], returning every mismatch found.
+///
+/// An ELF OS ABI of [`ELFOSABI_NONE`] is compatible with any OS ABI, and [`ELFOSABI_GNU`] is compatible with [`ELFOSABI_NONE`].
+/// COFF objects and PE images are considered the same container.
+///
+/// Architectures without an assigned ELF machine (such as [`ELF64_CLEVER`](crate::properties::binfmt::ELF64_CLEVER)) use [`EM_NONE`].
+/// A file with [`EM_NONE`] is accepted for any of them, as the header does not say which one it was built for
+pub fn check_header(header: &ObjectHeader, expected: &BinaryFormat) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+
+    let same_container = matches!(
+        (header.container, expected.container),
+        (Container::Elf { .. }, Container::Elf { .. })
+            | (Container::Coff { .. }, Container::Coff { .. })
+            | (Container::MachO { .. }, Container::MachO { .. })
+            | (Container::Wasm, Container::Wasm)
+            | (Container::O65, Container::O65)
+    );
+    if !same_container {
+        mismatches.push(Mismatch::Container {
+            found: header.container.kind_name(),
+            expected: expected.container.kind_name(),
+        });
+        return mismatches;
+    }
+
+    if let Some(class) = header.class
+        && class != expected.class
+    {
+        mismatches.push(Mismatch::Class {
+            found: class.bits(),
+            expected: expected.class.bits(),
+        });
+    }
+
+    if header.byte_order != expected.byte_order {
+        mismatches.push(Mismatch::ByteOrder {
+            found: header.byte_order,
+            expected: expected.byte_order,
+        });
+    }
+
+    let same_machine = match (header.container, expected.container) {
+        (Container::Elf { machine, .. }, Container::Elf { machine: exp, .. }) => {
+            machine == exp
+                || (exp != EM_NONE && FormatArch::from_container(&expected.container).is_none())
+        }
+        (Container::Coff { machine, .. }, Container::Coff { machine: exp, .. }) => machine == exp,
+        (Container::MachO { cputype, .. }, Container::MachO { cputype: exp, .. }) => cputype == exp,
+        _ => true,
+    };
+    if !same_machine {
+        mismatches.push(Mismatch::Machine {
+            found: describe_machine(header),
+            expected: expected.to_string(),
+        });
+    }
+
+    if let (
+        Container::Elf { osabi, flags, .. },
+        Container::Elf {
+            osabi: exp_osabi,
+            flags: exp_flags,
+            ..
+        },
+    ) = (header.container, expected.container)
+    {
+        let compatible = osabi == exp_osabi
+            || osabi == ELFOSABI_NONE
+            || (osabi == ELFOSABI_GNU && exp_osabi == ELFOSABI_NONE);
+        if !compatible {
+            mismatches.push(Mismatch::OsAbi {
+                found: osabi,
+                expected: exp_osabi,
+            });
+        }
+
+        if same_machine
+            && expected.arch == FormatArch::Mips
+            && (flags & EF_MIPS_ABI2) != (exp_flags & EF_MIPS_ABI2)
+        {
+            mismatches.push(Mismatch::MipsAbi {
+                found_n32: flags & EF_MIPS_ABI2 != 0,
+            });
+        }
+    }
+
+    mismatches
+}
+
+/// A file (or archive member) that is incompatible with the target
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct InputMismatch {
+    /// The name of the archive member, or [`None`] if the input is not an archive
+    pub member: Option<String>,
+    /// The ways in which the file is incompatible
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// An error produced when validating a linker input
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InputError {
+    /// The file is not a recognized object, executable, or archive
+    Unrecognized,
+    /// The file is an archive, but could not be read
+    Archive(ArchiveError),
+    /// The file, or one or more members of the archive, are incompatible with the target
+    Mismatch(Vec<InputMismatch>),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unrecognized => f.write_str("file format not recognized"),
+            Self::Archive(e) => e.fmt(f),
+            Self::Mismatch(inputs) => {
+                let mut sep = "";
+                for input in inputs {
+                    for mismatch in &input.mismatches {
+                        f.write_str(sep)?;
+                        if let Some(member) = &input.member {
+                            f.write_fmt(format_args!("{member}: "))?;
+                        }
+                        mismatch.fmt(f)?;
+                        sep = "\n";
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl From<ArchiveError> for InputError {
+    fn from(value: ArchiveError) -> Self {
+        Self::Archive(value)
+    }
+}
+
+/// Checks the header of a file against a [`LinkFormat`], returning every mismatch found.
+///
+/// PE images are checked against [`LinkFormat::exec_binfmt`], and all other files against [`LinkFormat::object_binfmt`]
+pub fn check_link_format(header: &ObjectHeader, format: &LinkFormat) -> Vec<Mismatch> {
+    let expected = match header.container {
+        Container::Coff { image: true, .. } => &format.exec_binfmt,
+        _ => &format.object_binfmt,
+    };
+    check_header(header, expected)
+}
+
+/// Checks that a linker input (an object, executable, dylib, or archive) is compatible with a [`LinkFormat`].
+///
+/// Objects are checked against [`LinkFormat::object_binfmt`], and PE images against [`LinkFormat::exec_binfmt`].
+/// Every member of an archive is checked, except members that are not recognized as objects (such as bitcode or text files)
+pub fn validate_input_format(data: &[u8], format: &LinkFormat) -> Result<(), InputError> {
+    if data.starts_with(ARCHIVE_MAGIC) {
+        let archive = Archive::parse(data)?;
+        let bad = archive
+            .members
+            .iter()
+            .filter_map(|member| {
+                let header = ObjectHeader::read(member.data)?;
+                let mismatches = check_link_format(&header, format);
+                (!mismatches.is_empty()).then(|| InputMismatch {
+                    member: Some(member.name.to_string()),
+                    mismatches,
+                })
+            })
+            .collect::<Vec<_>>();
+        if bad.is_empty() {
+            Ok(())
+        } else {
+            Err(InputError::Mismatch(bad))
+        }
+    } else {
+        let header = ObjectHeader::read(data).ok_or(InputError::Unrecognized)?;
+        let mismatches = check_link_format(&header, format);
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(InputError::Mismatch(vec![InputMismatch {
+                member: None,
+                mismatches,
+            }]))
+        }
+    }
+}
+
+/// Checks that a linker input (an object, executable, dylib, or archive) is compatible with `target`.
+///
+/// See [`validate_input_format`]
+pub fn validate_input(data: &[u8], target: &Target) -> Result<(), InputError> {
+    validate_input_format(data, &target.link.formats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builtin::link::x86::ELF_X86_64,
+        linker::{archive::ArchiveWriter, object::TestElf},
+        properties::{
+            binfmt::{
+                ELF32_NTRADBIGMIPS, ELF32_TRADBIGMIPS, ELF32_TRADLITTLEMIPS, ELF32_X86,
+                ELF32_X86_64, ELF64_CLEVER, ELF64_HOLEYBYTES, ELF64_X86_64, ELFOSABI_FREEBSD,
+            },
+            link::ArchiveFormat,
+        },
+    };
+
+    fn header(fmt: &BinaryFormat) -> ObjectHeader {
+        ObjectHeader {
+            container: fmt.container,
+            class: Some(fmt.class),
+            byte_order: fmt.byte_order,
+        }
+    }
+
+    #[test]
+    fn em_none_machines() {
+        assert_eq!(check_header(&header(&ELF64_CLEVER), &ELF64_CLEVER), []);
+        // Clever-ISA and HoleyBytes both use `EM_NONE`, so they cannot be told apart
+        assert_eq!(check_header(&header(&ELF64_HOLEYBYTES), &ELF64_CLEVER), []);
+
+        assert_eq!(
+            check_header(&header(&ELF64_X86_64), &ELF64_CLEVER),
+            [Mismatch::Machine {
+                found: "elf64-x86_64".to_string(),
+                expected: "elf64-clever".to_string(),
+            }]
+        );
+        assert_eq!(
+            check_header(&header(&ELF64_CLEVER), &ELF64_X86_64),
+            [Mismatch::Machine {
+                found: "e_machine EM_NONE".to_string(),
+                expected: "elf64-x86_64".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn class() {
+        assert_eq!(
+            check_header(&header(&ELF32_X86), &ELF64_X86_64),
+            [
+                Mismatch::Class {
+                    found: 32,
+                    expected: 64,
+                },
+                Mismatch::Machine {
+                    found: "elf32-x86".to_string(),
+                    expected: "elf64-x86_64".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn x32_into_lp64() {
+        assert_eq!(
+            check_header(&header(&ELF32_X86_64), &ELF64_X86_64),
+            [Mismatch::Class {
+                found: 32,
+                expected: 64,
+            }]
+        );
+        assert_eq!(
+            check_header(&header(&ELF64_X86_64), &ELF32_X86_64),
+            [Mismatch::Class {
+                found: 64,
+                expected: 32,
+            }]
+        );
+    }
+
+    #[test]
+    fn byte_order() {
+        assert_eq!(
+            check_header(&header(&ELF32_TRADBIGMIPS), &ELF32_TRADLITTLEMIPS),
+            [Mismatch::ByteOrder {
+                found: ByteOrder::Big,
+                expected: ByteOrder::Little,
+            }]
+        );
+    }
+
+    #[test]
+    fn osabi() {
+        let gnu = ELF64_X86_64.with_osabi(ELFOSABI_GNU);
+        let freebsd = ELF64_X86_64.with_osabi(ELFOSABI_FREEBSD);
+
+        // ELFOSABI_NONE objects link anywhere, and ELFOSABI_GNU objects link into ELFOSABI_NONE targets
+        assert_eq!(check_header(&header(&ELF64_X86_64), &freebsd), []);
+        assert_eq!(check_header(&header(&ELF64_X86_64), &gnu), []);
+        assert_eq!(check_header(&header(&gnu), &ELF64_X86_64), []);
+        assert_eq!(check_header(&header(&freebsd), &freebsd), []);
+
+        assert_eq!(
+            check_header(&header(&freebsd), &ELF64_X86_64),
+            [Mismatch::OsAbi {
+                found: ELFOSABI_FREEBSD,
+                expected: ELFOSABI_NONE,
+            }]
+        );
+        assert_eq!(
+            check_header(&header(&gnu), &freebsd),
+            [Mismatch::OsAbi {
+                found: ELFOSABI_GNU,
+                expected: ELFOSABI_FREEBSD,
+            }]
+        );
+    }
+
+    #[test]
+    fn mips_n32() {
+        assert_eq!(
+            check_header(&header(&ELF32_NTRADBIGMIPS), &ELF32_TRADBIGMIPS),
+            [Mismatch::MipsAbi { found_n32: true }]
+        );
+        assert_eq!(
+            check_header(&header(&ELF32_TRADBIGMIPS), &ELF32_NTRADBIGMIPS),
+            [Mismatch::MipsAbi { found_n32: false }]
+        );
+        assert_eq!(
+            check_header(&header(&ELF32_NTRADBIGMIPS), &ELF32_NTRADBIGMIPS),
+            []
+        );
+    }
+
+    #[test]
+    fn archive_members() {
+        let archive = ArchiveWriter::new(ArchiveFormat::SysV)
+            .member("good.o", TestElf::new(&ELF64_X86_64).to_bytes(), ["good"])
+            .member("i386.o", TestElf::new(&ELF32_X86).to_bytes(), ["bad"])
+            .member("README", b"not an object".to_vec(), Vec::<String>::new())
+            .to_bytes()
+            .unwrap();
+
+        let err = validate_input_format(&archive, &ELF_X86_64).unwrap_err();
+        assert_eq!(
+            err,
+            InputError::Mismatch(vec![InputMismatch {
+                member: Some("i386.o".to_string()),
+                mismatches: check_header(&header(&ELF32_X86), &ELF64_X86_64),
+            }])
+        );
+        assert_eq!(
+            err.to_string(),
+            "i386.o: file is 32-bit, but the target expects 64-bit objects\n\
+             i386.o: file is for machine elf32-x86, but the target expects elf64-x86_64"
+        );
+
+        let archive = ArchiveWriter::new(ArchiveFormat::SysV)
+            .member("good.o", TestElf::new(&ELF64_X86_64).to_bytes(), ["good"])
+            .to_bytes()
+            .unwrap();
+        assert_eq!(validate_input_format(&archive, &ELF_X86_64), Ok(()));
+    }
+
+    #[test]
+    fn input_errors() {
+        assert_eq!(
+            validate_input_format(&TestElf::new(&ELF64_X86_64).to_bytes(), &ELF_X86_64),
+            Ok(())
+        );
+        assert_eq!(
+            validate_input_format(b"not an object", &ELF_X86_64),
+            Err(InputError::Unrecognized)
+        );
+        assert_eq!(
+            InputError::Unrecognized.to_string(),
+            "file format not recognized"
+        );
+
+        let err = validate_input_format(&TestElf::new(&ELF32_TRADBIGMIPS).to_bytes(), &ELF_X86_64)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "file is 32-bit, but the target expects 64-bit objects\n\
+             file is big-endian, but the target is little-endian\n\
+             file is for machine elf32-tradbigmips, but the target expects elf64-x86_64"
+        );
+    }
+}
@@ -21,8 +21,12 @@ pub mod arch;
 
 pub mod os;
 
+pub mod env;
+
 pub mod abi;
 
+pub mod binfmt;
+
 pub mod link;
 
 pub mod target;
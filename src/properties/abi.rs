@@ -2,6 +2,8 @@
 
 use core::num::{NonZeroU8, NonZeroU16};
 
+use crate::helpers::CowStr;
+
 /// Specifies the layout of primitive types
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct PrimitiveLayouts {
@@ -227,6 +229,9 @@ pub const BE_LP32_NEAR_FAR: IntLayouts = IntLayouts {
 pub struct Abi {
     /// Describes how to override the lowering of floating-point types to lxca at ABI boundaries
     pub float_pass_override: Option<PassModeOverride>,
+    /// The width, in bits, of the widest floating-point type passed in floating-point registers if [`Abi::float_pass_override`] is [`None`].
+    /// Wider floating-point types are lowered to integers at ABI boundaries. If [`None`], all floating-point types are passed in floating-point registers
+    pub float_reg_max_width: Option<u16>,
     /// Describes how to override the lowering of vector types to lxca at ABI boundaries
     pub simd_pass_override: Option<PassModeOverride>,
 }
@@ -239,3 +244,38 @@ pub enum PassModeOverride {
     /// Lowers the type to a pointer at the abi boundary
     Memory,
 }
+
+/// The direction in which the stack grows
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum StackDirection {
+    /// The stack grows towards lower addresses
+    Down,
+    /// The stack grows towards higher addresses
+    Up,
+}
+
+/// How large stack allocations are probed, so that they cannot skip over the guard page
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum StackProbes {
+    /// Stack allocations are not probed
+    None,
+    /// Each page of a large allocation is touched by inline code
+    Inline,
+    /// A probe function is called with the size of the allocation, such as `__chkstk` on Windows.
+    /// This is the symbol name of the function in object files
+    Call(CowStr),
+}
+
+/// Properties of the call stack
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Stack {
+    /// The alignment of the stack pointer at call boundaries, in bytes
+    pub align: u16,
+    /// The size of the red zone, in bytes. This is the region beyond the stack pointer that leaf functions may use without adjusting the stack pointer,
+    ///  and which is not clobbered by signal or interrupt handlers. `0` means there is no red zone
+    pub red_zone: u16,
+    /// The direction in which the stack grows
+    pub direction: StackDirection,
+    /// How large stack allocations are probed
+    pub probes: StackProbes,
+}
@@ -15,6 +15,108 @@ pub struct TargetFeature {
     pub implies: CowSlice<CowStr>,
 }
 
+/// A width of atomic operations that the architecture can perform natively
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct NativeAtomic {
+    /// The width of the operation, in bits
+    pub width: u16,
+    /// The features which enable lock-free atomic operations of this width. Any one of these features is sufficient.
+    ///
+    /// If empty, operations of this width are always lock-free
+    pub enabled_by: CowSlice<CowStr>,
+}
+
+bitflags::bitflags! {
+    /// A set of atomic operation widths
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct AtomicWidths : u8 {
+        /// 8-bit atomic operations
+        const W8 = 0x01;
+        /// 16-bit atomic operations
+        const W16 = 0x02;
+        /// 32-bit atomic operations
+        const W32 = 0x04;
+        /// 64-bit atomic operations
+        const W64 = 0x08;
+        /// 128-bit atomic operations
+        const W128 = 0x10;
+    }
+}
+
+impl AtomicWidths {
+    /// Returns the flag for atomic operations `width` bits wide, or [`AtomicWidths::empty`] if the width is not a supported atomic width
+    pub const fn from_width(width: u16) -> Self {
+        match width {
+            8 => Self::W8,
+            16 => Self::W16,
+            32 => Self::W32,
+            64 => Self::W64,
+            128 => Self::W128,
+            _ => Self::empty(),
+        }
+    }
+}
+
+/// The atomic properties of a target, computed from its resolved features
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Atomics {
+    /// The widths of atomic operations which are performed natively (lock-free)
+    pub native: AtomicWidths,
+    /// The maximum width of a lock-free atomic operation, in bits, or 0 if the target has no lock-free atomics
+    pub max_lock_free_width: u16,
+}
+
+impl Atomics {
+    /// Atomic properties for a target with no lock-free atomic operations
+    pub const NONE: Atomics = Atomics {
+        native: AtomicWidths::empty(),
+        max_lock_free_width: 0,
+    };
+
+    /// Checks whether atomic operations `width` bits wide are lock-free
+    pub const fn is_lock_free(&self, width: u16) -> bool {
+        let flag = AtomicWidths::from_width(width);
+        !flag.is_empty() && self.native.contains(flag)
+    }
+
+    /// Checks whether atomic operations `width` bits wide must be lowered to `__atomic_*` library calls (such as those provided by libatomic)
+    pub const fn needs_libcall(&self, width: u16) -> bool {
+        !self.is_lock_free(width)
+    }
+
+    /// The value of the `__GCC_ATOMIC_*_LOCK_FREE` macro for a type `width` bits wide.
+    ///
+    /// This is `2` for types that are always lock-free and `0` otherwise.
+    /// Lock-freedom never depends on the runtime machine, so `1` ("sometimes lock-free") is never returned.
+    pub const fn gcc_lock_free(&self, width: u16) -> u8 {
+        if self.is_lock_free(width) { 2 } else { 0 }
+    }
+
+    /// The values of the `target_has_atomic` cfg, given the width of a pointer in bits.
+    ///
+    /// This includes each lock-free width (such as `"32"`), and `"ptr"` if pointer-width atomics are lock-free.
+    pub fn target_has_atomic(&self, pointer_width: u16) -> Vec<CowStr> {
+        let mut values = self
+            .native
+            .iter()
+            .filter_map(|flag| match flag {
+                AtomicWidths::W8 => Some(cowstr!("8")),
+                AtomicWidths::W16 => Some(cowstr!("16")),
+                AtomicWidths::W32 => Some(cowstr!("32")),
+                AtomicWidths::W64 => Some(cowstr!("64")),
+                AtomicWidths::W128 => Some(cowstr!("128")),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if self.is_lock_free(pointer_width) {
+            values.push(cowstr!("ptr"));
+        }
+
+        values
+    }
+}
+
 /// Architecture properties
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct Arch {
@@ -28,6 +130,10 @@ pub struct Arch {
     pub raw_width: usize,
     /// The list of target features
     pub features: CowSlice<TargetFeature>,
+    /// The widths of atomic operations that can be performed natively (lock-free), and the features needed for each.
+    ///
+    /// See [`Target::compile_atomics`][crate::properties::target::Target::compile_atomics] for the atomic properties of a particular target.
+    pub native_atomics: CowSlice<NativeAtomic>,
     /// The default target machine for the architecture
     pub default_machine: CowPtr<'static, Machine>,
     /// The list of call tags (ABI strings) supported on the target
@@ -74,3 +180,71 @@ macro_rules! arch_features {
         ];
     };
 }
+
+/// Helper macro for defining the native atomic widths of an architecture
+#[macro_export]
+macro_rules! native_atomics {
+    {
+        $(#[$meta:meta])*
+        $vis:vis static $atomics:ident = [
+            $($width:literal $(($($enabled_by:literal),* $(,)?))?),*
+            $(,)?
+        ];
+    } => {
+        $(#[$meta])*
+        $vis static $atomics: &[$crate::properties::arch::NativeAtomic] = &[
+            $(
+                $crate::properties::arch::NativeAtomic {
+                    width: $width,
+                    enabled_by: $crate::slice![$($($crate::cowstr!($enabled_by)),*)?]
+                }
+            ),*
+        ];
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn has_atomic(atomics: &Atomics, pointer_width: u16) -> Vec<String> {
+        atomics
+            .target_has_atomic(pointer_width)
+            .iter()
+            .map(|value| value.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn target_has_atomic() {
+        assert!(has_atomic(&Atomics::NONE, 32).is_empty());
+
+        let atomics = Atomics {
+            native: AtomicWidths::W8 | AtomicWidths::W16 | AtomicWidths::W32,
+            max_lock_free_width: 32,
+        };
+        assert_eq!(has_atomic(&atomics, 32), ["8", "16", "32", "ptr"]);
+        assert_eq!(has_atomic(&atomics, 64), ["8", "16", "32"]);
+
+        let atomics = Atomics {
+            native: AtomicWidths::all(),
+            max_lock_free_width: 128,
+        };
+        assert_eq!(
+            has_atomic(&atomics, 64),
+            ["8", "16", "32", "64", "128", "ptr"]
+        );
+    }
+
+    #[test]
+    fn unknown_widths_are_not_atomic() {
+        // Bits outside of the named widths are not reported as 128-bit atomics
+        let atomics = Atomics {
+            native: AtomicWidths::W32 | AtomicWidths::from_bits_retain(0x80),
+            max_lock_free_width: 32,
+        };
+        assert_eq!(has_atomic(&atomics, 64), ["32"]);
+        assert!(!atomics.is_lock_free(24));
+        assert_eq!(AtomicWidths::from_width(24), AtomicWidths::empty());
+    }
+}
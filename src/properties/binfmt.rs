@@ -0,0 +1,617 @@
+//! Descriptors for object and executable file formats.
+//!
+//! A [`BinaryFormat`] is the structured form of a BFD-style format name (such as `elf64-x86_64`), and can be parsed from and printed as that name.
+
+use core::{fmt, str::FromStr};
+
+use crate::properties::abi::ByteOrder;
+
+use ByteOrder::{Big, Little};
+use FormatClass::{Bits16, Bits32, Bits64};
+
+/// No machine (`EM_NONE`). Used for ELF architectures that do not have an `e_machine` value assigned
+pub const EM_NONE: u16 = 0;
+/// SPARC (`EM_SPARC`)
+pub const EM_SPARC: u16 = 2;
+/// Intel 80386 (`EM_386`)
+pub const EM_386: u16 = 3;
+/// Motorola 68000 (`EM_68K`)
+pub const EM_68K: u16 = 4;
+/// MIPS (`EM_MIPS`)
+pub const EM_MIPS: u16 = 8;
+/// 32-bit PowerPC (`EM_PPC`)
+pub const EM_PPC: u16 = 20;
+/// 64-bit PowerPC (`EM_PPC64`)
+pub const EM_PPC64: u16 = 21;
+/// 32-bit ARM (`EM_ARM`)
+pub const EM_ARM: u16 = 40;
+/// SPARC V9 (`EM_SPARCV9`)
+pub const EM_SPARCV9: u16 = 43;
+/// x86-64 (`EM_X86_64`)
+pub const EM_X86_64: u16 = 62;
+/// Atmel AVR (`EM_AVR`)
+pub const EM_AVR: u16 = 83;
+/// AArch64 (`EM_AARCH64`)
+pub const EM_AARCH64: u16 = 183;
+/// RISC-V (`EM_RISCV`)
+pub const EM_RISCV: u16 = 243;
+/// MOS 6502 (`EM_MOS`), as used by llvm-mos
+pub const EM_MOS: u16 = 6502;
+
+/// No OS ABI, or System V (`ELFOSABI_NONE`)
+pub const ELFOSABI_NONE: u8 = 0;
+/// NetBSD (`ELFOSABI_NETBSD`)
+pub const ELFOSABI_NETBSD: u8 = 2;
+/// GNU/Linux (`ELFOSABI_GNU`)
+pub const ELFOSABI_GNU: u8 = 3;
+/// FreeBSD (`ELFOSABI_FREEBSD`)
+pub const ELFOSABI_FREEBSD: u8 = 9;
+/// OpenBSD (`ELFOSABI_OPENBSD`)
+pub const ELFOSABI_OPENBSD: u8 = 12;
+
+/// MIPS n32 ABI (`EF_MIPS_ABI2`)
+pub const EF_MIPS_ABI2: u32 = 0x20;
+
+/// Intel 80386 (`IMAGE_FILE_MACHINE_I386`)
+pub const IMAGE_FILE_MACHINE_I386: u16 = 0x014c;
+/// x86-64 (`IMAGE_FILE_MACHINE_AMD64`)
+pub const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
+/// AArch64 (`IMAGE_FILE_MACHINE_ARM64`)
+pub const IMAGE_FILE_MACHINE_ARM64: u16 = 0xaa64;
+
+/// The bit set in a Mach-O `cputype` for 64-bit architectures (`CPU_ARCH_ABI64`)
+pub const CPU_ARCH_ABI64: u32 = 0x0100_0000;
+/// x86 (`CPU_TYPE_X86`)
+pub const CPU_TYPE_X86: u32 = 7;
+/// x86-64 (`CPU_TYPE_X86_64`)
+pub const CPU_TYPE_X86_64: u32 = CPU_TYPE_X86 | CPU_ARCH_ABI64;
+/// 32-bit ARM (`CPU_TYPE_ARM`)
+pub const CPU_TYPE_ARM: u32 = 12;
+/// AArch64 (`CPU_TYPE_ARM64`)
+pub const CPU_TYPE_ARM64: u32 = CPU_TYPE_ARM | CPU_ARCH_ABI64;
+/// All x86 processors (`CPU_SUBTYPE_X86_ALL`, also `CPU_SUBTYPE_X86_64_ALL`)
+pub const CPU_SUBTYPE_X86_ALL: u32 = 3;
+/// All AArch64 processors (`CPU_SUBTYPE_ARM64_ALL`)
+pub const CPU_SUBTYPE_ARM64_ALL: u32 = 0;
+
+/// The container of a binary format, with the container-specific machine identification
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Container {
+    /// An ELF object or executable
+    Elf {
+        /// The `e_machine` field of the ELF header
+        machine: u16,
+        /// The `EI_OSABI` byte of the ELF identification
+        osabi: u8,
+        /// The `e_flags` field of the ELF header
+        flags: u32,
+    },
+    /// A COFF object or PE image
+    Coff {
+        /// The `Machine` field of the COFF file header
+        machine: u16,
+        /// Whether this is a PE image (`pei-*`), rather than a COFF object (`pe-*`)
+        image: bool,
+    },
+    /// A Mach-O object or executable
+    MachO {
+        /// The `cputype` field of the Mach-O header
+        cputype: u32,
+        /// The `cpusubtype` field of the Mach-O header
+        cpusubtype: u32,
+    },
+    /// A WebAssembly module
+    Wasm,
+    /// An o65 relocatable object, used by 6502 toolchains
+    O65,
+}
+
+/// The size of addresses in a binary format (the ELF class for ELF)
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FormatClass {
+    /// 16-bit addresses
+    Bits16,
+    /// 32-bit addresses (`ELFCLASS32`)
+    Bits32,
+    /// 64-bit addresses (`ELFCLASS64`)
+    Bits64,
+}
+
+/// The architecture family of a binary format.
+///
+/// This distinguishes formats that share a container-level machine identification (or have none)
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatArch {
+    /// 32-bit x86
+    X86,
+    /// x86-64, including x32
+    X86_64,
+    /// 32-bit ARM
+    Arm,
+    /// AArch64
+    Aarch64,
+    /// RISC-V
+    Riscv,
+    /// Motorola 68000 series
+    M68k,
+    /// MIPS
+    Mips,
+    /// PowerPC
+    PowerPc,
+    /// SPARC
+    Sparc,
+    /// AVR
+    Avr,
+    /// Clever-ISA
+    Clever,
+    /// HoleyBytes
+    HoleyBytes,
+    /// 6502 and derivatives
+    M6502,
+    /// WebAssembly
+    Wasm,
+}
+
+/// A structured description of an object or executable format
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct BinaryFormat {
+    /// The container, with the machine identification
+    pub container: Container,
+    /// The size of addresses
+    pub class: FormatClass,
+    /// The byte order of the format
+    pub byte_order: ByteOrder,
+    /// The architecture family
+    pub arch: FormatArch,
+}
+
+const fn elf(
+    class: FormatClass,
+    byte_order: ByteOrder,
+    arch: FormatArch,
+    machine: u16,
+    flags: u32,
+) -> BinaryFormat {
+    BinaryFormat {
+        container: Container::Elf {
+            machine,
+            osabi: ELFOSABI_NONE,
+            flags,
+        },
+        class,
+        byte_order,
+        arch,
+    }
+}
+
+const fn coff(class: FormatClass, arch: FormatArch, machine: u16, image: bool) -> BinaryFormat {
+    BinaryFormat {
+        container: Container::Coff { machine, image },
+        class,
+        byte_order: ByteOrder::Little,
+        arch,
+    }
+}
+
+const fn macho(arch: FormatArch, cputype: u32, cpusubtype: u32) -> BinaryFormat {
+    BinaryFormat {
+        container: Container::MachO {
+            cputype,
+            cpusubtype,
+        },
+        class: FormatClass::Bits64,
+        byte_order: ByteOrder::Little,
+        arch,
+    }
+}
+
+/// `elf32-x86`
+pub const ELF32_X86: BinaryFormat = elf(Bits32, Little, FormatArch::X86, EM_386, 0);
+/// `elf32-x86_64` (x32)
+pub const ELF32_X86_64: BinaryFormat = elf(Bits32, Little, FormatArch::X86_64, EM_X86_64, 0);
+/// `elf64-x86_64`
+pub const ELF64_X86_64: BinaryFormat = elf(Bits64, Little, FormatArch::X86_64, EM_X86_64, 0);
+/// `elf32-littlearm`
+pub const ELF32_LITTLEARM: BinaryFormat = elf(Bits32, Little, FormatArch::Arm, EM_ARM, 0);
+/// `elf32-bigarm`
+pub const ELF32_BIGARM: BinaryFormat = elf(Bits32, Big, FormatArch::Arm, EM_ARM, 0);
+/// `elf64-littleaarch64`
+pub const ELF64_LITTLEAARCH64: BinaryFormat =
+    elf(Bits64, Little, FormatArch::Aarch64, EM_AARCH64, 0);
+/// `elf64-bigaarch64`
+pub const ELF64_BIGAARCH64: BinaryFormat = elf(Bits64, Big, FormatArch::Aarch64, EM_AARCH64, 0);
+/// `elf32-littleriscv`
+pub const ELF32_LITTLERISCV: BinaryFormat = elf(Bits32, Little, FormatArch::Riscv, EM_RISCV, 0);
+/// `elf64-littleriscv`
+pub const ELF64_LITTLERISCV: BinaryFormat = elf(Bits64, Little, FormatArch::Riscv, EM_RISCV, 0);
+/// `elf32-m68k`
+pub const ELF32_M68K: BinaryFormat = elf(Bits32, Big, FormatArch::M68k, EM_68K, 0);
+/// `elf32-tradbigmips` (o32)
+pub const ELF32_TRADBIGMIPS: BinaryFormat = elf(Bits32, Big, FormatArch::Mips, EM_MIPS, 0);
+/// `elf32-tradlittlemips` (o32)
+pub const ELF32_TRADLITTLEMIPS: BinaryFormat = elf(Bits32, Little, FormatArch::Mips, EM_MIPS, 0);
+/// `elf32-ntradbigmips` (n32)
+pub const ELF32_NTRADBIGMIPS: BinaryFormat =
+    elf(Bits32, Big, FormatArch::Mips, EM_MIPS, EF_MIPS_ABI2);
+/// `elf32-ntradlittlemips` (n32)
+pub const ELF32_NTRADLITTLEMIPS: BinaryFormat =
+    elf(Bits32, Little, FormatArch::Mips, EM_MIPS, EF_MIPS_ABI2);
+/// `elf64-tradbigmips`
+pub const ELF64_TRADBIGMIPS: BinaryFormat = elf(Bits64, Big, FormatArch::Mips, EM_MIPS, 0);
+/// `elf64-tradlittlemips`
+pub const ELF64_TRADLITTLEMIPS: BinaryFormat = elf(Bits64, Little, FormatArch::Mips, EM_MIPS, 0);
+/// `elf32-powerpc`
+pub const ELF32_POWERPC: BinaryFormat = elf(Bits32, Big, FormatArch::PowerPc, EM_PPC, 0);
+/// `elf32-powerpcle`
+pub const ELF32_POWERPCLE: BinaryFormat = elf(Bits32, Little, FormatArch::PowerPc, EM_PPC, 0);
+/// `elf64-powerpc`
+pub const ELF64_POWERPC: BinaryFormat = elf(Bits64, Big, FormatArch::PowerPc, EM_PPC64, 0);
+/// `elf64-powerpcle`
+pub const ELF64_POWERPCLE: BinaryFormat = elf(Bits64, Little, FormatArch::PowerPc, EM_PPC64, 0);
+/// `elf32-sparc`
+pub const ELF32_SPARC: BinaryFormat = elf(Bits32, Big, FormatArch::Sparc, EM_SPARC, 0);
+/// `elf32-sparcel`
+pub const ELF32_SPARCEL: BinaryFormat = elf(Bits32, Little, FormatArch::Sparc, EM_SPARC, 0);
+/// `elf64-sparc`
+pub const ELF64_SPARC: BinaryFormat = elf(Bits64, Big, FormatArch::Sparc, EM_SPARCV9, 0);
+/// `elf32-avr`
+pub const ELF32_AVR: BinaryFormat = elf(Bits32, Little, FormatArch::Avr, EM_AVR, 0);
+/// `elf64-clever`. Clever-ISA does not have an assigned `e_machine`.
+///
+/// This shares [`EM_NONE`] with [`ELF64_HOLEYBYTES`], so the two are only distinguished by [`BinaryFormat::arch`] (and by name).
+/// The architecture of a file with [`EM_NONE`] cannot be determined from its header (see [`FormatArch::from_container`])
+pub const ELF64_CLEVER: BinaryFormat = elf(Bits64, Little, FormatArch::Clever, EM_NONE, 0);
+/// `elf64-holeybytes`. HoleyBytes does not have an assigned `e_machine`, and shares [`EM_NONE`] with [`ELF64_CLEVER`]
+pub const ELF64_HOLEYBYTES: BinaryFormat = elf(Bits64, Little, FormatArch::HoleyBytes, EM_NONE, 0);
+/// `elf32-mos`
+pub const ELF32_MOS: BinaryFormat = elf(Bits32, Little, FormatArch::M6502, EM_MOS, 0);
+/// `pe-i386`
+pub const PE_I386: BinaryFormat = coff(Bits32, FormatArch::X86, IMAGE_FILE_MACHINE_I386, false);
+/// `pei-i386`
+pub const PEI_I386: BinaryFormat = coff(Bits32, FormatArch::X86, IMAGE_FILE_MACHINE_I386, true);
+/// `pe-x86-64`
+pub const PE_X86_64: BinaryFormat =
+    coff(Bits64, FormatArch::X86_64, IMAGE_FILE_MACHINE_AMD64, false);
+/// `pei-x86-64`
+pub const PEI_X86_64: BinaryFormat =
+    coff(Bits64, FormatArch::X86_64, IMAGE_FILE_MACHINE_AMD64, true);
+/// `pe-aarch64-little`
+pub const PE_AARCH64: BinaryFormat =
+    coff(Bits64, FormatArch::Aarch64, IMAGE_FILE_MACHINE_ARM64, false);
+/// `pei-aarch64-little`
+pub const PEI_AARCH64: BinaryFormat =
+    coff(Bits64, FormatArch::Aarch64, IMAGE_FILE_MACHINE_ARM64, true);
+/// `mach-o-x86-64`
+pub const MACHO_X86_64: BinaryFormat =
+    macho(FormatArch::X86_64, CPU_TYPE_X86_64, CPU_SUBTYPE_X86_ALL);
+/// `mach-o-arm64`
+pub const MACHO_ARM64: BinaryFormat =
+    macho(FormatArch::Aarch64, CPU_TYPE_ARM64, CPU_SUBTYPE_ARM64_ALL);
+/// `wasm32`
+pub const WASM32: BinaryFormat = BinaryFormat {
+    container: Container::Wasm,
+    class: Bits32,
+    byte_order: Little,
+    arch: FormatArch::Wasm,
+};
+/// `wasm64`
+pub const WASM64: BinaryFormat = BinaryFormat {
+    class: Bits64,
+    ..WASM32
+};
+/// `o65`
+pub const O65: BinaryFormat = BinaryFormat {
+    container: Container::O65,
+    class: Bits16,
+    byte_order: Little,
+    arch: FormatArch::M6502,
+};
+/// `o65-32`, the o65 format with 32-bit sizes and offsets
+pub const O65_32: BinaryFormat = BinaryFormat {
+    class: Bits32,
+    ..O65
+};
+
+/// Known formats, by name. The first name for a format is its canonical name
+static KNOWN_FORMATS: &[(&str, BinaryFormat)] = &[
+    ("elf32-x86", ELF32_X86),
+    ("elf32-i386", ELF32_X86),
+    ("elf32-x86_64", ELF32_X86_64),
+    ("elf32-x86-64", ELF32_X86_64),
+    ("elf64-x86_64", ELF64_X86_64),
+    ("elf64-x86-64", ELF64_X86_64),
+    ("elf32-littlearm", ELF32_LITTLEARM),
+    ("elf32-bigarm", ELF32_BIGARM),
+    ("elf64-littleaarch64", ELF64_LITTLEAARCH64),
+    ("elf64-bigaarch64", ELF64_BIGAARCH64),
+    ("elf32-littleriscv", ELF32_LITTLERISCV),
+    ("elf64-littleriscv", ELF64_LITTLERISCV),
+    ("elf32-m68k", ELF32_M68K),
+    ("elf32-tradbigmips", ELF32_TRADBIGMIPS),
+    ("elf32-tradlittlemips", ELF32_TRADLITTLEMIPS),
+    ("elf32-ntradbigmips", ELF32_NTRADBIGMIPS),
+    ("elf32-ntradlittlemips", ELF32_NTRADLITTLEMIPS),
+    ("elf64-tradbigmips", ELF64_TRADBIGMIPS),
+    ("elf64-tradlittlemips", ELF64_TRADLITTLEMIPS),
+    ("elf32-powerpc", ELF32_POWERPC),
+    ("elf32-powerpcle", ELF32_POWERPCLE),
+    ("elf64-powerpc", ELF64_POWERPC),
+    ("elf64-powerpcle", ELF64_POWERPCLE),
+    ("elf32-sparc", ELF32_SPARC),
+    ("elf32-sparcel", ELF32_SPARCEL),
+    ("elf64-sparc", ELF64_SPARC),
+    ("elf32-avr", ELF32_AVR),
+    ("elf64-clever", ELF64_CLEVER),
+    ("elf64-holeybytes", ELF64_HOLEYBYTES),
+    ("elf32-mos", ELF32_MOS),
+    ("pe-i386", PE_I386),
+    ("pei-i386", PEI_I386),
+    ("pe-x86-64", PE_X86_64),
+    ("pei-x86-64", PEI_X86_64),
+    ("pe-aarch64-little", PE_AARCH64),
+    ("pei-aarch64-little", PEI_AARCH64),
+    ("mach-o-x86-64", MACHO_X86_64),
+    ("mach-o-arm64", MACHO_ARM64),
+    ("wasm32", WASM32),
+    ("wasm64", WASM64),
+    ("o65", O65),
+    ("o65-32", O65_32),
+];
+
+impl BinaryFormat {
+    /// Returns the format with the OS ABI and any flags not reflected in the format name cleared
+    const fn name_key(self) -> BinaryFormat {
+        match self.container {
+            Container::Elf { machine, flags, .. } => BinaryFormat {
+                container: Container::Elf {
+                    machine,
+                    osabi: ELFOSABI_NONE,
+                    flags: if matches!(self.arch, FormatArch::Mips) {
+                        flags & EF_MIPS_ABI2
+                    } else {
+                        0
+                    },
+                },
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    /// The BFD-style name of the format, such as `elf64-x86_64`.
+    ///
+    /// The ELF OS ABI and flags are not part of the name, except for the MIPS n32 ABI flag.
+    /// Returns [`None`] if the format does not correspond to a known name
+    pub fn bfd_name(&self) -> Option<&'static str> {
+        let key = self.name_key();
+        KNOWN_FORMATS
+            .iter()
+            .find(|(_, fmt)| *fmt == key)
+            .map(|(name, _)| *name)
+    }
+
+    /// Looks up a format by its BFD-style name. The ELF OS ABI of the result is [`ELFOSABI_NONE`]
+    pub fn from_bfd_name(name: &str) -> Option<BinaryFormat> {
+        KNOWN_FORMATS
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, fmt)| *fmt)
+    }
+
+    /// Returns the format with the ELF `EI_OSABI` set to `osabi`. Formats other than ELF are returned unchanged
+    pub const fn with_osabi(self, osabi: u8) -> BinaryFormat {
+        match self.container {
+            Container::Elf { machine, flags, .. } => BinaryFormat {
+                container: Container::Elf {
+                    machine,
+                    osabi,
+                    flags,
+                },
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    /// Returns the format with the ELF `e_flags` set to `flags`. Formats other than ELF are returned unchanged
+    pub const fn with_elf_flags(self, flags: u32) -> BinaryFormat {
+        match self.container {
+            Container::Elf { machine, osabi, .. } => BinaryFormat {
+                container: Container::Elf {
+                    machine,
+                    osabi,
+                    flags,
+                },
+                ..self
+            },
+            _ => self,
+        }
+    }
+}
+
+impl FormatArch {
+    /// Determines the architecture family from the machine identification of a container.
+    ///
+    /// Returns [`None`] if the machine is not known, or is [`EM_NONE`] for an ELF container.
+    /// [`EM_NONE`] is used by more than one architecture ([`ELF64_CLEVER`] and [`ELF64_HOLEYBYTES`]), so it does not identify one
+    pub fn from_container(container: &Container) -> Option<FormatArch> {
+        match container {
+            Container::Wasm => Some(FormatArch::Wasm),
+            Container::O65 => Some(FormatArch::M6502),
+            Container::Elf {
+                machine: EM_NONE, ..
+            } => None,
+            Container::Elf { machine, .. } => KNOWN_FORMATS.iter().find_map(|(_, fmt)| {
+                matches!(fmt.container, Container::Elf { machine: m, .. } if m == *machine)
+                    .then_some(fmt.arch)
+            }),
+            Container::Coff { machine, .. } => KNOWN_FORMATS.iter().find_map(|(_, fmt)| {
+                matches!(fmt.container, Container::Coff { machine: m, .. } if m == *machine)
+                    .then_some(fmt.arch)
+            }),
+            Container::MachO { cputype, .. } => KNOWN_FORMATS.iter().find_map(|(_, fmt)| {
+                matches!(fmt.container, Container::MachO { cputype: c, .. } if c == *cputype)
+                    .then_some(fmt.arch)
+            }),
+        }
+    }
+}
+
+impl Container {
+    /// The name of the container kind, such as `ELF` or `Mach-O`
+    pub const fn kind_name(&self) -> &'static str {
+        match self {
+            Container::Elf { .. } => "ELF",
+            Container::Coff { image: false, .. } => "COFF",
+            Container::Coff { image: true, .. } => "PE",
+            Container::MachO { .. } => "Mach-O",
+            Container::Wasm => "wasm",
+            Container::O65 => "o65",
+        }
+    }
+}
+
+impl FormatClass {
+    /// The width of addresses, in bits
+    pub const fn bits(self) -> u32 {
+        match self {
+            FormatClass::Bits16 => 16,
+            FormatClass::Bits32 => 32,
+            FormatClass::Bits64 => 64,
+        }
+    }
+}
+
+impl fmt::Display for BinaryFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.bfd_name() {
+            return f.write_str(name);
+        }
+        let bits = self.class.bits();
+        match self.container {
+            Container::Elf { machine, .. } => {
+                f.write_fmt(format_args!("elf{bits}-unknown-{machine:#x}"))
+            }
+            Container::Coff { machine, image } => f.write_fmt(format_args!(
+                "{}-unknown-{machine:#x}",
+                if image { "pei" } else { "pe" }
+            )),
+            Container::MachO { cputype, .. } => {
+                f.write_fmt(format_args!("mach-o-unknown-{cputype:#x}"))
+            }
+            Container::Wasm => f.write_fmt(format_args!("wasm{bits}")),
+            Container::O65 => f.write_fmt(format_args!("o65-{bits}")),
+        }
+    }
+}
+
+/// An error returned when parsing an unknown [`BinaryFormat`] name
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct UnknownBinaryFormat(pub String);
+
+impl fmt::Display for UnknownBinaryFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("unknown binary format {}", self.0))
+    }
+}
+
+impl std::error::Error for UnknownBinaryFormat {}
+
+impl FromStr for BinaryFormat {
+    type Err = UnknownBinaryFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bfd_name(s).ok_or_else(|| UnknownBinaryFormat(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bfd_name_round_trip() {
+        for &(name, fmt) in KNOWN_FORMATS {
+            assert_eq!(BinaryFormat::from_bfd_name(name), Some(fmt), "{name}");
+            assert_eq!(name.parse::<BinaryFormat>(), Ok(fmt), "{name}");
+
+            let canonical = fmt.bfd_name().unwrap();
+            assert_eq!(BinaryFormat::from_bfd_name(canonical), Some(fmt), "{name}");
+            assert_eq!(fmt.to_string(), canonical);
+        }
+    }
+
+    #[test]
+    fn canonical_names() {
+        assert_eq!(ELF32_X86.bfd_name(), Some("elf32-x86"));
+        assert_eq!(
+            BinaryFormat::from_bfd_name("elf64-x86-64"),
+            Some(ELF64_X86_64)
+        );
+        assert_eq!(ELF64_X86_64.bfd_name(), Some("elf64-x86_64"));
+        assert_eq!(
+            "not-a-format".parse::<BinaryFormat>(),
+            Err(UnknownBinaryFormat("not-a-format".to_string()))
+        );
+    }
+
+    #[test]
+    fn name_ignores_osabi_and_flags() {
+        assert_eq!(
+            ELF64_X86_64.with_osabi(ELFOSABI_FREEBSD).bfd_name(),
+            Some("elf64-x86_64")
+        );
+        assert_eq!(
+            ELF32_LITTLEARM.with_elf_flags(0x0500_0000).bfd_name(),
+            Some("elf32-littlearm")
+        );
+        assert_eq!(
+            ELF32_TRADBIGMIPS.with_elf_flags(EF_MIPS_ABI2).bfd_name(),
+            Some("elf32-ntradbigmips")
+        );
+    }
+
+    #[test]
+    fn em_none_formats() {
+        assert_ne!(ELF64_CLEVER, ELF64_HOLEYBYTES);
+        assert_eq!(ELF64_CLEVER.bfd_name(), Some("elf64-clever"));
+        assert_eq!(ELF64_HOLEYBYTES.bfd_name(), Some("elf64-holeybytes"));
+        assert_eq!(FormatArch::from_container(&ELF64_CLEVER.container), None);
+        assert_eq!(
+            FormatArch::from_container(&ELF64_HOLEYBYTES.container),
+            None
+        );
+    }
+
+    #[test]
+    fn arch_from_container() {
+        for &(name, fmt) in KNOWN_FORMATS {
+            if let Some(arch) = FormatArch::from_container(&fmt.container) {
+                assert_eq!(arch, fmt.arch, "{name}");
+            }
+        }
+        assert_eq!(
+            FormatArch::from_container(&Container::Elf {
+                machine: 0xffff,
+                osabi: ELFOSABI_NONE,
+                flags: 0,
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn unknown_format_display() {
+        let fmt = BinaryFormat {
+            container: Container::Elf {
+                machine: 0x1234,
+                osabi: ELFOSABI_NONE,
+                flags: 0,
+            },
+            ..ELF64_X86_64
+        };
+        assert_eq!(fmt.bfd_name(), None);
+        assert_eq!(fmt.to_string(), "elf64-unknown-0x1234");
+    }
+}
@@ -0,0 +1,64 @@
+//! Environment properties, such as the C library in use
+
+use crate::properties::{CowSlice, CowStr, ExtPropertyValue, link::OutputKind};
+
+/// Properties about the environment (usually the C library)
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Env {
+    /// The canonical name of the environment
+    pub name: CowStr,
+    /// The name of the C library, or [`None`] if no C library is provided by the environment
+    pub libc_name: Option<CowStr>,
+    /// The default threading model
+    pub threading_model: ThreadingModel,
+    /// Whether or not the (Rust or C++) standard library is available in the environment.
+    /// If this is false, only the freestanding subset (`core`/`alloc` or the freestanding C++ headers) is available
+    pub has_std: bool,
+    /// Libraries required by the C library.
+    /// [`Target::default_link_files`][crate::properties::target::Target::default_link_files] adds these after [`DefaultLinking::libraries`][crate::properties::link::DefaultLinking::libraries]
+    pub link_libraries: EnvLibraries,
+
+    /// Extended properties set by the environment
+    pub env_extended_properties: CowSlice<(CowStr, ExtPropertyValue)>,
+}
+
+/// The threading model of an environment
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ThreadingModel {
+    /// No threads are available
+    Single,
+    /// POSIX Threads (`pthread_create`)
+    Posix,
+    /// Win32 Threads (`CreateThread`)
+    Win32,
+    /// Threads provided by the OS using an interface that is neither POSIX nor Win32
+    Native,
+}
+
+/// The libraries required by the C library, which depend on whether the output is statically linked
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct EnvLibraries {
+    /// The libraries used for [`OutputKind::Exe`], [`OutputKind::Pie`], and [`OutputKind::Dylib`]
+    pub dynamic: CowSlice<CowStr>,
+    /// The libraries used for [`OutputKind::StaticExe`] and [`OutputKind::StaticPie`].
+    /// Shared-only libraries (such as `libgcc_s`) must not appear here
+    pub static_exe: CowSlice<CowStr>,
+}
+
+impl EnvLibraries {
+    /// No libraries for any output kind
+    pub const NONE: EnvLibraries = EnvLibraries {
+        dynamic: slice![],
+        static_exe: slice![],
+    };
+
+    /// Obtains the list of libraries for the specified [`OutputKind`]
+    pub fn for_kind(&self, kind: OutputKind) -> &[CowStr] {
+        if kind.is_static() {
+            &self.static_exe
+        } else {
+            &self.dynamic
+        }
+    }
+}
@@ -1,6 +1,11 @@
 //! Properties for the link step, such as how to produce object files, and how to invoke the linker
 
-use crate::helpers::{CowPtr, CowSlice, CowStr};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    helpers::{CowPtr, CowSlice, CowStr},
+    properties::binfmt::BinaryFormat,
+};
 
 bitflags::bitflags! {
     /// Supported types for artifact files
@@ -11,15 +16,18 @@ bitflags::bitflags! {
         const EXE = 0x01;
         /// Dynamically linked libraries are supported
         const DYLIB = 0x02;
-        /// Position independant code is supported.
+        /// Position independent code is supported.
         /// This should be true wherever dylibs are supported
         const PIC = 0x04;
-        /// Position Independant Executables are supported.
+        /// Position Independent Executables are supported.
         const PIE = 0x08;
-        /// The default executable type is Position Independant.
+        /// The default executable type is Position Independent.
         const DEFAULT_PIE = 0x10;
-        /// Position Independant Executables are supported without a dynamic linker
+        /// Position Independent Executables are supported without a dynamic linker
         const STATIC_PIE = 0x20;
+        /// Executables are dynamically linked by default.
+        /// If this is not set, executables are statically linked by default (as static-pie if [`SupportedArtifacts::DEFAULT_PIE`] is set)
+        const DEFAULT_DYNAMIC = 0x40;
     }
 }
 
@@ -30,6 +38,9 @@ impl SupportedArtifacts {
         .union(SupportedArtifacts::PIC)
         .union(SupportedArtifacts::PIE)
         .union(SupportedArtifacts::STATIC_PIE);
+    /// Default set of [`SupportedArtifacts`] where executables are static-pie by default
+    pub const DEFAULT_STATIC_PIE: SupportedArtifacts =
+        SupportedArtifacts::all().difference(SupportedArtifacts::DEFAULT_DYNAMIC);
     /// Default set of [`SupportedArtifacts`] where a dynamic linker is not available
     pub const NO_DYNLINKER: SupportedArtifacts =
         SupportedArtifacts::EXE.union(SupportedArtifacts::STATIC_PIE);
@@ -48,37 +59,248 @@ pub struct Link {
     /// Controls what is needed to make the stack non-executable
     pub nx_stack: NxStackMode,
 
+    /// The supported code models
+    pub code_models: CowPtr<'static, CodeModels>,
+
+    /// The default hardening of generated code and linked outputs
+    pub hardening: CowPtr<'static, Hardening>,
+
     /// The name of the dynamic linker/program interpreter for elf platforms
     pub dynlinker_name: Option<CowStr>,
 
+    /// How dynamic libraries are named, and how runtime search paths are recorded
+    pub dylib_policy: CowPtr<'static, DylibPolicy>,
+
     /// The default libraries on the platform
     pub default_libraries: Option<CowPtr<'static, DefaultLinking>>,
+
+    /// The default memory layout of the output, for targets that require a fixed layout (such as kernels and bare-metal images).
+    /// If this is [`None`], the linker's builtin layout is used
+    pub memory_layout: Option<CowPtr<'static, MemoryLayout>>,
 }
 
 /// Default libraries/startfiles behaviour
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct DefaultLinking {
     /// The list of startfile objects that appear on the link line first if `-nostartfiles` is not provided
-    pub start_files: CowSlice<CowStr>,
+    pub start_files: StartupFiles,
     /// The list of endfile objects that appear on the link line last if `-nostartfiles` is not provided
-    pub end_files: CowSlice<CowStr>,
+    pub end_files: StartupFiles,
     /// The list of libraries that are added to the link line after all inputs if `-nostdlib` is not provided
     pub libraries: CowSlice<CowStr>,
+    /// The list of libraries used in place of [`DefaultLinking::libraries`] for statically linked outputs
+    /// ([`OutputKind::StaticExe`] and [`OutputKind::StaticPie`]), or [`None`] if they are the same
+    pub static_libraries: Option<CowSlice<CowStr>>,
+}
+
+impl DefaultLinking {
+    /// Obtains the list of libraries for the specified [`OutputKind`]
+    pub fn libraries_for(&self, kind: OutputKind) -> &[CowStr] {
+        match &self.static_libraries {
+            Some(libraries) if kind.is_static() => libraries,
+            _ => &self.libraries,
+        }
+    }
+}
+
+/// A list of startfile or endfile objects for each [`OutputKind`]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct StartupFiles {
+    /// The objects used for [`OutputKind::Exe`]
+    pub exe: CowSlice<CowStr>,
+    /// The objects used for [`OutputKind::Pie`]
+    pub pie: CowSlice<CowStr>,
+    /// The objects used for [`OutputKind::StaticExe`]
+    pub static_exe: CowSlice<CowStr>,
+    /// The objects used for [`OutputKind::StaticPie`]
+    pub static_pie: CowSlice<CowStr>,
+    /// The objects used for [`OutputKind::Dylib`]
+    pub dylib: CowSlice<CowStr>,
+}
+
+impl StartupFiles {
+    /// No startfile or endfile objects for any output kind
+    pub const NONE: StartupFiles = StartupFiles {
+        exe: slice![],
+        pie: slice![],
+        static_exe: slice![],
+        static_pie: slice![],
+        dylib: slice![],
+    };
+
+    /// Obtains the list of objects for the specified [`OutputKind`]
+    pub fn for_kind(&self, kind: OutputKind) -> &[CowStr] {
+        match kind {
+            OutputKind::Exe => &self.exe,
+            OutputKind::Pie => &self.pie,
+            OutputKind::StaticExe => &self.static_exe,
+            OutputKind::StaticPie => &self.static_pie,
+            OutputKind::Dylib => &self.dylib,
+        }
+    }
+}
+
+/// The kind of linked output
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum OutputKind {
+    /// A dynamically linked, position dependent executable
+    Exe,
+    /// A dynamically linked, position independent executable
+    Pie,
+    /// A statically linked, position dependent executable
+    StaticExe,
+    /// A statically linked, position independent executable that relocates itself
+    StaticPie,
+    /// A dynamic library
+    Dylib,
+}
+
+impl OutputKind {
+    /// The [`SupportedArtifacts`] that must be present to produce this kind of output.
+    /// Dynamically linked executables require [`SupportedArtifacts::DYLIB`]
+    pub const fn required_artifacts(self) -> SupportedArtifacts {
+        match self {
+            OutputKind::Exe => SupportedArtifacts::EXE.union(SupportedArtifacts::DYLIB),
+            OutputKind::Pie => SupportedArtifacts::EXE
+                .union(SupportedArtifacts::DYLIB)
+                .union(SupportedArtifacts::PIE),
+            OutputKind::StaticExe => SupportedArtifacts::EXE,
+            OutputKind::StaticPie => SupportedArtifacts::EXE.union(SupportedArtifacts::STATIC_PIE),
+            OutputKind::Dylib => SupportedArtifacts::DYLIB,
+        }
+    }
+
+    /// Whether or not this kind of output is an executable
+    pub const fn is_exe(self) -> bool {
+        !matches!(self, OutputKind::Dylib)
+    }
+
+    /// Whether or not this kind of output is statically linked
+    pub const fn is_static(self) -> bool {
+        matches!(self, OutputKind::StaticExe | OutputKind::StaticPie)
+    }
+
+    /// The kind of executable produced when no options are passed, given the [`SupportedArtifacts`] of the target
+    pub const fn default_exe(artifacts: SupportedArtifacts) -> OutputKind {
+        match (
+            artifacts.contains(SupportedArtifacts::DEFAULT_PIE),
+            artifacts.contains(SupportedArtifacts::DEFAULT_DYNAMIC),
+        ) {
+            (true, true) => OutputKind::Pie,
+            (true, false) => OutputKind::StaticPie,
+            (false, true) => OutputKind::Exe,
+            (false, false) => OutputKind::StaticExe,
+        }
+    }
+}
+
+/// The default objects and libraries added to a link, in link line order
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct DefaultLinkFiles<'a> {
+    /// Startfile objects, which appear before all inputs
+    pub start_files: &'a [CowStr],
+    /// Libraries, which appear after all inputs
+    pub libraries: &'a [CowStr],
+    /// Libraries required by the environment, which appear after [`DefaultLinkFiles::libraries`].
+    /// See [`Env::link_libraries`][crate::properties::env::Env::link_libraries]
+    pub env_libraries: &'a [CowStr],
+    /// Endfile objects, which appear after all libraries
+    pub end_files: &'a [CowStr],
+}
+
+impl Link {
+    /// Resolves the default startfiles, libraries, and endfiles for the specified [`OutputKind`].
+    /// The environment libraries are not included; see [`Target::default_link_files`][crate::properties::target::Target::default_link_files].
+    ///
+    /// Returns [`None`] if the [`OutputKind`] is not supported by the target's [`SupportedArtifacts`]
+    pub fn default_link_files(&self, kind: OutputKind) -> Option<DefaultLinkFiles<'_>> {
+        if !self
+            .formats
+            .supported_artifacts
+            .contains(kind.required_artifacts())
+        {
+            return None;
+        }
+
+        Some(match &self.default_libraries {
+            Some(defaults) => DefaultLinkFiles {
+                start_files: defaults.start_files.for_kind(kind),
+                libraries: defaults.libraries_for(kind),
+                env_libraries: &[],
+                end_files: defaults.end_files.for_kind(kind),
+            },
+            None => DefaultLinkFiles {
+                start_files: &[],
+                libraries: &[],
+                env_libraries: &[],
+                end_files: &[],
+            },
+        })
+    }
+
+    /// Checks that `model` is one of the target's [`CodeModels::supported`]
+    pub fn check_code_model(&self, model: CodeModel) -> Result<(), ModelError> {
+        if self.code_models.supported.contains(&model) {
+            Ok(())
+        } else {
+            Err(ModelError::UnsupportedCodeModel(model))
+        }
+    }
+
+    /// Checks that the code models and relocation models of the target are consistent with each other and with [`LinkFormat::supported_artifacts`]
+    pub fn validate_models(&self) -> Result<(), ModelError> {
+        self.check_code_model(self.code_models.default)?;
+        self.formats.validate_reloc_models()
+    }
+
+    /// Computes the default soname (or install name) of a dynamic library built for the target.
+    ///
+    /// See [`DylibPolicy::soname`]
+    pub fn soname(&self, stem: &str, version: Option<&str>) -> Option<String> {
+        self.dylib_policy
+            .soname(&self.output_filename, stem, version)
+    }
 }
 
 /// The format of link outputs
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct LinkFormat {
-    /// The binary format (format-arch) for object files
-    pub object_binfmt: CowStr,
-    /// The binary format (format-arch) for executables, dylibs, and [`StaticLibraryFormat::Object`] static libs
-    pub exec_binfmt: CowStr,
+    /// The binary format for object files
+    pub object_binfmt: BinaryFormat,
+    /// The binary format for executables, dylibs, and [`StaticLibraryFormat::Object`] static libs
+    pub exec_binfmt: BinaryFormat,
     /// The format of static libraries. Most static libraries use the ar archive format of various flavours.
     pub staticlib_format: StaticLibraryFormat,
     /// Supported Artifact kinds
     pub supported_artifacts: SupportedArtifacts,
+    /// The supported relocation models, which must be consistent with [`LinkFormat::supported_artifacts`]
+    pub reloc_models: CowPtr<'static, RelocModels>,
     /// The default flavour for the linker executable (ld, link, etc.)
     pub default_linker_format: LinkerFlavour,
+    /// The prefix prepended to C symbol names in object files (for example, `_` on Mach-O)
+    pub symbol_prefix: CowStr,
+}
+
+impl LinkFormat {
+    /// Checks that `model` is one of the format's [`RelocModels::supported`], and that it is usable with [`LinkFormat::supported_artifacts`]
+    pub fn check_reloc_model(&self, model: RelocModel) -> Result<(), ModelError> {
+        if !self.reloc_models.supported.contains(&model) {
+            Err(ModelError::UnsupportedRelocModel(model))
+        } else if !model.is_supported_by(self.supported_artifacts) {
+            Err(ModelError::MissingArtifacts(model))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that the default and every supported relocation model are usable with [`LinkFormat::supported_artifacts`]
+    pub fn validate_reloc_models(&self) -> Result<(), ModelError> {
+        self.check_reloc_model(self.reloc_models.default)?;
+        self.reloc_models
+            .supported
+            .iter()
+            .try_for_each(|&model| self.check_reloc_model(model))
+    }
 }
 
 /// Output Filenames
@@ -100,6 +322,216 @@ pub struct FileNames {
     pub staticlib_prefix: CowStr,
     /// The suffix to append to the default filename for static libraries
     pub staticlib_suffix: CowStr,
+    /// The prefix to prepend to the default filename for import libraries
+    pub implib_prefix: CowStr,
+    /// The suffix to append to the default filename for import libraries.
+    /// This is empty if the platform does not use import libraries to link against dynamic libraries
+    pub implib_suffix: CowStr,
+    /// Where the version is placed in the filename of a versioned dynamic library
+    pub dylib_version: VersionPlacement,
+    /// The suffix used for separate debug info files. This is empty if separate debug info is not supported
+    pub debuginfo_suffix: CowStr,
+    /// How the name of separate debug info files is derived from the name of the artifact
+    pub debuginfo_placement: DebugInfoPlacement,
+    /// The suffix that replaces [`FileNames::obj_suffix`] for split DWARF files (`.dwo`).
+    /// This is empty if split DWARF is not supported
+    pub split_dwarf_suffix: CowStr,
+}
+
+/// Where the version is placed in the filename of a versioned dynamic library
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum VersionPlacement {
+    /// Versioned dynamic library names are not supported
+    Unsupported,
+    /// The version follows the suffix (`libfoo.so.1.2.3`)
+    AfterSuffix,
+    /// The version precedes the suffix (`libfoo.1.2.3.dylib`)
+    BeforeSuffix,
+}
+
+/// How the name of a separate debug info file is derived
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum DebugInfoPlacement {
+    /// Separate debug info is not supported
+    Unsupported,
+    /// The suffix (and version) of the artifact is replaced by the debug info suffix (`foo.exe` to `foo.pdb`)
+    ReplaceSuffix,
+    /// The debug info suffix is appended to the complete artifact name (`libfoo.so` to `libfoo.so.debug`)
+    AppendSuffix,
+}
+
+/// The kind of artifact named by [`FileNames`]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ArtifactKind {
+    /// An object file
+    Object,
+    /// An executable
+    Exe,
+    /// A dynamic library
+    Dylib,
+    /// A static library
+    StaticLib,
+    /// An import library for a dynamic library
+    ImportLib,
+}
+
+/// The result of [`FileNames::parse_name`]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ParsedName<'a> {
+    /// The kind of the artifact
+    pub kind: ArtifactKind,
+    /// The stem of the artifact name, without prefix, suffix, or version
+    pub stem: &'a str,
+    /// The version of a versioned dynamic library
+    pub version: Option<&'a str>,
+}
+
+impl FileNames {
+    /// Obtains the prefix and suffix for the given [`ArtifactKind`]
+    pub fn affixes(&self, kind: ArtifactKind) -> (&str, &str) {
+        match kind {
+            ArtifactKind::Object => (&self.obj_prefix, &self.obj_suffix),
+            ArtifactKind::Exe => (&self.exe_prefix, &self.exe_suffix),
+            ArtifactKind::Dylib => (&self.dylib_prefix, &self.dylib_suffix),
+            ArtifactKind::StaticLib => (&self.staticlib_prefix, &self.staticlib_suffix),
+            ArtifactKind::ImportLib => (&self.implib_prefix, &self.implib_suffix),
+        }
+    }
+
+    /// Computes the file name of an artifact of the given `kind` from `stem`.
+    ///
+    /// `version` is only valid for [`ArtifactKind::Dylib`].
+    /// Returns [`None`] if a version is given but versioned names are not supported,
+    ///  or if `kind` is [`ArtifactKind::ImportLib`] and the platform does not use import libraries
+    pub fn output_name(
+        &self,
+        kind: ArtifactKind,
+        stem: &str,
+        version: Option<&str>,
+    ) -> Option<String> {
+        if kind == ArtifactKind::ImportLib && self.implib_suffix.is_empty() {
+            return None;
+        }
+        let (prefix, suffix) = self.affixes(kind);
+        match (version, kind, self.dylib_version) {
+            (None, _, _) => Some(format!("{prefix}{stem}{suffix}")),
+            (Some(version), ArtifactKind::Dylib, VersionPlacement::AfterSuffix) => {
+                Some(format!("{prefix}{stem}{suffix}.{version}"))
+            }
+            (Some(version), ArtifactKind::Dylib, VersionPlacement::BeforeSuffix) => {
+                Some(format!("{prefix}{stem}.{version}{suffix}"))
+            }
+            (Some(_), _, _) => None,
+        }
+    }
+
+    /// Computes the file name of the separate debug info for an artifact (`.pdb`, `.debug`, or `.dSYM`).
+    ///
+    /// Returns [`None`] if [`FileNames::output_name`] does, or if separate debug info is not supported
+    pub fn debug_info_name(
+        &self,
+        kind: ArtifactKind,
+        stem: &str,
+        version: Option<&str>,
+    ) -> Option<String> {
+        let name = self.output_name(kind, stem, version)?;
+        match self.debuginfo_placement {
+            DebugInfoPlacement::Unsupported => None,
+            DebugInfoPlacement::ReplaceSuffix => {
+                let (prefix, _) = self.affixes(kind);
+                Some(format!("{prefix}{stem}{}", self.debuginfo_suffix))
+            }
+            DebugInfoPlacement::AppendSuffix => Some(format!("{name}{}", self.debuginfo_suffix)),
+        }
+    }
+
+    /// Computes the file name of the split DWARF file for an object file (`foo.o` becomes `foo.dwo`).
+    ///
+    /// Returns [`None`] if split DWARF is not supported
+    pub fn split_dwarf_name(&self, stem: &str) -> Option<String> {
+        if self.split_dwarf_suffix.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "{}{stem}{}",
+            self.obj_prefix, self.split_dwarf_suffix
+        ))
+    }
+
+    /// Parses a file name into the kind of artifact, stem, and version.
+    ///
+    /// If the name matches multiple kinds, the kind with the longest prefix and suffix is chosen (so `libfoo.dll.a` is an import library rather than a static library).
+    /// Executables are only chosen if no other kind matches, unless they have a non-empty suffix.
+    ///
+    /// A version is only recognized when it follows the dylib suffix (`libfoo.so.1.2.3`).
+    /// Versions before the suffix (`libfoo.1.2.3.dylib`) cannot be told apart from a stem containing dots (`libpython3.11.dylib`),
+    ///  so they are kept as part of the stem
+    pub fn parse_name<'a>(&self, name: &'a str) -> Option<ParsedName<'a>> {
+        const KINDS: [ArtifactKind; 5] = [
+            ArtifactKind::Dylib,
+            ArtifactKind::ImportLib,
+            ArtifactKind::StaticLib,
+            ArtifactKind::Object,
+            ArtifactKind::Exe,
+        ];
+
+        let mut best: Option<(usize, ParsedName<'a>)> = None;
+
+        for kind in KINDS {
+            if kind == ArtifactKind::ImportLib && self.implib_suffix.is_empty() {
+                continue;
+            }
+            let (prefix, suffix) = self.affixes(kind);
+            let Some(rest) = name.strip_prefix(prefix) else {
+                continue;
+            };
+
+            let parsed = if let Some(stem) = rest.strip_suffix(suffix) {
+                ParsedName {
+                    kind,
+                    stem,
+                    version: None,
+                }
+            } else if let (ArtifactKind::Dylib, VersionPlacement::AfterSuffix, false) =
+                (kind, self.dylib_version, suffix.is_empty())
+            {
+                let Some((stem, version)) = rest.rsplit_once(&format!("{suffix}.")) else {
+                    continue;
+                };
+                if !is_version(version) {
+                    continue;
+                }
+                ParsedName {
+                    kind,
+                    stem,
+                    version: Some(version),
+                }
+            } else {
+                continue;
+            };
+
+            if parsed.stem.is_empty() {
+                continue;
+            }
+
+            let specificity = prefix.len() + suffix.len();
+            if best
+                .as_ref()
+                .is_none_or(|(best_specificity, _)| specificity > *best_specificity)
+            {
+                best = Some((specificity, parsed));
+            }
+        }
+
+        best.map(|(_, parsed)| parsed)
+    }
+}
+
+fn is_version(version: &str) -> bool {
+    !version.is_empty()
+        && version
+            .split('.')
+            .all(|component| !component.is_empty() && component.bytes().all(|b| b.is_ascii_digit()))
 }
 
 /// The format of static libraries
@@ -148,6 +580,519 @@ pub enum NxStackMode {
     Unsupported,
 }
 
+/// A code model, which constrains the size and placement of code and data (`-mcmodel`)
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum CodeModel {
+    /// Code and data are within 1MiB of each other (AArch64)
+    Tiny,
+    /// Code and data are in the low 2GiB of the address space (x86-64), or within 4GiB of each other (AArch64).
+    /// On RISC-V, this is `medlow`
+    Small,
+    /// Code and data are in the top 2GiB of the address space (x86-64 kernels)
+    Kernel,
+    /// Code is in the low 2GiB of the address space, but data may be anywhere (x86-64).
+    /// On RISC-V, this is `medany`
+    Medium,
+    /// No assumptions are made about the placement of code or data
+    Large,
+}
+
+impl CodeModel {
+    /// The name of the code model, as accepted by `-mcmodel`
+    pub const fn name(self) -> &'static str {
+        match self {
+            CodeModel::Tiny => "tiny",
+            CodeModel::Small => "small",
+            CodeModel::Kernel => "kernel",
+            CodeModel::Medium => "medium",
+            CodeModel::Large => "large",
+        }
+    }
+
+    /// Parses the name of a code model. The RISC-V names `medlow` and `medany` are accepted as aliases
+    pub fn from_name(name: &str) -> Option<CodeModel> {
+        match name {
+            "tiny" => Some(CodeModel::Tiny),
+            "small" | "medlow" => Some(CodeModel::Small),
+            "kernel" => Some(CodeModel::Kernel),
+            "medium" | "medany" => Some(CodeModel::Medium),
+            "large" => Some(CodeModel::Large),
+            _ => None,
+        }
+    }
+}
+
+/// A relocation model, which determines how code and data are addressed
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RelocModel {
+    /// Absolute addressing, for position dependent executables
+    Static,
+    /// Position independent code, suitable for dynamic libraries (`-fPIC`)
+    Pic,
+    /// Position independent code that may assume symbols are defined in the executable (`-fPIE`)
+    Pie,
+    /// Read-only data and code are addressed relative to the program counter (ARM `-fropi`)
+    Ropi,
+    /// Read-write data is addressed relative to the static base register (ARM `-frwpi`)
+    Rwpi,
+    /// Both [`RelocModel::Ropi`] and [`RelocModel::Rwpi`]
+    RopiRwpi,
+    /// Absolute addressing for code in the executable, with indirect references to dynamic library symbols (Mach-O `-mdynamic-no-pic`)
+    DynamicNoPic,
+}
+
+impl RelocModel {
+    /// The name of the relocation model
+    pub const fn name(self) -> &'static str {
+        match self {
+            RelocModel::Static => "static",
+            RelocModel::Pic => "pic",
+            RelocModel::Pie => "pie",
+            RelocModel::Ropi => "ropi",
+            RelocModel::Rwpi => "rwpi",
+            RelocModel::RopiRwpi => "ropi-rwpi",
+            RelocModel::DynamicNoPic => "dynamic-no-pic",
+        }
+    }
+
+    /// Parses the name of a relocation model
+    pub fn from_name(name: &str) -> Option<RelocModel> {
+        match name {
+            "static" => Some(RelocModel::Static),
+            "pic" => Some(RelocModel::Pic),
+            "pie" => Some(RelocModel::Pie),
+            "ropi" => Some(RelocModel::Ropi),
+            "rwpi" => Some(RelocModel::Rwpi),
+            "ropi-rwpi" => Some(RelocModel::RopiRwpi),
+            "dynamic-no-pic" => Some(RelocModel::DynamicNoPic),
+            _ => None,
+        }
+    }
+
+    /// Whether the relocation model can be used with the given [`SupportedArtifacts`].
+    ///
+    /// [`RelocModel::Pie`] requires either [`SupportedArtifacts::PIE`] or [`SupportedArtifacts::STATIC_PIE`],
+    ///  and [`RelocModel::DynamicNoPic`] requires dynamic libraries to refer to
+    pub const fn is_supported_by(self, artifacts: SupportedArtifacts) -> bool {
+        match self {
+            RelocModel::Static | RelocModel::Ropi | RelocModel::Rwpi | RelocModel::RopiRwpi => {
+                artifacts.contains(SupportedArtifacts::EXE)
+            }
+            RelocModel::Pic => artifacts.contains(SupportedArtifacts::PIC),
+            RelocModel::Pie => {
+                artifacts.intersects(SupportedArtifacts::PIE.union(SupportedArtifacts::STATIC_PIE))
+            }
+            RelocModel::DynamicNoPic => {
+                artifacts.contains(SupportedArtifacts::EXE.union(SupportedArtifacts::DYLIB))
+            }
+        }
+    }
+}
+
+/// The code models supported by a target
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct CodeModels {
+    /// The supported code models
+    pub supported: CowSlice<CodeModel>,
+    /// The code model used when none is specified
+    pub default: CodeModel,
+}
+
+/// The relocation models supported by a [`LinkFormat`]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct RelocModels {
+    /// The supported relocation models
+    pub supported: CowSlice<RelocModel>,
+    /// The relocation model used for executables when none is specified
+    pub default: RelocModel,
+}
+
+/// An error produced when a code model or relocation model is not supported
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ModelError {
+    /// The code model is not supported by the target
+    UnsupportedCodeModel(CodeModel),
+    /// The relocation model is not supported by the target
+    UnsupportedRelocModel(RelocModel),
+    /// The relocation model requires artifacts that are not in [`LinkFormat::supported_artifacts`]
+    MissingArtifacts(RelocModel),
+}
+
+impl core::fmt::Display for ModelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedCodeModel(model) => f.write_fmt(format_args!(
+                "code model `{}` is not supported by the target",
+                model.name()
+            )),
+            Self::UnsupportedRelocModel(model) => f.write_fmt(format_args!(
+                "relocation model `{}` is not supported by the target",
+                model.name()
+            )),
+            Self::MissingArtifacts(model) => f.write_fmt(format_args!(
+                "relocation model `{}` requires artifacts that the target does not support",
+                model.name()
+            )),
+        }
+    }
+}
+
+impl std::error::Error for ModelError {}
+
+/// Only the small code model
+pub static CODE_MODELS_SMALL: CodeModels = CodeModels {
+    supported: slice![CodeModel::Small],
+    default: CodeModel::Small,
+};
+
+/// Relocation models for formats that only support position dependent executables
+pub static RELOC_MODELS_STATIC: RelocModels = RelocModels {
+    supported: slice![RelocModel::Static],
+    default: RelocModel::Static,
+};
+
+/// Relocation models for formats that support static-pie executables, but not dynamic linking
+pub static RELOC_MODELS_STATIC_PIE: RelocModels = RelocModels {
+    supported: slice![RelocModel::Static, RelocModel::Pie],
+    default: RelocModel::Static,
+};
+
+/// Relocation models for formats with dynamic linking, where executables are position dependent by default
+pub const RELOC_MODELS_NO_DEFAULT_PIE: RelocModels = RelocModels {
+    supported: slice![RelocModel::Static, RelocModel::Pic, RelocModel::Pie],
+    default: RelocModel::Static,
+};
+
+/// Relocation models for formats with dynamic linking, where executables are PIE by default
+pub static RELOC_MODELS_DEFAULT_PIE: RelocModels = RelocModels {
+    default: RelocModel::Pie,
+    ..RELOC_MODELS_NO_DEFAULT_PIE
+};
+
+/// Which relocations are made read-only after relocation processing
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RelroMode {
+    /// No `PT_GNU_RELRO` segment is emitted
+    None,
+    /// Relocated data other than the PLT GOT is made read-only (`-z relro`)
+    Partial,
+    /// All symbols are bound at load time, so the entire GOT is made read-only (`-z relro -z now`)
+    Full,
+}
+
+/// The functions that are instrumented with a stack protector
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum StackProtector {
+    /// No functions are instrumented
+    None,
+    /// Functions with character arrays or `alloca` (`-fstack-protector`)
+    Basic,
+    /// Functions with any local arrays, or that take the address of a local (`-fstack-protector-strong`)
+    Strong,
+    /// Every function (`-fstack-protector-all`)
+    All,
+}
+
+impl StackProtector {
+    /// The compiler flag that selects this level
+    pub const fn flag(self) -> &'static str {
+        match self {
+            StackProtector::None => "-fno-stack-protector",
+            StackProtector::Basic => "-fstack-protector",
+            StackProtector::Strong => "-fstack-protector-strong",
+            StackProtector::All => "-fstack-protector-all",
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// x86 Control-flow Enforcement Technology (CET) protections
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct CfProtection : u8 {
+        /// Shadow stacks (`shstk`), which protect return addresses
+        const SHSTK = 0x01;
+        /// Indirect branch tracking (`endbr32`/`endbr64` landing pads)
+        const IBT = 0x02;
+    }
+}
+
+bitflags::bitflags! {
+    /// AArch64 branch protection
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct BranchProtection : u8 {
+        /// Branch target identification landing pads (`bti`)
+        const BTI = 0x01;
+        /// Return address signing with pointer authentication (`pac-ret`)
+        const PAC_RET = 0x02;
+    }
+}
+
+/// Default hardening of generated code and linked outputs
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Hardening {
+    /// Whether relocated data is made read-only after loading
+    pub relro: RelroMode,
+    /// The default stack protector level
+    pub stack_protector: StackProtector,
+    /// The default level of `_FORTIFY_SOURCE` when optimizing. `0` disables fortification
+    pub fortify_source: u8,
+    /// The CET protections enabled by default on x86
+    pub cf_protection: CfProtection,
+    /// The branch protections enabled by default on AArch64
+    pub branch_protection: BranchProtection,
+    /// Whether objects are marked with `.note.gnu.property` for the enabled [`Hardening::cf_protection`] or [`Hardening::branch_protection`],
+    ///  so that the linked output is only marked as protected if every input is
+    pub gnu_property_note: bool,
+    /// Whether stack allocations are probed so that the stack cannot skip over a guard page (`-fstack-clash-protection`)
+    pub stack_clash_protection: bool,
+}
+
+impl Hardening {
+    /// No hardening enabled by default
+    pub const NONE: Hardening = Hardening {
+        relro: RelroMode::None,
+        stack_protector: StackProtector::None,
+        fortify_source: 0,
+        cf_protection: CfProtection::empty(),
+        branch_protection: BranchProtection::empty(),
+        gnu_property_note: false,
+        stack_clash_protection: false,
+    };
+
+    /// The GCC-style compiler flags that select these defaults.
+    ///
+    /// `optimizing` is whether optimizations are enabled, since `_FORTIFY_SOURCE` has no effect (and warns) without optimization
+    pub fn compiler_flags(&self, optimizing: bool) -> Vec<String> {
+        let mut flags = vec![self.stack_protector.flag().to_string()];
+        if self.fortify_source != 0 && optimizing {
+            flags.push(format!("-D_FORTIFY_SOURCE={}", self.fortify_source));
+        }
+        let cf = self.cf_protection;
+        if cf == CfProtection::all() {
+            flags.push("-fcf-protection=full".to_string());
+        } else if cf == CfProtection::SHSTK {
+            flags.push("-fcf-protection=return".to_string());
+        } else if cf == CfProtection::IBT {
+            flags.push("-fcf-protection=branch".to_string());
+        }
+        let bp = self.branch_protection;
+        if bp == BranchProtection::all() {
+            flags.push("-mbranch-protection=bti+pac-ret".to_string());
+        } else if bp == BranchProtection::BTI {
+            flags.push("-mbranch-protection=bti".to_string());
+        } else if bp == BranchProtection::PAC_RET {
+            flags.push("-mbranch-protection=pac-ret".to_string());
+        }
+        if self.stack_clash_protection {
+            flags.push("-fstack-clash-protection".to_string());
+        }
+        flags
+    }
+}
+
+/// Hardening for targets without hardened defaults, such as freestanding targets
+pub static HARDENING_NONE: Hardening = Hardening::NONE;
+
+/// How a dynamic library records the name it is loaded by
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum SonameMode {
+    /// Dynamic libraries do not record a load name (or dynamic libraries are not supported)
+    Unsupported,
+    /// The name is recorded in `DT_SONAME`, and copied into `DT_NEEDED` of dependents (`-soname`)
+    Soname,
+    /// The name is recorded as the install name in `LC_ID_DYLIB`, and copied into `LC_LOAD_DYLIB` of dependents (`-install_name`)
+    InstallName,
+}
+
+/// How much of the library version is included in the default soname
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum SonameVersion {
+    /// The soname is unversioned (`libfoo.so`)
+    None,
+    /// The soname includes only the major version (`libfoo.so.1`)
+    Major,
+    /// The soname includes the full version (`libfoo.so.1.2`)
+    Full,
+}
+
+/// The kind of runtime library search path recorded in executables and dynamic libraries
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RunpathMode {
+    /// Runtime search paths are not supported
+    Unsupported,
+    /// `DT_RUNPATH`, which is searched after `LD_LIBRARY_PATH`, and only for the object's own dependencies
+    Runpath,
+    /// `DT_RPATH`, which is searched before `LD_LIBRARY_PATH`, and is inherited by dependencies
+    Rpath,
+    /// `LC_RPATH`, which is substituted for `@rpath` in install names
+    LcRpath,
+}
+
+/// Policy for naming dynamic libraries and recording runtime search paths
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct DylibPolicy {
+    /// How dynamic libraries record the name they are loaded by
+    pub soname: SonameMode,
+    /// The part of the version included in the default soname
+    pub soname_version: SonameVersion,
+    /// The prefix of the default soname, such as `@rpath/` for install names
+    pub soname_prefix: CowStr,
+    /// The kind of runtime search path that is used by default
+    pub runpath: RunpathMode,
+    /// Whether the linker must be passed `--enable-new-dtags` to record [`RunpathMode::Runpath`] rather than `DT_RPATH`
+    pub requires_new_dtags: bool,
+    /// The token that refers to the directory of the loading object in a runtime search path (`$ORIGIN` or `@loader_path`).
+    /// This is empty if there is no such token
+    pub origin_token: CowStr,
+    /// The token that refers to the runtime search paths in a dependency name (`@rpath`).
+    /// This is empty if there is no such token
+    pub rpath_token: CowStr,
+}
+
+impl DylibPolicy {
+    /// Computes the default soname (or install name) of a dynamic library from its stem and version.
+    ///
+    /// Returns [`None`] if sonames are not supported, or if [`FileNames::output_name`] does
+    pub fn soname(&self, names: &FileNames, stem: &str, version: Option<&str>) -> Option<String> {
+        if self.soname == SonameMode::Unsupported {
+            return None;
+        }
+        let version = match self.soname_version {
+            SonameVersion::None => None,
+            SonameVersion::Major => {
+                version.map(|v| v.split_once('.').map_or(v, |(major, _)| major))
+            }
+            SonameVersion::Full => version,
+        };
+        let name = names.output_name(ArtifactKind::Dylib, stem, version)?;
+        Some(format!("{}{name}", self.soname_prefix))
+    }
+
+    /// Computes a runtime search path relative to the directory of the loading object, such as `$ORIGIN/../lib`.
+    ///
+    /// Returns [`None`] if runtime search paths are not supported, or there is no [`DylibPolicy::origin_token`]
+    pub fn origin_relative(&self, path: &str) -> Option<String> {
+        if self.runpath == RunpathMode::Unsupported || self.origin_token.is_empty() {
+            return None;
+        }
+        let path = path.trim_start_matches('/');
+        if path.is_empty() {
+            Some(self.origin_token.to_string())
+        } else {
+            Some(format!("{}/{path}", self.origin_token))
+        }
+    }
+}
+
+/// The policy for targets that do not support sonames or runtime search paths (such as PE and WebAssembly)
+pub const DYLIB_POLICY_NONE: DylibPolicy = DylibPolicy {
+    soname: SonameMode::Unsupported,
+    soname_version: SonameVersion::None,
+    soname_prefix: cowstr!(""),
+    runpath: RunpathMode::Unsupported,
+    requires_new_dtags: false,
+    origin_token: cowstr!(""),
+    rpath_token: cowstr!(""),
+};
+
+/// The policy for ELF targets linked with GNU ld, which records `DT_RPATH` unless `--enable-new-dtags` is passed
+pub const DYLIB_POLICY_ELF: DylibPolicy = DylibPolicy {
+    soname: SonameMode::Soname,
+    soname_version: SonameVersion::Major,
+    soname_prefix: cowstr!(""),
+    runpath: RunpathMode::Runpath,
+    requires_new_dtags: true,
+    origin_token: cowstr!("$ORIGIN"),
+    rpath_token: cowstr!(""),
+};
+
+/// The policy for Mach-O targets, where dynamic libraries are found through `@rpath`
+pub const DYLIB_POLICY_MACHO: DylibPolicy = DylibPolicy {
+    soname: SonameMode::InstallName,
+    soname_version: SonameVersion::Major,
+    soname_prefix: cowstr!("@rpath/"),
+    runpath: RunpathMode::LcRpath,
+    requires_new_dtags: false,
+    origin_token: cowstr!("@loader_path"),
+    rpath_token: cowstr!("@rpath"),
+};
+
+/// Describes the memory layout of a linked image, used to generate linker scripts
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MemoryLayout {
+    /// The symbol used as the entry point of the image
+    pub entry: Option<CowStr>,
+    /// The memory regions available to place sections in.
+    /// If this is empty, sections are placed by address only
+    pub regions: CowSlice<MemoryRegion>,
+    /// The output sections of the image, in placement order
+    pub sections: CowSlice<SectionPlacement>,
+}
+
+bitflags::bitflags! {
+    /// Access attributes of a [`MemoryRegion`]
+    #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+    pub struct RegionAttributes : u8 {
+        /// The region is readable
+        const READ = 0x01;
+        /// The region is writable
+        const WRITE = 0x02;
+        /// The region is executable
+        const EXEC = 0x04;
+    }
+}
+
+/// A region of memory in the address space of the target
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MemoryRegion {
+    /// The name of the region
+    pub name: CowStr,
+    /// The access attributes of the region
+    pub attributes: RegionAttributes,
+    /// The first address of the region
+    pub origin: u64,
+    /// The length of the region, in bytes
+    pub length: u64,
+}
+
+/// Describes where an output section is placed, and which input sections it contains
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct SectionPlacement {
+    /// The name of the output section
+    pub name: CowStr,
+    /// The input section patterns collected into the output section (for example, `.text.*`)
+    pub inputs: CowSlice<CowStr>,
+    /// Whether the input sections are retained even if unreferenced (e.g. for vector tables)
+    pub keep: bool,
+    /// The virtual address of the section. If this is [`None`], the section follows the previous section
+    pub address: Option<u64>,
+    /// The alignment of the start of the section, in bytes
+    pub align: Option<u64>,
+    /// The region the section is placed in at runtime
+    pub region: Option<CowStr>,
+    /// The region the section is loaded from, if different from [`SectionPlacement::region`] (for example, initialized data copied out of ROM)
+    pub load_region: Option<CowStr>,
+    /// The load address of the section, if different from [`SectionPlacement::address`]
+    pub load_address: Option<u64>,
+    /// Whether the section occupies no space in the image (such as `.bss`)
+    pub noload: bool,
+}
+
+impl SectionPlacement {
+    /// A [`SectionPlacement`] with no inputs and no fixed placement, suitable for struct update syntax
+    pub const DEFAULT: SectionPlacement = SectionPlacement {
+        name: cowstr!(""),
+        inputs: slice![],
+        keep: false,
+        address: None,
+        align: None,
+        region: None,
+        load_region: None,
+        load_address: None,
+        noload: false,
+    };
+}
+
 /// Library search properties
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct LibrarySearch {
@@ -171,6 +1116,30 @@ pub struct LibrarySearch {
     pub use_target_stem_dirs: bool,
 }
 
+impl LibrarySearch {
+    /// Computes the library search directories, in search order.
+    ///
+    /// Each of the [`LibrarySearch::base_dirs`] is resolved relative to `sysroot` (or `/` if `sysroot` is [`None`]).
+    /// If [`LibrarySearch::use_target_stem_dirs`] is set and `target_stem` is provided,
+    ///  `<base>/<target_stem>/<search_dir>` is searched before `<base>/<search_dir>`
+    pub fn search_paths(&self, sysroot: Option<&Path>, target_stem: Option<&str>) -> Vec<PathBuf> {
+        let mut paths = Vec::new();
+        for base in &self.base_dirs {
+            let base = match sysroot {
+                Some(sysroot) => sysroot.join(base.trim_start_matches('/')),
+                None => PathBuf::from(&**base),
+            };
+            for dir in &self.search_dirs {
+                if let (true, Some(stem)) = (self.use_target_stem_dirs, target_stem) {
+                    paths.push(base.join(stem).join(&**dir));
+                }
+                paths.push(base.join(&**dir));
+            }
+        }
+        paths
+    }
+}
+
 /// The Default search for Unix-like platforms (and platforms with similar filesystem layouts)
 pub const SEARCH_UNIX_DEFAULT: LibrarySearch = LibrarySearch {
     base_dirs: slice![cowstr!("/"), cowstr!("/usr"), cowstr!("/usr/local")],
@@ -182,7 +1151,7 @@ pub const SEARCH_UNIX_DEFAULT: LibrarySearch = LibrarySearch {
     use_target_stem_dirs: true,
 };
 
-/// The Default search for Unix-like platforms (and platforms with similar filesystem layouts)
+/// Output filenames for Unix-like platforms
 pub static FILENAMES_ELF: FileNames = FileNames {
     obj_prefix: cowstr!(""),
     obj_suffix: cowstr!(".o"),
@@ -192,16 +1161,532 @@ pub static FILENAMES_ELF: FileNames = FileNames {
     dylib_suffix: cowstr!(".so"),
     staticlib_prefix: cowstr!("lib"),
     staticlib_suffix: cowstr!(".a"),
+    implib_prefix: cowstr!(""),
+    implib_suffix: cowstr!(""),
+    dylib_version: VersionPlacement::AfterSuffix,
+    debuginfo_suffix: cowstr!(".debug"),
+    debuginfo_placement: DebugInfoPlacement::AppendSuffix,
+    split_dwarf_suffix: cowstr!(".dwo"),
 };
 
-/// The Default search for Unix-like platforms (and platforms with similar filesystem layouts)
+/// Library search for PE targets using the MSVC toolchain.
+/// `-l<name>` resolves to `<name>.lib`, which is either a static library or an import library
+pub const SEARCH_MSVC: LibrarySearch = LibrarySearch {
+    base_dirs: slice![cowstr!("/")],
+    search_dirs: slice![cowstr!("lib")],
+    staticlib_prefixes: slice![cowstr!(""), cowstr!("lib")],
+    staticlib_suffixes: slice![cowstr!(".lib")],
+    dylib_prefixes: slice![cowstr!("")],
+    dylib_suffixes: slice![cowstr!(".lib")],
+    use_target_stem_dirs: false,
+};
+
+/// Library search for PE targets using the MinGW toolchain.
+/// Import libraries (`.dll.a`) are preferred over linking directly against a `.dll`
+pub const SEARCH_MINGW: LibrarySearch = LibrarySearch {
+    base_dirs: slice![cowstr!("/"), cowstr!("/usr")],
+    search_dirs: slice![cowstr!("lib")],
+    staticlib_prefixes: slice![cowstr!("lib"), cowstr!("")],
+    staticlib_suffixes: slice![cowstr!(".a"), cowstr!(".lib")],
+    dylib_prefixes: slice![cowstr!("lib"), cowstr!("")],
+    dylib_suffixes: slice![cowstr!(".dll.a"), cowstr!(".dll")],
+    use_target_stem_dirs: true,
+};
+
+/// Output filenames for PE targets using the MSVC toolchain conventions.
+/// Import libraries for `foo.dll` are named `foo.lib`, so static libraries use the `lib` prefix to avoid a conflict
 pub static FILENAMES_PE: FileNames = FileNames {
     obj_prefix: cowstr!(""),
     obj_suffix: cowstr!(".obj"),
     exe_prefix: cowstr!(""),
-    exe_suffix: cowstr!(""),
+    exe_suffix: cowstr!(".exe"),
     dylib_prefix: cowstr!(""),
     dylib_suffix: cowstr!(".dll"),
     staticlib_prefix: cowstr!("lib"),
     staticlib_suffix: cowstr!(".lib"),
+    implib_prefix: cowstr!(""),
+    implib_suffix: cowstr!(".lib"),
+    dylib_version: VersionPlacement::Unsupported,
+    debuginfo_suffix: cowstr!(".pdb"),
+    debuginfo_placement: DebugInfoPlacement::ReplaceSuffix,
+    split_dwarf_suffix: cowstr!(""),
+};
+
+/// Output filenames for PE targets using the MinGW (GNU) toolchain conventions
+pub static FILENAMES_MINGW: FileNames = FileNames {
+    obj_prefix: cowstr!(""),
+    obj_suffix: cowstr!(".o"),
+    exe_prefix: cowstr!(""),
+    exe_suffix: cowstr!(".exe"),
+    dylib_prefix: cowstr!(""),
+    dylib_suffix: cowstr!(".dll"),
+    staticlib_prefix: cowstr!("lib"),
+    staticlib_suffix: cowstr!(".a"),
+    implib_prefix: cowstr!("lib"),
+    implib_suffix: cowstr!(".dll.a"),
+    dylib_version: VersionPlacement::Unsupported,
+    debuginfo_suffix: cowstr!(""),
+    debuginfo_placement: DebugInfoPlacement::Unsupported,
+    split_dwarf_suffix: cowstr!(""),
+};
+
+/// Library search for Darwin (macOS).
+/// Text-based stubs (`.tbd`) are accepted in place of dynamic libraries
+pub const SEARCH_DARWIN: LibrarySearch = LibrarySearch {
+    base_dirs: slice![cowstr!("/usr"), cowstr!("/usr/local")],
+    search_dirs: slice![cowstr!("lib")],
+    staticlib_prefixes: slice![cowstr!("lib")],
+    staticlib_suffixes: slice![cowstr!(".a")],
+    dylib_prefixes: slice![cowstr!("lib")],
+    dylib_suffixes: slice![cowstr!(".dylib"), cowstr!(".tbd")],
+    use_target_stem_dirs: false,
+};
+
+/// Output filenames for Mach-O targets
+pub static FILENAMES_MACHO: FileNames = FileNames {
+    obj_prefix: cowstr!(""),
+    obj_suffix: cowstr!(".o"),
+    exe_prefix: cowstr!(""),
+    exe_suffix: cowstr!(""),
+    dylib_prefix: cowstr!("lib"),
+    dylib_suffix: cowstr!(".dylib"),
+    staticlib_prefix: cowstr!("lib"),
+    staticlib_suffix: cowstr!(".a"),
+    implib_prefix: cowstr!(""),
+    implib_suffix: cowstr!(""),
+    dylib_version: VersionPlacement::BeforeSuffix,
+    debuginfo_suffix: cowstr!(".dSYM"),
+    debuginfo_placement: DebugInfoPlacement::AppendSuffix,
+    split_dwarf_suffix: cowstr!(""),
 };
+
+/// Output filenames for WebAssembly. Executables use the `.wasm` suffix
+pub static FILENAMES_WASM: FileNames = FileNames {
+    obj_prefix: cowstr!(""),
+    obj_suffix: cowstr!(".o"),
+    exe_prefix: cowstr!(""),
+    exe_suffix: cowstr!(".wasm"),
+    dylib_prefix: cowstr!("lib"),
+    dylib_suffix: cowstr!(".wasm"),
+    staticlib_prefix: cowstr!("lib"),
+    staticlib_suffix: cowstr!(".a"),
+    implib_prefix: cowstr!(""),
+    implib_suffix: cowstr!(""),
+    dylib_version: VersionPlacement::Unsupported,
+    debuginfo_suffix: cowstr!(""),
+    debuginfo_placement: DebugInfoPlacement::Unsupported,
+    split_dwarf_suffix: cowstr!(".dwo"),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed<'a>(kind: ArtifactKind, stem: &'a str, version: Option<&'a str>) -> ParsedName<'a> {
+        ParsedName {
+            kind,
+            stem,
+            version,
+        }
+    }
+
+    #[test]
+    fn output_name_elf() {
+        let names = &FILENAMES_ELF;
+        assert_eq!(
+            names
+                .output_name(ArtifactKind::Object, "foo", None)
+                .as_deref(),
+            Some("foo.o")
+        );
+        assert_eq!(
+            names.output_name(ArtifactKind::Exe, "foo", None).as_deref(),
+            Some("foo")
+        );
+        assert_eq!(
+            names
+                .output_name(ArtifactKind::Dylib, "foo", None)
+                .as_deref(),
+            Some("libfoo.so")
+        );
+        assert_eq!(
+            names
+                .output_name(ArtifactKind::Dylib, "foo", Some("1.2.3"))
+                .as_deref(),
+            Some("libfoo.so.1.2.3")
+        );
+        assert_eq!(
+            names
+                .output_name(ArtifactKind::StaticLib, "foo", None)
+                .as_deref(),
+            Some("libfoo.a")
+        );
+        assert_eq!(
+            names.output_name(ArtifactKind::ImportLib, "foo", None),
+            None
+        );
+        assert_eq!(names.output_name(ArtifactKind::Exe, "foo", Some("1")), None);
+    }
+
+    #[test]
+    fn output_name_other_formats() {
+        assert_eq!(
+            FILENAMES_PE
+                .output_name(ArtifactKind::Dylib, "foo", None)
+                .as_deref(),
+            Some("foo.dll")
+        );
+        assert_eq!(
+            FILENAMES_PE
+                .output_name(ArtifactKind::ImportLib, "foo", None)
+                .as_deref(),
+            Some("foo.lib")
+        );
+        assert_eq!(
+            FILENAMES_PE.output_name(ArtifactKind::Dylib, "foo", Some("1")),
+            None
+        );
+        assert_eq!(
+            FILENAMES_MINGW
+                .output_name(ArtifactKind::ImportLib, "foo", None)
+                .as_deref(),
+            Some("libfoo.dll.a")
+        );
+        assert_eq!(
+            FILENAMES_MACHO
+                .output_name(ArtifactKind::Dylib, "foo", Some("1.2"))
+                .as_deref(),
+            Some("libfoo.1.2.dylib")
+        );
+        assert_eq!(
+            FILENAMES_WASM
+                .output_name(ArtifactKind::Exe, "foo", None)
+                .as_deref(),
+            Some("foo.wasm")
+        );
+    }
+
+    #[test]
+    fn split_dwarf_name() {
+        assert_eq!(
+            FILENAMES_ELF
+                .output_name(ArtifactKind::Object, "foo", None)
+                .as_deref(),
+            Some("foo.o")
+        );
+        assert_eq!(
+            FILENAMES_ELF.split_dwarf_name("foo").as_deref(),
+            Some("foo.dwo")
+        );
+        assert_eq!(
+            FILENAMES_WASM.split_dwarf_name("foo").as_deref(),
+            Some("foo.dwo")
+        );
+        assert_eq!(FILENAMES_PE.split_dwarf_name("foo"), None);
+        assert_eq!(FILENAMES_MACHO.split_dwarf_name("foo"), None);
+    }
+
+    #[test]
+    fn debug_info_name() {
+        assert_eq!(
+            FILENAMES_ELF
+                .debug_info_name(ArtifactKind::Dylib, "foo", None)
+                .as_deref(),
+            Some("libfoo.so.debug")
+        );
+        assert_eq!(
+            FILENAMES_ELF
+                .debug_info_name(ArtifactKind::Dylib, "foo", Some("1"))
+                .as_deref(),
+            Some("libfoo.so.1.debug")
+        );
+        assert_eq!(
+            FILENAMES_ELF
+                .debug_info_name(ArtifactKind::Exe, "foo", None)
+                .as_deref(),
+            Some("foo.debug")
+        );
+        assert_eq!(
+            FILENAMES_PE
+                .debug_info_name(ArtifactKind::Exe, "foo", None)
+                .as_deref(),
+            Some("foo.pdb")
+        );
+        assert_eq!(
+            FILENAMES_MACHO
+                .debug_info_name(ArtifactKind::Dylib, "foo", None)
+                .as_deref(),
+            Some("libfoo.dylib.dSYM")
+        );
+        assert_eq!(
+            FILENAMES_WASM.debug_info_name(ArtifactKind::Exe, "foo", None),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_name_elf() {
+        let names = &FILENAMES_ELF;
+        assert_eq!(
+            names.parse_name("libfoo.so"),
+            Some(parsed(ArtifactKind::Dylib, "foo", None))
+        );
+        assert_eq!(
+            names.parse_name("libfoo.so.1.2.3"),
+            Some(parsed(ArtifactKind::Dylib, "foo", Some("1.2.3")))
+        );
+        assert_eq!(
+            names.parse_name("libpython3.11.so.1.0"),
+            Some(parsed(ArtifactKind::Dylib, "python3.11", Some("1.0")))
+        );
+        assert_eq!(
+            names.parse_name("libfoo.a"),
+            Some(parsed(ArtifactKind::StaticLib, "foo", None))
+        );
+        assert_eq!(
+            names.parse_name("foo.o"),
+            Some(parsed(ArtifactKind::Object, "foo", None))
+        );
+        assert_eq!(
+            names.parse_name("foo"),
+            Some(parsed(ArtifactKind::Exe, "foo", None))
+        );
+        // Not a version, so this is an executable with a dotted name
+        assert_eq!(
+            names.parse_name("libfoo.so.x"),
+            Some(parsed(ArtifactKind::Exe, "libfoo.so.x", None))
+        );
+    }
+
+    #[test]
+    fn parse_name_other_formats() {
+        assert_eq!(
+            FILENAMES_MINGW.parse_name("libfoo.dll.a"),
+            Some(parsed(ArtifactKind::ImportLib, "foo", None))
+        );
+        assert_eq!(
+            FILENAMES_MINGW.parse_name("libfoo.a"),
+            Some(parsed(ArtifactKind::StaticLib, "foo", None))
+        );
+        assert_eq!(
+            FILENAMES_PE.parse_name("foo.exe"),
+            Some(parsed(ArtifactKind::Exe, "foo", None))
+        );
+        assert_eq!(
+            FILENAMES_PE.parse_name("foo.dll"),
+            Some(parsed(ArtifactKind::Dylib, "foo", None))
+        );
+        assert_eq!(
+            FILENAMES_MACHO.parse_name("libpython3.11.dylib"),
+            Some(parsed(ArtifactKind::Dylib, "python3.11", None))
+        );
+        assert_eq!(
+            FILENAMES_MACHO.parse_name("libfoo.dylib"),
+            Some(parsed(ArtifactKind::Dylib, "foo", None))
+        );
+    }
+
+    #[test]
+    fn parse_output_name_round_trip() {
+        for names in [
+            &FILENAMES_ELF,
+            &FILENAMES_PE,
+            &FILENAMES_MINGW,
+            &FILENAMES_MACHO,
+        ] {
+            for kind in [
+                ArtifactKind::Object,
+                ArtifactKind::Dylib,
+                ArtifactKind::StaticLib,
+                ArtifactKind::ImportLib,
+            ] {
+                let Some(name) = names.output_name(kind, "foo", None) else {
+                    continue;
+                };
+                assert_eq!(
+                    names.parse_name(&name),
+                    Some(parsed(kind, "foo", None)),
+                    "{name}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn code_model_names() {
+        for model in [
+            CodeModel::Tiny,
+            CodeModel::Small,
+            CodeModel::Kernel,
+            CodeModel::Medium,
+            CodeModel::Large,
+        ] {
+            assert_eq!(CodeModel::from_name(model.name()), Some(model));
+        }
+        assert_eq!(CodeModel::from_name("medlow"), Some(CodeModel::Small));
+        assert_eq!(CodeModel::from_name("medany"), Some(CodeModel::Medium));
+        assert_eq!(CodeModel::from_name("huge"), None);
+    }
+
+    #[test]
+    fn reloc_model_names() {
+        for model in [
+            RelocModel::Static,
+            RelocModel::Pic,
+            RelocModel::Pie,
+            RelocModel::Ropi,
+            RelocModel::Rwpi,
+            RelocModel::RopiRwpi,
+            RelocModel::DynamicNoPic,
+        ] {
+            assert_eq!(RelocModel::from_name(model.name()), Some(model));
+        }
+        assert_eq!(RelocModel::from_name("PIC"), None);
+    }
+
+    #[test]
+    fn builtin_models() {
+        for &name in crate::builtin::target::BUILTIN_TARGETS {
+            let target =
+                crate::builtin::target::from_target(&target_tuples::TargetRef::parse(name))
+                    .unwrap();
+            assert_eq!(target.link.validate_models(), Ok(()), "{name}");
+        }
+    }
+
+    #[test]
+    fn check_models() {
+        let link = &crate::builtin::link::x86::ELF_X86_64_FREESTANDING_LINK;
+        assert_eq!(link.check_code_model(CodeModel::Kernel), Ok(()));
+        assert_eq!(
+            link.check_code_model(CodeModel::Tiny),
+            Err(ModelError::UnsupportedCodeModel(CodeModel::Tiny))
+        );
+
+        let format = LinkFormat {
+            supported_artifacts: SupportedArtifacts::EXE,
+            reloc_models: cow!(RELOC_MODELS_STATIC),
+            ..(*link.formats).clone()
+        };
+        assert_eq!(format.check_reloc_model(RelocModel::Static), Ok(()));
+        assert_eq!(
+            format.check_reloc_model(RelocModel::Pic),
+            Err(ModelError::UnsupportedRelocModel(RelocModel::Pic))
+        );
+        assert_eq!(format.validate_reloc_models(), Ok(()));
+
+        // PIC is listed, but the format cannot produce dynamic libraries
+        let format = LinkFormat {
+            reloc_models: cow!(RELOC_MODELS_DEFAULT_PIE),
+            ..format
+        };
+        assert_eq!(
+            format.validate_reloc_models(),
+            Err(ModelError::MissingArtifacts(RelocModel::Pie))
+        );
+    }
+
+    #[test]
+    fn darwin_dynamic_no_pic() {
+        use crate::builtin::link::darwin::{MACHO_AARCH64, MACHO_X86_64};
+        assert_eq!(
+            MACHO_X86_64.check_reloc_model(RelocModel::DynamicNoPic),
+            Ok(())
+        );
+        assert_eq!(
+            MACHO_AARCH64.check_reloc_model(RelocModel::DynamicNoPic),
+            Err(ModelError::UnsupportedRelocModel(RelocModel::DynamicNoPic))
+        );
+    }
+
+    #[test]
+    fn dylib_soname() {
+        let elf = &DYLIB_POLICY_ELF;
+        assert_eq!(
+            elf.soname(&FILENAMES_ELF, "foo", Some("1.2.3")).as_deref(),
+            Some("libfoo.so.1")
+        );
+        assert_eq!(
+            elf.soname(&FILENAMES_ELF, "foo", None).as_deref(),
+            Some("libfoo.so")
+        );
+        let full = DylibPolicy {
+            soname_version: SonameVersion::Full,
+            ..DYLIB_POLICY_ELF
+        };
+        assert_eq!(
+            full.soname(&FILENAMES_ELF, "foo", Some("1.2.3")).as_deref(),
+            Some("libfoo.so.1.2.3")
+        );
+        let unversioned = DylibPolicy {
+            soname_version: SonameVersion::None,
+            ..DYLIB_POLICY_ELF
+        };
+        assert_eq!(
+            unversioned
+                .soname(&FILENAMES_ELF, "foo", Some("1.2.3"))
+                .as_deref(),
+            Some("libfoo.so")
+        );
+        assert_eq!(
+            DYLIB_POLICY_MACHO
+                .soname(&FILENAMES_MACHO, "foo", Some("1.2.3"))
+                .as_deref(),
+            Some("@rpath/libfoo.1.dylib")
+        );
+        assert_eq!(
+            DYLIB_POLICY_NONE.soname(&FILENAMES_PE, "foo", Some("1.2.3")),
+            None
+        );
+    }
+
+    #[test]
+    fn dylib_origin_relative() {
+        assert_eq!(
+            DYLIB_POLICY_ELF.origin_relative("../lib").as_deref(),
+            Some("$ORIGIN/../lib")
+        );
+        assert_eq!(
+            DYLIB_POLICY_ELF.origin_relative("/").as_deref(),
+            Some("$ORIGIN")
+        );
+        assert_eq!(
+            DYLIB_POLICY_MACHO
+                .origin_relative("../Frameworks")
+                .as_deref(),
+            Some("@loader_path/../Frameworks")
+        );
+        assert_eq!(DYLIB_POLICY_NONE.origin_relative("../lib"), None);
+    }
+
+    #[test]
+    fn target_soname() {
+        let soname = |name: &str| {
+            crate::builtin::target::from_target(&target_tuples::TargetRef::parse(name))
+                .unwrap()
+                .link
+                .soname("foo", Some("1.2"))
+        };
+        assert_eq!(
+            soname("x86_64-pc-linux-gnu").as_deref(),
+            Some("libfoo.so.1")
+        );
+        assert_eq!(
+            soname("x86_64-apple-darwin").as_deref(),
+            Some("@rpath/libfoo.1.dylib")
+        );
+        assert_eq!(soname("x86_64-pc-windows-msvc"), None);
+        assert_eq!(soname("riscv64-unknown-elf"), None);
+        assert_eq!(soname("x86_64-pc-lilium-kernel"), None);
+    }
+
+    #[test]
+    fn freestanding_dylib_policy() {
+        for name in ["riscv64-unknown-elf", "x86_64-pc-lilium-kernel"] {
+            let target =
+                crate::builtin::target::from_target(&target_tuples::TargetRef::parse(name))
+                    .unwrap();
+            assert_eq!(*target.link.dylib_policy, DYLIB_POLICY_NONE, "{name}");
+        }
+    }
+}
@@ -5,9 +5,10 @@ use crate::{
     helpers::{CowPtr, CowSlice, CowStr},
     properties::{
         ExtPropertyValue,
-        abi::{Abi, PrimitiveLayouts},
-        arch::{Arch, Machine},
-        link::Link,
+        abi::{Abi, PrimitiveLayouts, Stack},
+        arch::{Arch, AtomicWidths, Atomics, Machine},
+        env::Env,
+        link::{DefaultLinkFiles, Link, OutputKind},
         os::Os,
     },
 };
@@ -19,6 +20,8 @@ pub struct Target {
     pub arch: CowPtr<'static, Arch>,
     /// The OS properties
     pub os: CowPtr<'static, Os>,
+    /// The environment properties
+    pub env: CowPtr<'static, Env>,
     /// The default ("C") call tag
     pub default_tag: CowStr,
     /// The system ("system") call tag.
@@ -27,6 +30,8 @@ pub struct Target {
     pub primitive_layout: CowPtr<'static, PrimitiveLayouts>,
     /// The abi overrides
     pub abi: CowPtr<'static, Abi>,
+    /// The properties of the call stack
+    pub stack: CowPtr<'static, Stack>,
     /// The link settings
     pub link: CowPtr<'static, Link>,
     /// Sets or unsets features implied by the current machine
@@ -34,6 +39,7 @@ pub struct Target {
     /// Extended Properties set by the target as a whole.
     /// These properties override all others (except those set by a CLI specified machine or by CLI flags), and additionally:
     /// * The OS properties override the architecture properties, unless they start with `arch.` or the architecture name
+    /// * The environment properties override the OS properties
     /// * The Machine properties (including default machine) overrides the architecture properties.
     pub extended_properties: CowSlice<(CowStr, ExtPropertyValue)>,
 }
@@ -66,6 +72,10 @@ impl Target {
             working.insert(name.clone(), val.clone());
         }
 
+        for (name, val) in &self.env.env_extended_properties {
+            working.insert(name.clone(), val.clone());
+        }
+
         for (name, val) in &self.extended_properties {
             working.insert(name.clone(), val.clone());
         }
@@ -79,6 +89,17 @@ impl Target {
         working
     }
 
+    /// Resolves the default startfiles, libraries, and endfiles for the specified [`OutputKind`], including the libraries required by the environment.
+    ///
+    /// The environment libraries are only added if the target links default libraries. See [`Link::default_link_files`]
+    pub fn default_link_files(&self, kind: OutputKind) -> Option<DefaultLinkFiles<'_>> {
+        let mut files = self.link.default_link_files(kind)?;
+        if self.link.default_libraries.is_some() {
+            files.env_libraries = self.env.link_libraries.for_kind(kind);
+        }
+        Some(files)
+    }
+
     /// Compiles the list of target features set by default on this target. `mach` is a machine passed in explicity by the `-march` flag
     pub fn compile_target_features(&self, mach: Option<&Machine>) -> HashSet<CowStr> {
         let implied_features = self
@@ -123,7 +144,7 @@ impl Target {
         loop {
             let mut work_done = false;
             for feat in disabled.clone() {
-                for feat in reverse_implied_features.get(&feat).unwrap() {
+                for feat in reverse_implied_features.get(&feat).into_iter().flatten() {
                     work_done |= disabled.insert(feat.to_owned());
                 }
             }
@@ -138,7 +159,7 @@ impl Target {
         loop {
             let mut work_done = false;
             for feat in working.clone() {
-                for feat in implied_features.get(&feat).unwrap() {
+                for feat in implied_features.get(&feat).into_iter().flatten() {
                     work_done |= working.insert(feat.to_owned());
                 }
             }
@@ -149,4 +170,129 @@ impl Target {
 
         working
     }
+
+    /// Computes the atomic properties of this target from the features resolved by [`Target::compile_target_features`].
+    /// `mach` is a machine passed in explicity by the `-march` flag
+    pub fn compile_atomics(&self, mach: Option<&Machine>) -> Atomics {
+        let features = self.compile_target_features(mach);
+
+        let mut atomics = Atomics::NONE;
+
+        for atomic in &self.arch.native_atomics {
+            if atomic.enabled_by.is_empty()
+                || atomic
+                    .enabled_by
+                    .iter()
+                    .any(|feature| features.contains(feature))
+            {
+                atomics.native |= AtomicWidths::from_width(atomic.width);
+                atomics.max_lock_free_width = atomics.max_lock_free_width.max(atomic.width);
+            }
+        }
+
+        atomics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use target_tuples::TargetRef;
+
+    use super::*;
+    use crate::builtin::{
+        archs::{clever::CLEVER, m65::M6502},
+        target::from_target,
+    };
+
+    fn target(name: &str) -> Target {
+        from_target(&TargetRef::parse(name)).unwrap()
+    }
+
+    fn machine<'a>(target: &'a Target, name: &str) -> &'a Machine {
+        target
+            .arch
+            .machines
+            .iter()
+            .find(|mach| mach.name == *name)
+            .unwrap()
+    }
+
+    fn with_features(target: Target, features: &[(&'static str, bool)]) -> Target {
+        Target {
+            override_features: CowPtr::Boxed(
+                features
+                    .iter()
+                    .map(|&(name, enabled)| (CowPtr::Borrowed(name), enabled))
+                    .collect(),
+            ),
+            ..target
+        }
+    }
+
+    #[test]
+    fn i686_atomics() {
+        let target = target("i686-pc-linux-gnu");
+        assert_eq!(target.compile_atomics(None).max_lock_free_width, 64);
+
+        // cmpxchg8b is required for 64-bit atomics
+        let i486 = target.compile_atomics(Some(machine(&target, "i486")));
+        assert_eq!(i486.max_lock_free_width, 32);
+        assert!(!i486.native.contains(AtomicWidths::W64));
+
+        let target = with_features(target, &[("cx8", false)]);
+        assert_eq!(target.compile_atomics(None).max_lock_free_width, 32);
+    }
+
+    #[test]
+    fn x86_64_atomics() {
+        let target = target("x86_64-pc-linux-gnu");
+        assert_eq!(target.compile_atomics(None).max_lock_free_width, 64);
+
+        let v2 = target.compile_atomics(Some(machine(&target, "x86-64v2")));
+        assert_eq!(v2.max_lock_free_width, 128);
+        assert!(v2.native.contains(AtomicWidths::W128));
+
+        let target = with_features(target, &[("cx16", true)]);
+        assert_eq!(target.compile_atomics(None).max_lock_free_width, 128);
+    }
+
+    #[test]
+    fn clever_atomics() {
+        // There is no complete builtin Clever-ISA target, as it has no primitive layouts yet
+        let target = Target {
+            arch: CowPtr::Borrowed(&CLEVER),
+            ..target("x86_64-pc-linux-gnu")
+        };
+        assert_eq!(target.compile_atomics(None), Atomics::NONE);
+
+        let target = with_features(target, &[("atomic-xchg", true)]);
+        let atomics = target.compile_atomics(None);
+        assert_eq!(atomics.max_lock_free_width, 64);
+        assert_eq!(
+            atomics.native,
+            AtomicWidths::W8 | AtomicWidths::W16 | AtomicWidths::W32 | AtomicWidths::W64
+        );
+    }
+
+    #[test]
+    fn m6502_atomics() {
+        let target = Target {
+            arch: CowPtr::Borrowed(&M6502),
+            ..target("x86_64-pc-linux-gnu")
+        };
+        assert_eq!(target.compile_atomics(None), Atomics::NONE);
+    }
+
+    #[test]
+    fn arm_atomics() {
+        // The default machine of arm-linux-gnueabi is ARMv5TE, which has no exclusive loads and stores.
+        // Atomics are provided by the kernel user helpers (`__kuser_cmpxchg`) instead, so none are lock-free in the sense of `native`
+        let target = target("arm-linux-gnueabi");
+        assert_eq!(target.compile_atomics(None), Atomics::NONE);
+
+        let v6 = target.compile_atomics(Some(machine(&target, "armv6")));
+        assert_eq!(v6.native, AtomicWidths::W32);
+        let v7 = target.compile_atomics(Some(machine(&target, "armv7-a")));
+        assert_eq!(v7.max_lock_free_width, 64);
+    }
 }